@@ -1,13 +1,20 @@
 #![allow(dead_code)] // Infrastructure prepared for Phase 1, will be used when integrated
 //! Disk Cache - Sled-backed persistent storage.
 //!
-//! Stores notification read status, sync timestamps, and cached responses.
+//! Stores notification read status, sync timestamps, cached responses, and
+//! per-account snapshots used to start up offline.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sled::Transactional;
+use sled::transaction::{ConflictableTransactionError, TransactionError};
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
+use std::sync::OnceLock;
 use thiserror::Error;
 
+use crate::github::{NotificationView, UserInfo};
+
 /// Cache-related errors.
 #[derive(Debug, Error)]
 pub enum CacheError {
@@ -34,7 +41,43 @@ pub struct CachedNotification {
 pub struct SyncMetadata {
     pub last_sync: DateTime<Utc>,
     pub etag: Option<String>,
+    #[serde(default)]
+    pub last_modified: Option<String>,
     pub notification_count: usize,
+    /// Minimum polling interval requested by GitHub (`X-Poll-Interval`).
+    #[serde(default)]
+    pub poll_interval_secs: Option<u64>,
+}
+
+/// A thread hidden until `until`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnoozedThread {
+    /// The thread as it was when snoozed; newer activity ends the snooze early.
+    pub notification: NotificationView,
+    pub until: DateTime<Utc>,
+}
+
+/// One thread update decided by a rule.
+#[derive(Debug, Clone, Copy)]
+pub struct RuleHit<'a> {
+    pub account: &'a str,
+    pub rule_id: &'a str,
+    pub thread_id: &'a str,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Marker key for a thread, prefixed by account so a fetch prunes only its own.
+fn rule_hit_key(account: &str, thread_id: &str) -> String {
+    format!("{account}/{thread_id}")
+}
+
+/// How often a rule has decided a thread's outcome.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleHitStats {
+    pub rule_id: String,
+    pub hits: u64,
+    /// Update time of the latest thread the rule decided.
+    pub last_hit: DateTime<Utc>,
 }
 
 /// Sled-backed persistent cache.
@@ -50,6 +93,23 @@ impl DiskCache {
         Ok(Self { db })
     }
 
+    /// Returns the process-wide cache, opening it on first use.
+    ///
+    /// sled holds an exclusive lock on the database, so every caller must
+    /// share this instance. `None` if the cache could not be opened.
+    pub fn shared() -> Option<&'static DiskCache> {
+        static SHARED: OnceLock<Option<DiskCache>> = OnceLock::new();
+        SHARED
+            .get_or_init(|| match Self::open() {
+                Ok(cache) => Some(cache),
+                Err(e) => {
+                    eprintln!("[CACHE] Failed to open disk cache: {}", e);
+                    None
+                }
+            })
+            .as_ref()
+    }
+
     /// Gets the cache directory path.
     fn cache_path() -> Result<PathBuf, CacheError> {
         dirs::cache_dir()
@@ -146,9 +206,323 @@ impl DiskCache {
         }
     }
 
+    /// Store the `Last-Modified` validator for a URL.
+    pub fn save_last_modified(&self, url: &str, last_modified: &str) -> Result<(), CacheError> {
+        let tree = self.db.open_tree("last_modified")?;
+        tree.insert(url.as_bytes(), last_modified.as_bytes())?;
+        Ok(())
+    }
+
+    /// Get the cached `Last-Modified` validator for a URL.
+    pub fn get_last_modified(&self, url: &str) -> Result<Option<String>, CacheError> {
+        let tree = self.db.open_tree("last_modified")?;
+        match tree.get(url.as_bytes())? {
+            Some(bytes) => Ok(Some(String::from_utf8_lossy(&bytes).to_string())),
+            None => Ok(None),
+        }
+    }
+
+    // =========================================================================
+    // Offline Snapshot (per-account)
+    // =========================================================================
+
+    /// Save the last fetched notification list for an account.
+    pub fn save_notifications(
+        &self,
+        account: &str,
+        notifications: &[NotificationView],
+    ) -> Result<(), CacheError> {
+        self.save_json("notifications", account, &notifications)
+    }
+
+    /// Load the last fetched notification list for an account.
+    pub fn load_notifications(
+        &self,
+        account: &str,
+    ) -> Result<Option<Vec<NotificationView>>, CacheError> {
+        self.load_json("notifications", account)
+    }
+
+    /// Save the user profile for an account, used to restore it offline.
+    pub fn save_user(&self, account: &str, user: &UserInfo) -> Result<(), CacheError> {
+        self.save_json("users", account, user)
+    }
+
+    /// Load the cached user profile for an account.
+    pub fn load_user(&self, account: &str) -> Result<Option<UserInfo>, CacheError> {
+        self.load_json("users", account)
+    }
+
+    // =========================================================================
+    // Local Unread Marks
+    // =========================================================================
+
+    /// Keep a thread unread locally. GitHub has no API to mark a thread unread,
+    /// so the full view is stored to bring it back after refreshes.
+    pub fn save_unread_mark(&self, notification: &NotificationView) -> Result<(), CacheError> {
+        self.save_json("unread_marks", &notification.id, notification)
+    }
+
+    /// Drop the local unread mark for a thread.
+    pub fn remove_unread_mark(&self, notification_id: &str) -> Result<(), CacheError> {
+        let tree = self.db.open_tree("unread_marks")?;
+        tree.remove(notification_id.as_bytes())?;
+        Ok(())
+    }
+
+    /// All threads marked unread locally.
+    pub fn load_unread_marks(&self) -> Result<Vec<NotificationView>, CacheError> {
+        self.load_all_json("unread_marks")
+    }
+
+    // =========================================================================
+    // Deferred Notifications
+    // =========================================================================
+
+    /// Queue a notification held back by an account schedule.
+    pub fn save_deferred(&self, notification: &NotificationView) -> Result<(), CacheError> {
+        self.save_json("deferred", &notification.id, notification)
+    }
+
+    /// Drop a notification from the deferred queue.
+    pub fn remove_deferred(&self, notification_id: &str) -> Result<(), CacheError> {
+        let tree = self.db.open_tree("deferred")?;
+        tree.remove(notification_id.as_bytes())?;
+        Ok(())
+    }
+
+    /// Every queued deferred notification.
+    pub fn load_deferred(&self) -> Result<Vec<NotificationView>, CacheError> {
+        self.load_all_json("deferred")
+    }
+
+    /// Empty the deferred queue.
+    pub fn clear_deferred(&self) -> Result<(), CacheError> {
+        self.db.open_tree("deferred")?.clear()?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Snoozed Threads
+    // =========================================================================
+
+    /// Persist a snoozed thread.
+    pub fn save_snooze(&self, snooze: &SnoozedThread) -> Result<(), CacheError> {
+        self.save_json("snoozed", &snooze.notification.id, snooze)
+    }
+
+    /// Drop a thread's snooze.
+    pub fn remove_snooze(&self, notification_id: &str) -> Result<(), CacheError> {
+        let tree = self.db.open_tree("snoozed")?;
+        tree.remove(notification_id.as_bytes())?;
+        Ok(())
+    }
+
+    /// Every snoozed thread, expired or not.
+    pub fn load_snoozes(&self) -> Result<Vec<SnoozedThread>, CacheError> {
+        self.load_all_json("snoozed")
+    }
+
+    // =========================================================================
+    // Rule Hit Statistics
+    // =========================================================================
+
+    /// Count one hit per thread update from a fetch of `accounts`.
+    /// Re-evaluating the same update (refreshes) doesn't count again.
+    ///
+    /// Counts and their "already counted" markers are written in one transaction.
+    /// Markers for the fetched accounts' threads that have left the list are pruned.
+    pub fn record_rule_hits<'a>(
+        &self,
+        accounts: &HashSet<&str>,
+        hits: impl IntoIterator<Item = RuleHit<'a>>,
+    ) -> Result<(), CacheError> {
+        let hits: Vec<RuleHit<'a>> = hits.into_iter().collect();
+        let counted = self.db.open_tree("rule_hit_threads")?;
+        let stats = self.db.open_tree("rule_hits")?;
+
+        (&counted, &stats)
+            .transaction(|(counted, stats)| {
+                let mut new_hits: HashMap<&str, (u64, DateTime<Utc>)> = HashMap::new();
+                for hit in &hits {
+                    let key = rule_hit_key(hit.account, hit.thread_id);
+                    let marker = format!("{}@{}", hit.rule_id, hit.updated_at.to_rfc3339());
+                    if counted
+                        .get(key.as_bytes())?
+                        .is_some_and(|seen| seen == marker.as_bytes())
+                    {
+                        continue;
+                    }
+                    counted.insert(key.as_bytes(), marker.as_bytes())?;
+                    let (count, last_hit) =
+                        new_hits.entry(hit.rule_id).or_insert((0, hit.updated_at));
+                    *count += 1;
+                    *last_hit = (*last_hit).max(hit.updated_at);
+                }
+
+                for (rule_id, (count, last_hit)) in new_hits {
+                    let existing = stats
+                        .get(rule_id.as_bytes())?
+                        .map(|bytes| serde_json::from_slice::<RuleHitStats>(&bytes))
+                        .transpose()
+                        .map_err(|e| {
+                            ConflictableTransactionError::Abort(CacheError::Serialization(
+                                e.to_string(),
+                            ))
+                        })?;
+                    let updated = match existing {
+                        Some(existing) => RuleHitStats {
+                            hits: existing.hits + count,
+                            last_hit: existing.last_hit.max(last_hit),
+                            ..existing
+                        },
+                        None => RuleHitStats {
+                            rule_id: rule_id.to_string(),
+                            hits: count,
+                            last_hit,
+                        },
+                    };
+                    let json = serde_json::to_vec(&updated).map_err(|e| {
+                        ConflictableTransactionError::Abort(CacheError::Serialization(
+                            e.to_string(),
+                        ))
+                    })?;
+                    stats.insert(rule_id.as_bytes(), json)?;
+                }
+                Ok(())
+            })
+            .map_err(|e| match e {
+                TransactionError::Abort(e) => e,
+                TransactionError::Storage(e) => CacheError::Sled(e),
+            })?;
+
+        let present: HashSet<Vec<u8>> = hits
+            .iter()
+            .map(|hit| rule_hit_key(hit.account, hit.thread_id).into_bytes())
+            .collect();
+        let mut stale = sled::Batch::default();
+        for account in accounts {
+            for key in counted.scan_prefix(rule_hit_key(account, "")).keys() {
+                let key = key?;
+                if !present.contains(key.as_ref()) {
+                    stale.remove(key);
+                }
+            }
+        }
+        counted.apply_batch(stale)?;
+        Ok(())
+    }
+
+    /// Statistics for every rule that has matched at least once.
+    pub fn load_rule_hits(&self) -> Result<Vec<RuleHitStats>, CacheError> {
+        self.load_all_json("rule_hits")
+    }
+
+    /// Start counting from scratch.
+    pub fn clear_rule_hits(&self) -> Result<(), CacheError> {
+        self.db.open_tree("rule_hits")?.clear()?;
+        self.db.open_tree("rule_hit_threads")?.clear()?;
+        Ok(())
+    }
+
+    fn save_json<T: Serialize + ?Sized>(
+        &self,
+        tree: &str,
+        key: &str,
+        value: &T,
+    ) -> Result<(), CacheError> {
+        let tree = self.db.open_tree(tree)?;
+        let json =
+            serde_json::to_vec(value).map_err(|e| CacheError::Serialization(e.to_string()))?;
+        tree.insert(key.as_bytes(), json)?;
+        Ok(())
+    }
+
+    fn load_json<T: for<'de> Deserialize<'de>>(
+        &self,
+        tree: &str,
+        key: &str,
+    ) -> Result<Option<T>, CacheError> {
+        let tree = self.db.open_tree(tree)?;
+        match tree.get(key.as_bytes())? {
+            Some(bytes) => serde_json::from_slice(&bytes)
+                .map(Some)
+                .map_err(|e| CacheError::Serialization(e.to_string())),
+            None => Ok(None),
+        }
+    }
+
+    fn load_all_json<T: for<'de> Deserialize<'de>>(
+        &self,
+        tree: &str,
+    ) -> Result<Vec<T>, CacheError> {
+        let tree = self.db.open_tree(tree)?;
+        tree.iter()
+            .values()
+            .map(|bytes| {
+                serde_json::from_slice(&bytes?)
+                    .map_err(|e| CacheError::Serialization(e.to_string()))
+            })
+            .collect()
+    }
+
     /// Flush changes to disk.
     pub fn flush(&self) -> Result<(), CacheError> {
         self.db.flush()?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_rule_hits_count_each_update_once() {
+        let cache = DiskCache {
+            db: sled::Config::new().temporary(true).open().unwrap(),
+        };
+        let accounts = HashSet::from(["alice"]);
+        let hit = |rule_id, thread_id, updated_at| RuleHit {
+            account: "alice",
+            rule_id,
+            thread_id,
+            updated_at,
+        };
+        let hits = |rule: &str| {
+            cache
+                .load_rule_hits()
+                .unwrap()
+                .into_iter()
+                .find(|s| s.rule_id == rule)
+                .map(|s| s.hits)
+        };
+        let t0 = Utc::now();
+
+        let fetch = [hit("mute-ci", "1", t0), hit("mute-ci", "2", t0)];
+        cache.record_rule_hits(&accounts, fetch).unwrap();
+        // Re-evaluating the same updates on the next fetch doesn't count again.
+        cache.record_rule_hits(&accounts, fetch).unwrap();
+        assert_eq!(hits("mute-ci"), Some(2));
+
+        // A newer update, or another rule deciding the same update, does.
+        let t1 = t0 + Duration::minutes(5);
+        cache
+            .record_rule_hits(
+                &accounts,
+                [hit("mute-ci", "1", t1), hit("important-org", "2", t0)],
+            )
+            .unwrap();
+        assert_eq!(hits("mute-ci"), Some(3));
+        assert_eq!(hits("important-org"), Some(1));
+
+        // Thread 1 left the list, so its marker was pruned.
+        let markers = cache.db.open_tree("rule_hit_threads").unwrap();
+        cache
+            .record_rule_hits(&accounts, [hit("important-org", "2", t0)])
+            .unwrap();
+        assert_eq!(markers.len(), 1);
+        assert_eq!(hits("important-org"), Some(1));
+    }
+}
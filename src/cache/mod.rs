@@ -5,4 +5,4 @@
 mod disk;
 
 #[allow(unused_imports)]
-pub use disk::{CacheError, DiskCache};
+pub use disk::{CacheError, DiskCache, RuleHit, RuleHitStats, SnoozedThread, SyncMetadata};
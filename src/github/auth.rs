@@ -45,9 +45,13 @@ pub fn delete_token() -> Result<(), AuthError> {
 }
 
 /// Full authentication flow: validate token, save to keyring, return user info.
-pub async fn authenticate(token: &str) -> Result<(GitHubClient, UserInfo), AuthError> {
+/// `api_base` selects a GitHub Enterprise Server instance; `None` means github.com.
+pub async fn authenticate(
+    token: &str,
+    api_base: Option<&str>,
+) -> Result<(GitHubClient, UserInfo), AuthError> {
     // Validate and create client
-    let (client, user) = GitHubClient::validate_token(token).await?;
+    let (client, user) = GitHubClient::validate_token(token, api_base).await?;
 
     // Save to secure storage
     save_token(token)?;
@@ -55,15 +59,22 @@ pub async fn authenticate(token: &str) -> Result<(GitHubClient, UserInfo), AuthE
     Ok((client, user))
 }
 
-/// Validates the format of a GitHub Personal Access Token.
-/// Checks for 'ghp_' or 'github_pat_' prefix and non-empty content.
+/// Token prefixes GitHub issues: classic PAT, fine-grained PAT, OAuth and GitHub App user tokens.
+const TOKEN_PREFIXES: &[&str] = &["ghp_", "github_pat_", "gho_", "ghu_"];
+
+/// Validates the format of a GitHub token.
+/// Checks for a known prefix ('ghp_', 'github_pat_', or the device-flow 'gho_'/'ghu_')
+/// and non-empty content.
 pub fn validate_token_format(token: &str) -> Result<(), AuthError> {
     if token.is_empty() {
         return Err(AuthError::Keyring("Token cannot be empty".to_string()));
     }
-    if !token.starts_with("ghp_") && !token.starts_with("github_pat_") {
+    if !TOKEN_PREFIXES
+        .iter()
+        .any(|prefix| token.starts_with(prefix))
+    {
         return Err(AuthError::Keyring(
-            "Token must start with 'ghp_' or 'github_pat_'".to_string(),
+            "Token must start with 'ghp_', 'github_pat_', 'gho_' or 'ghu_'".to_string(),
         ));
     }
     Ok(())
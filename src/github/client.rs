@@ -1,13 +1,26 @@
 //! GitHub API client using Personal Access Tokens.
 
-use reqwest::header::{ACCEPT, AUTHORIZATION, HeaderMap, HeaderValue, USER_AGENT};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use chrono::{DateTime, Local, TimeZone, Utc};
+use reqwest::StatusCode;
+use reqwest::header::{
+    ACCEPT, AUTHORIZATION, AsHeaderName, ETAG, HeaderMap, HeaderValue, IF_MODIFIED_SINCE,
+    IF_NONE_MATCH, LAST_MODIFIED, LINK, USER_AGENT,
+};
 use serde::Deserialize;
 use thiserror::Error;
 
-use super::types::{Notification, NotificationView, UserInfo};
+use super::types::{Notification, NotificationView, TokenInfo, UserInfo};
+use crate::cache::{DiskCache, SyncMetadata};
+
+/// GitHub API base URL (github.com).
+pub const GITHUB_API_URL: &str = "https://api.github.com";
 
-/// GitHub API base URL.
-const GITHUB_API_URL: &str = "https://api.github.com";
+/// Page size requested from the notifications endpoint (GitHub's maximum).
+const NOTIFICATIONS_PER_PAGE: u32 = 100;
 
 /// Errors that can occur when interacting with the GitHub API.
 #[derive(Debug, Error, Clone)]
@@ -18,13 +31,63 @@ pub enum GitHubError {
     #[error("Invalid or expired token")]
     Unauthorized,
 
-    #[error("Rate limit exceeded")]
-    RateLimited,
+    #[error("{}", rate_limit_message(*.kind, .reset_at))]
+    RateLimited {
+        kind: RateLimitKind,
+        /// When requests may resume, if GitHub told us.
+        reset_at: Option<DateTime<Utc>>,
+    },
+
+    #[error("SAML single sign-on authorization required for this organization")]
+    SsoRequired {
+        /// Authorization URL from the `X-GitHub-SSO` header.
+        url: Option<String>,
+    },
+
+    #[error("Token is missing a required permission: {0}")]
+    InsufficientScope(String),
 
     #[error("GitHub API error: {status} - {message}")]
     Api { status: u16, message: String },
 }
 
+/// Which GitHub rate limit was hit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitKind {
+    /// Hourly request quota (`X-RateLimit-Remaining: 0`).
+    Primary,
+    /// Abuse-detection limit (`Retry-After`).
+    Secondary,
+}
+
+impl GitHubError {
+    /// Time before which no further requests should be made, for rate-limit errors.
+    pub fn retry_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::RateLimited { reset_at, .. } => *reset_at,
+            _ => None,
+        }
+    }
+}
+
+fn rate_limit_message(kind: RateLimitKind, reset_at: &Option<DateTime<Utc>>) -> String {
+    let prefix = match kind {
+        RateLimitKind::Primary => "API rate limit exceeded",
+        RateLimitKind::Secondary => "Secondary rate limit triggered",
+    };
+    match reset_at {
+        Some(reset) => format!(
+            "{}, retrying after {}",
+            prefix,
+            reset.with_timezone(&Local).format("%H:%M")
+        ),
+        None => prefix.to_string(),
+    }
+}
+
+/// How long to wait after a secondary limit when GitHub sends no `Retry-After`.
+const SECONDARY_LIMIT_FALLBACK_SECS: i64 = 60;
+
 impl From<reqwest::Error> for GitHubError {
     fn from(e: reqwest::Error) -> Self {
         GitHubError::Request(e.to_string())
@@ -45,12 +108,24 @@ struct GitHubUser {
 pub struct GitHubClient {
     client: reqwest::Client,
     token: String,
+    /// REST API base, e.g. `https://api.github.com` or `https://ghe.example.com/api/v3`.
+    api_base: String,
+    /// Last `X-Poll-Interval` seen from the notifications endpoint (0 = unknown).
+    /// Shared between clones so background fetches update the UI's copy.
+    poll_interval_secs: Arc<AtomicU64>,
 }
 
 impl GitHubClient {
-    /// Creates a new GitHub client with the given Personal Access Token.
-    pub fn new(token: impl Into<String>) -> Result<Self, GitHubError> {
+    /// Creates a client for a specific API base.
+    /// `None` targets github.com; `Some` is a GitHub Enterprise Server REST base.
+    pub fn with_api_base(
+        token: impl Into<String>,
+        api_base: Option<&str>,
+    ) -> Result<Self, GitHubError> {
         let token = token.into();
+        let api_base = api_base
+            .map(normalize_api_base)
+            .unwrap_or_else(|| GITHUB_API_URL.to_string());
 
         let mut headers = HeaderMap::new();
         headers.insert(
@@ -77,7 +152,48 @@ impl GitHubClient {
             .pool_max_idle_per_host(1)
             .build()?;
 
-        Ok(Self { client, token })
+        Ok(Self {
+            client,
+            token,
+            api_base,
+            poll_interval_secs: Arc::new(AtomicU64::new(0)),
+        })
+    }
+
+    /// The GitHub Enterprise Server API base, or `None` for github.com.
+    pub fn enterprise_api_base(&self) -> Option<&str> {
+        (self.api_base != GITHUB_API_URL).then_some(self.api_base.as_str())
+    }
+
+    /// GraphQL endpoint matching the REST base.
+    /// GHES serves it at `/api/graphql` next to `/api/v3`.
+    fn graphql_url(&self) -> String {
+        match self.api_base.strip_suffix("/v3") {
+            Some(api_root) if self.enterprise_api_base().is_some() => {
+                format!("{}/graphql", api_root)
+            }
+            _ => format!("{}/graphql", self.api_base),
+        }
+    }
+
+    /// Polling interval requested by GitHub via `X-Poll-Interval`, if seen yet.
+    pub fn poll_interval(&self) -> Option<Duration> {
+        match self.poll_interval_secs.load(Ordering::Relaxed) {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        }
+    }
+
+    /// Remembers the `X-Poll-Interval` header from a notifications response.
+    fn record_poll_interval(&self, headers: &HeaderMap) -> Option<u64> {
+        let secs = headers
+            .get("X-Poll-Interval")?
+            .to_str()
+            .ok()?
+            .parse::<u64>()
+            .ok()?;
+        self.poll_interval_secs.store(secs, Ordering::Relaxed);
+        Some(secs)
     }
 
     /// Validates and handles the response status.
@@ -87,30 +203,26 @@ impl GitHubClient {
         let status = response.status();
 
         if status.is_success() {
-            Ok(response)
-        } else if status.as_u16() == 401 {
-            Err(GitHubError::Unauthorized)
-        } else if status.as_u16() == 403 {
-            Err(GitHubError::RateLimited)
-        } else {
-            let message = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            Err(GitHubError::Api {
-                status: status.as_u16(),
-                message,
-            })
+            return Ok(response);
         }
+
+        let headers = response.headers().clone();
+        let body = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        Err(classify_error(status.as_u16(), &headers, &body, Utc::now()))
     }
 
     /// Fetches the authenticated user's information.
-    /// This is used to validate the token and get user details.
+    /// This is used to validate the token and get user details, including
+    /// the token's scopes and expiry from the response headers.
     pub async fn get_authenticated_user(&self) -> Result<UserInfo, GitHubError> {
-        let url = format!("{}/user", GITHUB_API_URL);
+        let url = format!("{}/user", self.api_base);
 
         let response = self.client.get(&url).send().await?;
         let response = Self::handle_response(response).await?;
+        let token = token_info_from_headers(response.headers());
 
         let user: GitHubUser = response.json().await?;
         Ok(UserInfo {
@@ -118,13 +230,16 @@ impl GitHubClient {
             name: user.name,
             avatar_url: user.avatar_url,
             html_url: user.html_url,
+            token,
         })
     }
 
     /// Validates a token by creating a client and fetching user info.
     /// Returns the client and user info if valid.
-    pub async fn validate_token(token: &str) -> Result<(Self, UserInfo), GitHubError> {
-        // Basic format validation
+    pub async fn validate_token(
+        token: &str,
+        api_base: Option<&str>,
+    ) -> Result<(Self, UserInfo), GitHubError> {
         // Basic format validation
         if let Err(e) = super::auth::validate_token_format(token) {
             return Err(GitHubError::Api {
@@ -133,21 +248,141 @@ impl GitHubClient {
             });
         }
 
-        let client = Self::new(token)?;
+        let client = Self::with_api_base(token, api_base)?;
         let user = client.get_authenticated_user().await?;
         Ok((client, user))
     }
 
+    /// Builds the first-page URL for the notifications endpoint.
+    fn notifications_url(&self, all: bool) -> String {
+        format!(
+            "{}/notifications?all={}&participating=false&per_page={}",
+            self.api_base, all, NOTIFICATIONS_PER_PAGE
+        )
+    }
+
     /// Fetches the user's notifications.
-    pub async fn get_notifications(&self, all: bool) -> Result<Vec<Notification>, GitHubError> {
-        let url = format!(
-            "{}/notifications?all={}&participating=false",
-            GITHUB_API_URL, all
-        );
+    ///
+    /// Follows `Link: rel="next"` headers across pages, stopping after
+    /// `max_pages` pages so a huge inbox can't stall a refresh.
+    pub async fn get_notifications(
+        &self,
+        all: bool,
+        max_pages: usize,
+    ) -> Result<Vec<Notification>, GitHubError> {
+        let response = self.client.get(self.notifications_url(all)).send().await?;
+        self.record_poll_interval(response.headers());
+        self.collect_notification_pages(response, max_pages).await
+    }
+
+    /// Reads a first notifications page and any following pages up to `max_pages`.
+    async fn collect_notification_pages(
+        &self,
+        first_page: reqwest::Response,
+        max_pages: usize,
+    ) -> Result<Vec<Notification>, GitHubError> {
+        let response = Self::handle_response(first_page).await?;
+        let next_url = next_page_url(response.headers());
+        let mut notifications: Vec<Notification> = response.json().await?;
+        self.read_following_pages(next_url, max_pages, &mut notifications)
+            .await?;
+        Ok(notifications)
+    }
+
+    /// Appends the pages from `next_url` on to the first page's `notifications`,
+    /// stopping after `max_pages` pages in total.
+    async fn read_following_pages(
+        &self,
+        mut next_url: Option<String>,
+        max_pages: usize,
+        notifications: &mut Vec<Notification>,
+    ) -> Result<(), GitHubError> {
+        let mut pages = 1;
+        while let Some(url) = next_url.take().filter(|_| pages < max_pages) {
+            let response = Self::handle_response(self.client.get(&url).send().await?).await?;
+            next_url = next_page_url(response.headers());
+            let page: Vec<Notification> = response.json().await?;
+            notifications.extend(page);
+            pages += 1;
+        }
+        Ok(())
+    }
+
+    /// Fetches notifications with a conditional request against the disk cache.
+    ///
+    /// The first page is sent with `If-None-Match`/`If-Modified-Since`; a 304
+    /// reuses the cached first page and doesn't count against the rate limit.
+    /// The validators only cover that page, so later pages are always fetched.
+    async fn get_notifications_conditional(
+        &self,
+        all: bool,
+        account: &str,
+        max_pages: usize,
+    ) -> Result<Vec<Notification>, GitHubError> {
+        let url = self.notifications_url(all);
+        let Some(cache) = DiskCache::shared() else {
+            return self.get_notifications(all, max_pages).await;
+        };
+        // The endpoint URL is identical for every account, so key by account too.
+        let cache_key = format!("{}|{}", account, url);
+
+        let mut request = self.client.get(&url);
+        if let Ok(Some(etag)) = cache.get_etag(&cache_key) {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        if let Ok(Some(last_modified)) = cache.get_last_modified(&cache_key) {
+            request = request.header(IF_MODIFIED_SINCE, last_modified);
+        }
+
+        let response = request.send().await?;
+        let poll_interval_secs = self.record_poll_interval(response.headers());
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            // A body longer than a page can't be combined with the later pages.
+            if let Ok(Some(body)) = cache.get_cached_body(&cache_key)
+                && let Ok(mut notifications) = serde_json::from_slice::<Vec<Notification>>(&body)
+                && notifications.len() <= NOTIFICATIONS_PER_PAGE as usize
+            {
+                // A 304 carries no Link header; a full first page may have a second.
+                let next_url = (notifications.len() == NOTIFICATIONS_PER_PAGE as usize)
+                    .then(|| format!("{url}&page=2"));
+                self.read_following_pages(next_url, max_pages, &mut notifications)
+                    .await?;
+                return Ok(notifications);
+            }
+            // Validators without a usable body: fall back to a full fetch.
+            return self.get_notifications(all, max_pages).await;
+        }
 
-        let response = self.client.get(&url).send().await?;
         let response = Self::handle_response(response).await?;
-        Ok(response.json().await?)
+        let etag = header_string(response.headers(), ETAG);
+        let last_modified = header_string(response.headers(), LAST_MODIFIED);
+        let next_url = next_page_url(response.headers());
+        let mut notifications: Vec<Notification> = response.json().await?;
+
+        // Cache the first page alone, since that's all the validators describe.
+        if let Ok(body) = serde_json::to_vec(&notifications) {
+            if let Some(etag) = &etag {
+                let _ = cache.save_etag_response(&cache_key, etag, &body);
+            }
+            if let Some(last_modified) = &last_modified {
+                let _ = cache.save_last_modified(&cache_key, last_modified);
+            }
+        }
+        self.read_following_pages(next_url, max_pages, &mut notifications)
+            .await?;
+        let _ = cache.save_sync_metadata(
+            account,
+            &SyncMetadata {
+                last_sync: chrono::Utc::now(),
+                etag,
+                last_modified,
+                notification_count: notifications.len(),
+                poll_interval_secs,
+            },
+        );
+
+        Ok(notifications)
     }
 
     /// Fetches notifications and converts them to frontend-friendly format.
@@ -156,8 +391,11 @@ impl GitHubClient {
         &self,
         all: bool,
         account: &str,
+        max_pages: usize,
     ) -> Result<Vec<NotificationView>, GitHubError> {
-        let notifications = self.get_notifications(all).await?;
+        let notifications = self
+            .get_notifications_conditional(all, account, max_pages)
+            .await?;
         let account = account.to_string();
         Ok(notifications
             .into_iter()
@@ -169,7 +407,7 @@ impl GitHubClient {
     pub async fn mark_as_read(&self, notification_id: &str) -> Result<(), GitHubError> {
         let url = format!(
             "{}/notifications/threads/{}",
-            GITHUB_API_URL, notification_id
+            self.api_base, notification_id
         );
 
         let response = self.client.patch(&url).send().await?;
@@ -178,7 +416,7 @@ impl GitHubClient {
 
     /// Marks all notifications as read.
     pub async fn mark_all_as_read(&self) -> Result<(), GitHubError> {
-        let url = format!("{}/notifications", GITHUB_API_URL);
+        let url = format!("{}/notifications", self.api_base);
 
         let response = self
             .client
@@ -192,7 +430,38 @@ impl GitHubClient {
 
     /// Marks a thread as "done" (removes it from inbox).
     pub async fn mark_thread_as_done(&self, thread_id: &str) -> Result<(), GitHubError> {
-        let url = format!("{}/notifications/threads/{}", GITHUB_API_URL, thread_id);
+        let url = format!("{}/notifications/threads/{}", self.api_base, thread_id);
+
+        let response = self.client.delete(&url).send().await?;
+        Self::handle_response(response).await.map(|_| ())
+    }
+
+    /// Subscribes to a thread again, or mutes it entirely when `ignored` is true.
+    pub async fn set_thread_subscription(
+        &self,
+        thread_id: &str,
+        ignored: bool,
+    ) -> Result<(), GitHubError> {
+        let url = format!(
+            "{}/notifications/threads/{}/subscription",
+            self.api_base, thread_id
+        );
+
+        let response = self
+            .client
+            .put(&url)
+            .json(&serde_json::json!({ "ignored": ignored }))
+            .send()
+            .await?;
+        Self::handle_response(response).await.map(|_| ())
+    }
+
+    /// Unsubscribes from a thread until the user is mentioned or comments again.
+    pub async fn delete_thread_subscription(&self, thread_id: &str) -> Result<(), GitHubError> {
+        let url = format!(
+            "{}/notifications/threads/{}/subscription",
+            self.api_base, thread_id
+        );
 
         let response = self.client.delete(&url).send().await?;
         Self::handle_response(response).await.map(|_| ())
@@ -361,8 +630,6 @@ impl GitHubClient {
     ) -> Result<super::subject_details::DiscussionDetails, GitHubError> {
         use super::subject_details::{DiscussionCategory, DiscussionDetails};
 
-        const GRAPHQL_URL: &str = "https://api.github.com/graphql";
-
         let query = format!(
             r#"{{
               repository(owner: "{}", name: "{}") {{
@@ -385,7 +652,12 @@ impl GitHubClient {
 
         let body = serde_json::json!({ "query": query });
 
-        let response = self.client.post(GRAPHQL_URL).json(&body).send().await?;
+        let response = self
+            .client
+            .post(self.graphql_url())
+            .json(&body)
+            .send()
+            .await?;
 
         let status = response.status();
         if !status.is_success() {
@@ -442,27 +714,143 @@ impl GitHubClient {
     }
 }
 
-/// Parse discussion URL to extract owner, repo, and number.
-/// Format: https://api.github.com/repos/{owner}/{repo}/discussions/{number}
-/// Parse discussion URL to extract owner, repo, and number.
-/// Format: https://api.github.com/repos/{owner}/{repo}/discussions/{number}
-fn parse_discussion_url(url: &str) -> Option<(String, String, u64)> {
-    let mut parts = url.split('/');
-    // Expected: ["https:", "", "api.github.com", "repos", "{owner}", "{repo}", "discussions", "{number}"]
+/// Maps a failed response to the most specific `GitHubError`.
+///
+/// GitHub uses 403 for rate limits, SSO enforcement and missing permissions
+/// alike, so the headers and body decide which one it really is.
+fn classify_error(status: u16, headers: &HeaderMap, body: &str, now: DateTime<Utc>) -> GitHubError {
+    let message = serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|json| json["message"].as_str().map(String::from))
+        .unwrap_or_else(|| body.to_string());
 
-    // Skip protocol, empty, host, "repos" -> 4 items
-    if parts.next()? != "https:" {
-        return None;
+    if status == 401 {
+        return GitHubError::Unauthorized;
     }
-    if !parts.next()?.is_empty() {
-        return None;
+    if status != 403 && status != 429 {
+        return GitHubError::Api { status, message };
     }
-    if parts.next()? != "api.github.com" {
-        return None;
+
+    // Header format: "required; url=https://github.com/orgs/.../sso?authorization_request=..."
+    if let Some(sso) = header_string(headers, "X-GitHub-SSO") {
+        let url = sso
+            .split(';')
+            .find_map(|part| part.trim().strip_prefix("url="))
+            .map(String::from);
+        return GitHubError::SsoRequired { url };
     }
-    if parts.next()? != "repos" {
-        return None;
+
+    let header_number =
+        |name: &str| -> Option<i64> { headers.get(name)?.to_str().ok()?.trim().parse().ok() };
+
+    if header_number("X-RateLimit-Remaining") == Some(0) {
+        let reset_at = header_number("X-RateLimit-Reset")
+            .and_then(|epoch| Utc.timestamp_opt(epoch, 0).single());
+        return GitHubError::RateLimited {
+            kind: RateLimitKind::Primary,
+            reset_at,
+        };
+    }
+
+    if let Some(secs) = header_number("Retry-After") {
+        return GitHubError::RateLimited {
+            kind: RateLimitKind::Secondary,
+            reset_at: Some(now + chrono::Duration::seconds(secs)),
+        };
+    }
+
+    if status == 429 || message.to_lowercase().contains("secondary rate limit") {
+        return GitHubError::RateLimited {
+            kind: RateLimitKind::Secondary,
+            reset_at: Some(now + chrono::Duration::seconds(SECONDARY_LIMIT_FALLBACK_SECS)),
+        };
+    }
+
+    // Classic tokens: the endpoint advertises which scopes it accepts.
+    if let Some(accepted) = header_string(headers, "X-Accepted-OAuth-Scopes")
+        && !accepted.trim().is_empty()
+    {
+        let granted = header_string(headers, "X-OAuth-Scopes").unwrap_or_default();
+        let granted: Vec<&str> = granted.split(',').map(str::trim).collect();
+        let satisfied = accepted
+            .split(',')
+            .map(str::trim)
+            .any(|scope| granted.contains(&scope));
+        if !satisfied {
+            return GitHubError::InsufficientScope(format!("requires one of: {}", accepted));
+        }
+    }
+
+    // Fine-grained tokens report missing permissions only in the message.
+    if message.contains("Resource not accessible by") {
+        return GitHubError::InsufficientScope(message);
+    }
+
+    GitHubError::Api { status, message }
+}
+
+/// Extracts token scopes and expiry from an authenticated response.
+fn token_info_from_headers(headers: &HeaderMap) -> TokenInfo {
+    let scopes = header_string(headers, "X-OAuth-Scopes").map(|scopes| {
+        scopes
+            .split(',')
+            .map(str::trim)
+            .filter(|scope| !scope.is_empty())
+            .map(String::from)
+            .collect()
+    });
+    let expires_at = header_string(headers, "github-authentication-token-expiration")
+        .and_then(|value| parse_token_expiration(&value));
+
+    TokenInfo { scopes, expires_at }
+}
+
+/// Parses `github-authentication-token-expiration`, which GitHub sends as
+/// `2024-07-12 19:32:51 UTC` or with a numeric offset (`2024-07-12 12:32:51 -0700`).
+fn parse_token_expiration(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(dt) = DateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S %z") {
+        return Some(dt.with_timezone(&Utc));
     }
+    let naive = value.strip_suffix("UTC")?.trim();
+    chrono::NaiveDateTime::parse_from_str(naive, "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .map(|dt| dt.and_utc())
+}
+
+/// Reads a header as an owned string, if present and valid UTF-8.
+fn header_string(headers: &HeaderMap, name: impl AsHeaderName) -> Option<String> {
+    headers.get(name)?.to_str().ok().map(String::from)
+}
+
+/// Extracts the `rel="next"` URL from a `Link` response header.
+///
+/// Format: `<https://api.github.com/notifications?page=2>; rel="next", <...>; rel="last"`
+fn next_page_url(headers: &HeaderMap) -> Option<String> {
+    let link = headers.get(LINK)?.to_str().ok()?;
+
+    link.split(',').find_map(|part| {
+        let (url, params) = part.split_once(';')?;
+        let is_next = params
+            .split(';')
+            .any(|param| param.trim() == r#"rel="next""#);
+
+        is_next.then(|| {
+            url.trim()
+                .trim_start_matches('<')
+                .trim_end_matches('>')
+                .to_string()
+        })
+    })
+}
+
+/// Parse discussion URL to extract owner, repo, and number.
+/// Format: `{api_base}/repos/{owner}/{repo}/discussions/{number}`, where the base is
+/// `https://api.github.com` or a GHES `https://{host}/api/v3`.
+fn parse_discussion_url(url: &str) -> Option<(String, String, u64)> {
+    let path = url.strip_prefix("https://")?;
+    let (_, repo_path) = path.split_once("/repos/")?;
+    let mut parts = repo_path.split('/');
 
     let owner = parts.next()?.to_string();
     let repo = parts.next()?.to_string();
@@ -475,3 +863,243 @@ fn parse_discussion_url(url: &str) -> Option<(String, String, u64)> {
 
     Some((owner, repo, number))
 }
+
+/// Host portion of an API base (`https://ghe.example.com/api/v3` → `ghe.example.com`).
+pub fn api_host(api_base: &str) -> &str {
+    let without_scheme = api_base
+        .split_once("://")
+        .map_or(api_base, |(_, rest)| rest);
+    without_scheme.split('/').next().unwrap_or(without_scheme)
+}
+
+/// Identifies an account across hosts: `octocat` on github.com,
+/// `octocat@ghe.example.com` on GitHub Enterprise Server.
+pub fn account_key(username: &str, api_base: Option<&str>) -> String {
+    match api_base.map(api_host) {
+        Some(host) => format!("{}@{}", username, host),
+        None => username.to_string(),
+    }
+}
+
+/// Normalizes a user-entered GitHub Enterprise Server address into a REST base.
+///
+/// Accepts `ghe.example.com`, `https://ghe.example.com` or the full
+/// `https://ghe.example.com/api/v3`. `github.com` and `api.github.com` map to
+/// [`GITHUB_API_URL`].
+pub fn normalize_api_base(input: &str) -> String {
+    let trimmed = input.trim().trim_end_matches('/');
+    let with_scheme = if trimmed.starts_with("https://") || trimmed.starts_with("http://") {
+        trimmed.to_string()
+    } else {
+        format!("https://{}", trimmed)
+    };
+
+    if matches!(
+        api_host(&with_scheme).to_ascii_lowercase().as_str(),
+        "github.com" | "www.github.com" | "api.github.com"
+    ) {
+        return GITHUB_API_URL.to_string();
+    }
+
+    if with_scheme.contains("/api/") {
+        with_scheme
+    } else {
+        format!("{}/api/v3", with_scheme)
+    }
+}
+
+/// GHES API base from a user-entered address, `None` when empty or github.com.
+pub fn enterprise_api_base_input(input: &str) -> Option<String> {
+    Some(input.trim())
+        .filter(|base| !base.is_empty())
+        .map(normalize_api_base)
+        .filter(|base| base != GITHUB_API_URL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link_headers(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(LINK, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn classify_primary_rate_limit_carries_reset() {
+        let now = Utc::now();
+        let err = classify_error(
+            403,
+            &headers(&[
+                ("X-RateLimit-Remaining", "0"),
+                ("X-RateLimit-Reset", "1700000000"),
+            ]),
+            r#"{"message":"API rate limit exceeded for user ID 1."}"#,
+            now,
+        );
+
+        assert!(matches!(
+            err,
+            GitHubError::RateLimited { kind: RateLimitKind::Primary, reset_at: Some(reset) }
+                if reset.timestamp() == 1_700_000_000
+        ));
+    }
+
+    #[test]
+    fn classify_secondary_rate_limit_uses_retry_after() {
+        let now = Utc::now();
+        let err = classify_error(429, &headers(&[("Retry-After", "120")]), "", now);
+
+        assert_eq!(err.retry_at(), Some(now + chrono::Duration::seconds(120)));
+        assert!(matches!(
+            err,
+            GitHubError::RateLimited {
+                kind: RateLimitKind::Secondary,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn classify_sso_and_scope_errors_are_not_rate_limits() {
+        let now = Utc::now();
+        let sso = classify_error(
+            403,
+            &headers(&[(
+                "X-GitHub-SSO",
+                "required; url=https://github.com/orgs/acme/sso?authorization_request=abc",
+            )]),
+            r#"{"message":"Resource protected by organization SAML enforcement."}"#,
+            now,
+        );
+        assert!(matches!(
+            sso,
+            GitHubError::SsoRequired { url: Some(ref url) } if url.starts_with("https://github.com/orgs/acme/sso")
+        ));
+
+        let scope = classify_error(
+            403,
+            &headers(&[
+                ("X-RateLimit-Remaining", "4999"),
+                ("X-Accepted-OAuth-Scopes", "notifications, repo"),
+                ("X-OAuth-Scopes", "read:user"),
+            ]),
+            r#"{"message":"Forbidden"}"#,
+            now,
+        );
+        assert!(matches!(scope, GitHubError::InsufficientScope(_)));
+        assert_eq!(scope.retry_at(), None);
+    }
+
+    #[test]
+    fn enterprise_urls_follow_api_base() {
+        assert_eq!(
+            normalize_api_base("ghe.example.com/"),
+            "https://ghe.example.com/api/v3"
+        );
+        assert_eq!(
+            normalize_api_base("https://ghe.example.com/api/v3"),
+            "https://ghe.example.com/api/v3"
+        );
+
+        let client = GitHubClient::with_api_base("ghp_test", Some("ghe.example.com")).unwrap();
+        assert_eq!(
+            client.enterprise_api_base(),
+            Some("https://ghe.example.com/api/v3")
+        );
+        assert_eq!(client.graphql_url(), "https://ghe.example.com/api/graphql");
+
+        let client = GitHubClient::with_api_base("ghp_test", None).unwrap();
+        assert_eq!(client.enterprise_api_base(), None);
+        assert_eq!(client.graphql_url(), "https://api.github.com/graphql");
+
+        assert_eq!(
+            parse_discussion_url("https://ghe.example.com/api/v3/repos/acme/app/discussions/7"),
+            Some(("acme".to_string(), "app".to_string(), 7))
+        );
+    }
+
+    #[test]
+    fn github_com_inputs_map_to_public_api() {
+        for input in [
+            "github.com",
+            "https://github.com/",
+            "api.github.com",
+            GITHUB_API_URL,
+        ] {
+            assert_eq!(normalize_api_base(input), GITHUB_API_URL, "{input}");
+            assert_eq!(enterprise_api_base_input(input), None, "{input}");
+        }
+        assert_eq!(enterprise_api_base_input("  "), None);
+
+        let client = GitHubClient::with_api_base("ghp_test", Some("github.com")).unwrap();
+        assert_eq!(client.enterprise_api_base(), None);
+
+        assert_eq!(account_key("octocat", None), "octocat");
+        assert_eq!(
+            account_key("octocat", Some("https://ghe.example.com/api/v3")),
+            "octocat@ghe.example.com"
+        );
+    }
+
+    #[test]
+    fn token_info_reads_scopes_and_expiry() {
+        let info = token_info_from_headers(&headers(&[
+            ("X-OAuth-Scopes", "read:user, notifications"),
+            (
+                "github-authentication-token-expiration",
+                "2030-07-12 19:32:51 UTC",
+            ),
+        ]));
+        assert_eq!(
+            info.scopes,
+            Some(vec!["read:user".to_string(), "notifications".to_string()])
+        );
+        assert_eq!(
+            info.expires_at.map(|dt| dt.to_rfc3339()),
+            Some("2030-07-12T19:32:51+00:00".to_string())
+        );
+        assert!(!info.lacks_notifications_scope());
+
+        let offset = parse_token_expiration("2030-07-12 12:32:51 -0700").unwrap();
+        assert_eq!(offset, info.expires_at.unwrap());
+
+        // Fine-grained tokens send no scopes header.
+        let fine_grained = token_info_from_headers(&HeaderMap::new());
+        assert_eq!(fine_grained, TokenInfo::default());
+        assert!(!fine_grained.lacks_notifications_scope());
+
+        let classic = token_info_from_headers(&headers(&[("X-OAuth-Scopes", "read:user")]));
+        assert!(classic.lacks_notifications_scope());
+    }
+
+    #[test]
+    fn next_page_url_follows_rel_next() {
+        let headers = link_headers(
+            r#"<https://api.github.com/notifications?per_page=100&page=2>; rel="next", <https://api.github.com/notifications?per_page=100&page=5>; rel="last""#,
+        );
+
+        assert_eq!(
+            next_page_url(&headers).as_deref(),
+            Some("https://api.github.com/notifications?per_page=100&page=2")
+        );
+    }
+
+    #[test]
+    fn next_page_url_absent_on_last_page() {
+        let headers = link_headers(
+            r#"<https://api.github.com/notifications?page=1>; rel="first", <https://api.github.com/notifications?page=4>; rel="prev""#,
+        );
+        assert_eq!(next_page_url(&headers), None);
+        assert_eq!(next_page_url(&HeaderMap::new()), None);
+    }
+}
@@ -0,0 +1,279 @@
+//! OAuth device authorization flow.
+//!
+//! Lets a user sign in by entering a short code on github.com instead of
+//! creating a Personal Access Token by hand:
+//! 1. `request_device_code` returns a user code and verification URL
+//! 2. the user approves the code in their browser
+//! 3. `poll_for_token` polls the token endpoint until GitHub issues a token
+
+use std::time::Duration;
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::client::api_host;
+
+/// OAuth App client ID baked in at build time, overridable at runtime.
+const BUILD_CLIENT_ID: Option<&str> = option_env!("GITTOP_OAUTH_CLIENT_ID");
+
+/// Scopes requested for device-flow tokens.
+const DEVICE_FLOW_SCOPES: &str = "notifications repo";
+
+/// Grant type for the device-flow token exchange (RFC 8628).
+const DEVICE_CODE_GRANT: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// Extra delay GitHub asks for on `slow_down`.
+const SLOW_DOWN_STEP_SECS: u64 = 5;
+
+/// Device-flow errors.
+#[derive(Debug, Error, Clone)]
+pub enum DeviceFlowError {
+    #[error("Browser sign-in is not configured for this build (set GITTOP_OAUTH_CLIENT_ID)")]
+    NotConfigured,
+
+    #[error("HTTP request failed: {0}")]
+    Request(String),
+
+    #[error("The sign-in code expired, please try again")]
+    Expired,
+
+    #[error("Sign-in was cancelled in the browser")]
+    AccessDenied,
+
+    #[error("GitHub OAuth error: {0}")]
+    OAuth(String),
+}
+
+impl From<reqwest::Error> for DeviceFlowError {
+    fn from(e: reqwest::Error) -> Self {
+        DeviceFlowError::Request(e.to_string())
+    }
+}
+
+/// Endpoints and client settings for the device flow.
+#[derive(Debug, Clone)]
+pub struct DeviceFlowConfig {
+    pub client_id: String,
+    pub device_code_url: String,
+    pub token_url: String,
+    pub scopes: String,
+}
+
+impl DeviceFlowConfig {
+    /// Configuration for github.com, or for a GHES instance when `api_base` is set.
+    ///
+    /// Returns `None` when no OAuth client ID is available.
+    pub fn for_api_base(api_base: Option<&str>) -> Option<Self> {
+        let client_id = std::env::var("GITTOP_OAUTH_CLIENT_ID")
+            .ok()
+            .or_else(|| BUILD_CLIENT_ID.map(String::from))
+            .filter(|id| !id.trim().is_empty())?;
+
+        let host = api_base.map_or("github.com", api_host);
+        Some(Self::with_endpoints(
+            client_id,
+            format!("https://{}/login/device/code", host),
+            format!("https://{}/login/oauth/access_token", host),
+        ))
+    }
+
+    /// Configuration with explicit endpoints (e.g. a local stand-in server).
+    pub fn with_endpoints(
+        client_id: impl Into<String>,
+        device_code_url: impl Into<String>,
+        token_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            device_code_url: device_code_url.into(),
+            token_url: token_url.into(),
+            scopes: DEVICE_FLOW_SCOPES.to_string(),
+        }
+    }
+}
+
+/// Response from the device code endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeviceCode {
+    pub device_code: String,
+    /// Short code the user types on the verification page.
+    pub user_code: String,
+    pub verification_uri: String,
+    /// Seconds until the codes expire.
+    pub expires_in: u64,
+    /// Minimum seconds between token polls.
+    pub interval: u64,
+}
+
+/// Token endpoint response: either a token or a pending/error state.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: Option<String>,
+    error: Option<String>,
+    error_description: Option<String>,
+}
+
+fn http_client() -> Result<reqwest::Client, DeviceFlowError> {
+    Ok(reqwest::Client::builder()
+        .user_agent("GitTop/0.1.0")
+        .build()?)
+}
+
+/// Starts the flow by requesting a device and user code.
+pub async fn request_device_code(config: &DeviceFlowConfig) -> Result<DeviceCode, DeviceFlowError> {
+    let response = http_client()?
+        .post(&config.device_code_url)
+        .header(reqwest::header::ACCEPT, "application/json")
+        .form(&[
+            ("client_id", config.client_id.as_str()),
+            ("scope", config.scopes.as_str()),
+        ])
+        .send()
+        .await?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(DeviceFlowError::OAuth(format!(
+            "device code request failed ({})",
+            status.as_u16()
+        )));
+    }
+
+    Ok(response.json().await?)
+}
+
+/// Polls the token endpoint until the user approves, denies, or the code expires.
+pub async fn poll_for_token(
+    config: &DeviceFlowConfig,
+    code: &DeviceCode,
+) -> Result<String, DeviceFlowError> {
+    let client = http_client()?;
+    let deadline = std::time::Instant::now() + Duration::from_secs(code.expires_in);
+    let mut interval = code.interval;
+
+    loop {
+        if std::time::Instant::now() >= deadline {
+            return Err(DeviceFlowError::Expired);
+        }
+        tokio::time::sleep(Duration::from_secs(interval)).await;
+
+        let response: TokenResponse = client
+            .post(&config.token_url)
+            .header(reqwest::header::ACCEPT, "application/json")
+            .form(&[
+                ("client_id", config.client_id.as_str()),
+                ("device_code", code.device_code.as_str()),
+                ("grant_type", DEVICE_CODE_GRANT),
+            ])
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        if let Some(token) = response.access_token {
+            return Ok(token);
+        }
+
+        match response.error.as_deref() {
+            Some("authorization_pending") => {}
+            Some("slow_down") => interval += SLOW_DOWN_STEP_SECS,
+            Some("expired_token") => return Err(DeviceFlowError::Expired),
+            Some("access_denied") => return Err(DeviceFlowError::AccessDenied),
+            Some(other) => {
+                return Err(DeviceFlowError::OAuth(
+                    response
+                        .error_description
+                        .unwrap_or_else(|| other.to_string()),
+                ));
+            }
+            None => {
+                return Err(DeviceFlowError::OAuth(
+                    "token response had neither a token nor an error".to_string(),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    /// Serves one canned JSON body per connection, in order, then stops.
+    fn stand_in_server(bodies: Vec<&'static str>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        std::thread::spawn(move || {
+            for body in bodies {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                // Drain the request so the client sees a clean response.
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                    if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = len.trim().parse().unwrap();
+                    }
+                }
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        format!("http://{}", addr)
+    }
+
+    fn test_code(expires_in: u64) -> DeviceCode {
+        DeviceCode {
+            device_code: "dev-123".to_string(),
+            user_code: "ABCD-1234".to_string(),
+            verification_uri: "https://github.com/login/device".to_string(),
+            expires_in,
+            interval: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn device_code_then_token_after_pending() {
+        let base = stand_in_server(vec![
+            r#"{"device_code":"dev-123","user_code":"ABCD-1234","verification_uri":"https://github.com/login/device","expires_in":900,"interval":0}"#,
+            r#"{"error":"authorization_pending"}"#,
+            r#"{"access_token":"gho_test","token_type":"bearer","scope":"notifications,repo"}"#,
+        ]);
+        let config = DeviceFlowConfig::with_endpoints(
+            "client",
+            format!("{}/login/device/code", base),
+            format!("{}/login/oauth/access_token", base),
+        );
+
+        let code = request_device_code(&config).await.unwrap();
+        assert_eq!(code.user_code, "ABCD-1234");
+
+        let token = poll_for_token(&config, &code).await.unwrap();
+        assert_eq!(token, "gho_test");
+    }
+
+    #[tokio::test]
+    async fn denied_authorization_is_reported() {
+        let base = stand_in_server(vec![r#"{"error":"access_denied"}"#]);
+        let config = DeviceFlowConfig::with_endpoints("client", "", format!("{}/token", base));
+
+        let result = poll_for_token(&config, &test_code(900)).await;
+        assert!(matches!(result, Err(DeviceFlowError::AccessDenied)));
+    }
+}
@@ -1,11 +1,14 @@
 //! Account Keyring - Secure per-account credential storage.
 //!
 //! Uses the system keyring to store GitHub PATs keyed by username.
-//! Format: service="gittop", user="gittop-{username}"
+//! Format: service="gittop", user="gittop-{username}" for github.com and
+//! user="gittop-{username}@{host}" for GitHub Enterprise Server accounts.
 
 use keyring::Entry;
 use thiserror::Error;
 
+use super::client::account_key;
+
 /// Service name for keyring storage.
 const SERVICE_NAME: &str = "gittop";
 
@@ -17,14 +20,15 @@ pub enum KeyringError {
 }
 
 /// Creates a keyring entry for a specific username.
-fn get_entry(username: &str) -> Result<Entry, KeyringError> {
-    let key = format!("gittop-{}", username);
+/// `api_base` is the account's GHES API base, `None` for github.com.
+fn get_entry(username: &str, api_base: Option<&str>) -> Result<Entry, KeyringError> {
+    let key = format!("gittop-{}", account_key(username, api_base));
     Entry::new(SERVICE_NAME, &key).map_err(|e| KeyringError::Internal(e.to_string()))
 }
 
 /// Saves a token for a specific account.
-pub fn save_token(username: &str, token: &str) -> Result<(), KeyringError> {
-    let entry = get_entry(username)?;
+pub fn save_token(username: &str, api_base: Option<&str>, token: &str) -> Result<(), KeyringError> {
+    let entry = get_entry(username, api_base)?;
     entry
         .set_password(token)
         .map_err(|e| KeyringError::Internal(e.to_string()))?;
@@ -32,8 +36,8 @@ pub fn save_token(username: &str, token: &str) -> Result<(), KeyringError> {
 }
 
 /// Loads the token for a specific account.
-pub fn load_token(username: &str) -> Result<Option<String>, KeyringError> {
-    let entry = get_entry(username)?;
+pub fn load_token(username: &str, api_base: Option<&str>) -> Result<Option<String>, KeyringError> {
+    let entry = get_entry(username, api_base)?;
     match entry.get_password() {
         Ok(token) => Ok(Some(token)),
         Err(keyring::Error::NoEntry) => Ok(None),
@@ -42,8 +46,8 @@ pub fn load_token(username: &str) -> Result<Option<String>, KeyringError> {
 }
 
 /// Deletes the token for a specific account.
-pub fn delete_token(username: &str) -> Result<(), KeyringError> {
-    let entry = get_entry(username)?;
+pub fn delete_token(username: &str, api_base: Option<&str>) -> Result<(), KeyringError> {
+    let entry = get_entry(username, api_base)?;
     match entry.delete_credential() {
         Ok(()) => Ok(()),
         Err(keyring::Error::NoEntry) => Ok(()), // Already deleted
@@ -2,6 +2,7 @@
 
 pub mod auth;
 pub mod client;
+pub mod device_flow;
 pub mod keyring;
 pub mod session;
 pub mod subject_details;
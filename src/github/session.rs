@@ -4,9 +4,12 @@
 
 use std::collections::HashMap;
 
-use super::client::{GitHubClient, GitHubError};
+use chrono::{DateTime, Utc};
+
+use super::client::{GitHubClient, GitHubError, account_key};
 use super::keyring::{self, KeyringError};
 use super::types::UserInfo;
+use crate::cache::DiskCache;
 use thiserror::Error;
 
 /// Session-related errors.
@@ -30,11 +33,99 @@ pub struct Session {
     pub user: UserInfo,
 }
 
+impl Session {
+    /// Account key (username plus GHES host) identifying this session.
+    pub fn key(&self) -> String {
+        account_key(&self.username, self.client.enterprise_api_base())
+    }
+}
+
+/// Why an account could not be fully restored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttentionReason {
+    /// GitHub was unreachable; retried automatically.
+    Offline,
+    /// GitHub rejected the token; the user has to sign in again.
+    Revoked,
+    /// No token in the keyring for this account.
+    MissingToken,
+    /// Any other failure (keyring, server errors, rate limits); retried automatically.
+    Failed(String),
+}
+
+impl AttentionReason {
+    fn from_error(error: &SessionError) -> Self {
+        match error {
+            SessionError::GitHub(GitHubError::Request(_)) => Self::Offline,
+            SessionError::GitHub(GitHubError::Unauthorized) => Self::Revoked,
+            SessionError::AccountNotFound(_) => Self::MissingToken,
+            other => Self::Failed(other.to_string()),
+        }
+    }
+
+    /// Whether retrying can fix this without user action.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::Offline | Self::Failed(_))
+    }
+}
+
+/// An account that is kept in settings but needs attention before it works normally.
+#[derive(Debug, Clone)]
+pub struct AccountAttention {
+    pub reason: AttentionReason,
+    username: String,
+    api_base: Option<String>,
+    attempts: u32,
+    /// Next automatic retry; `None` while a retry is running or for non-transient reasons.
+    pub retry_at: Option<DateTime<Utc>>,
+}
+
+impl AccountAttention {
+    /// First retry delay; doubles per failed attempt.
+    const RETRY_BASE_SECS: i64 = 30;
+    /// Upper bound for the retry delay.
+    const RETRY_MAX_SECS: i64 = 15 * 60;
+
+    fn new(
+        reason: AttentionReason,
+        username: String,
+        api_base: Option<String>,
+        attempts: u32,
+    ) -> Self {
+        let retry_at = reason.is_transient().then(|| {
+            let delay = (Self::RETRY_BASE_SECS << attempts.min(10)).min(Self::RETRY_MAX_SECS);
+            Utc::now() + chrono::Duration::seconds(delay)
+        });
+        Self {
+            reason,
+            username,
+            api_base,
+            attempts,
+            retry_at,
+        }
+    }
+
+    /// Short description for the sidebar badge.
+    pub fn summary(&self) -> String {
+        match &self.reason {
+            AttentionReason::Offline => "Offline, retrying".to_string(),
+            AttentionReason::Revoked => "Token revoked, sign in again".to_string(),
+            AttentionReason::MissingToken => "Token missing, sign in again".to_string(),
+            AttentionReason::Failed(e) => format!("Unavailable: {}", e),
+        }
+    }
+}
+
 /// Manages multiple GitHub sessions.
+///
+/// Sessions are keyed by [`account_key`], so the same login on github.com and on a
+/// GitHub Enterprise Server host are kept apart.
 #[derive(Debug, Clone, Default)]
 pub struct SessionManager {
     sessions: HashMap<String, Session>,
     primary: Option<String>,
+    /// Accounts that failed to restore or validate, keyed by account key.
+    attention: HashMap<String, AccountAttention>,
 }
 
 impl SessionManager {
@@ -43,46 +134,125 @@ impl SessionManager {
         Self::default()
     }
 
-    /// Restore a session for a known username (loads token from keyring).
-    pub async fn restore_account(&mut self, username: &str) -> Result<(), SessionError> {
-        let token = keyring::load_token(username)?
-            .ok_or_else(|| SessionError::AccountNotFound(username.to_string()))?;
-
-        // Validate the token using GitHubClient
-        let (client, user) = match GitHubClient::validate_token(&token).await {
-            Ok((client, user)) => (client, user),
-            Err(GitHubError::Unauthorized) => {
-                // Token expired, clean up
-                let _ = keyring::delete_token(username);
-                return Err(SessionError::AccountNotFound(username.to_string()));
+    /// Restore a session for a known account from the keyring token and the cached
+    /// profile, without touching the network.
+    /// `api_base` is the account's GitHub Enterprise Server API base, `None` for github.com.
+    ///
+    /// The token is validated afterwards with [`Self::load_session`]. Only a missing token
+    /// or a keyring failure leaves the account without a session; it is then marked as
+    /// needing attention. The token is never deleted.
+    pub fn restore_cached(
+        &mut self,
+        username: &str,
+        api_base: Option<&str>,
+    ) -> Result<(), SessionError> {
+        let token = match keyring::load_token(username, api_base).map_err(SessionError::from) {
+            Ok(Some(token)) => token,
+            Ok(None) => {
+                let error = SessionError::AccountNotFound(username.to_string());
+                self.mark_attention(username, api_base, &error);
+                return Err(error);
+            }
+            Err(e) => {
+                self.mark_attention(username, api_base, &e);
+                return Err(e);
             }
-            Err(e) => return Err(SessionError::GitHub(e)),
         };
 
-        // Create session
-        let session = Session {
+        let client = GitHubClient::with_api_base(&token, api_base)?;
+        let user = DiskCache::shared()
+            .and_then(|cache| {
+                cache
+                    .load_user(&account_key(username, api_base))
+                    .ok()
+                    .flatten()
+            })
+            .unwrap_or_else(|| placeholder_user(username));
+        self.insert(Session {
             username: username.to_string(),
             client,
             user,
-        };
+        });
+        Ok(())
+    }
 
-        // If this is the first account, make it primary
-        if self.sessions.is_empty() {
-            self.primary = Some(username.to_string());
+    /// Load the token for an account and validate it against GitHub.
+    pub async fn load_session(
+        username: &str,
+        api_base: Option<&str>,
+    ) -> Result<Session, SessionError> {
+        let token = keyring::load_token(username, api_base)?
+            .ok_or_else(|| SessionError::AccountNotFound(username.to_string()))?;
+        Self::validate_session(username, &token, api_base).await
+    }
+
+    async fn validate_session(
+        username: &str,
+        token: &str,
+        api_base: Option<&str>,
+    ) -> Result<Session, SessionError> {
+        let (client, user) = GitHubClient::validate_token(token, api_base).await?;
+        if let Some(cache) = DiskCache::shared() {
+            let _ = cache.save_user(&account_key(username, api_base), &user);
         }
+        Ok(Session {
+            username: username.to_string(),
+            client,
+            user,
+        })
+    }
+
+    /// Record a failed restore or retry, scheduling the next retry with backoff.
+    pub fn mark_attention(&mut self, username: &str, api_base: Option<&str>, error: &SessionError) {
+        let key = account_key(username, api_base);
+        let attempts = self
+            .attention
+            .get(&key)
+            .map_or(0, |a| a.attempts.saturating_add(1));
+        let attention = AccountAttention::new(
+            AttentionReason::from_error(error),
+            username.to_string(),
+            api_base.map(String::from),
+            attempts,
+        );
+        self.attention.insert(key, attention);
+    }
 
-        self.sessions.insert(username.to_string(), session);
+    /// Accounts whose retry is due, as `(username, api_base)`.
+    /// Their retry time is cleared so the same retry isn't started twice.
+    pub fn take_due_retries(&mut self, now: DateTime<Utc>) -> Vec<(String, Option<String>)> {
+        self.attention
+            .iter_mut()
+            .filter(|(_, a)| a.retry_at.is_some_and(|at| at <= now))
+            .map(|(_, a)| {
+                a.retry_at = None;
+                (a.username.clone(), a.api_base.clone())
+            })
+            .collect()
+    }
 
-        Ok(())
+    /// Attention state for an account key, if it needs any.
+    pub fn attention(&self, key: &str) -> Option<&AccountAttention> {
+        self.attention.get(key)
     }
 
-    /// Remove an account (also deletes from keyring).
-    pub fn remove_account(&mut self, username: &str) -> Result<(), SessionError> {
-        self.sessions.remove(username);
-        keyring::delete_token(username)?;
+    /// Remove an account by key (also deletes from keyring).
+    pub fn remove_account(&mut self, key: &str) -> Result<(), SessionError> {
+        let attention = self.attention.remove(key);
+        let account = self
+            .sessions
+            .remove(key)
+            .map(|s| {
+                let api_base = s.client.enterprise_api_base().map(String::from);
+                (s.username, api_base)
+            })
+            .or_else(|| attention.map(|a| (a.username, a.api_base)));
+        if let Some((username, api_base)) = account {
+            keyring::delete_token(&username, api_base.as_deref())?;
+        }
 
         // If we removed the primary, pick a new one
-        if self.primary.as_deref() == Some(username) {
+        if self.primary.as_deref() == Some(key) {
             self.primary = self.sessions.keys().next().cloned();
         }
 
@@ -90,13 +260,23 @@ impl SessionManager {
     }
 
     /// Add a session manually (e.g. after restoration).
+    /// A freshly validated session clears any attention state for the account.
     pub fn add_session(&mut self, session: Session) {
-        let username = session.username.clone();
+        let key = session.key();
+        if let Some(cache) = DiskCache::shared() {
+            let _ = cache.save_user(&key, &session.user);
+        }
+        self.attention.remove(&key);
+        self.insert(session);
+    }
+
+    fn insert(&mut self, session: Session) {
+        let key = session.key();
         // If this is the first account, make it primary
         if self.sessions.is_empty() {
-            self.primary = Some(username.clone());
+            self.primary = Some(key.clone());
         }
-        self.sessions.insert(username, session);
+        self.sessions.insert(key, session);
     }
 
     /// Get the primary session.
@@ -113,23 +293,22 @@ impl SessionManager {
         self.sessions.get_mut(name)
     }
 
-    /// Set which account is primary.
+    /// Set which account is primary, by account key.
     #[allow(dead_code)]
-    pub fn set_primary(&mut self, username: &str) {
-        if self.sessions.contains_key(username) {
-            self.primary = Some(username.to_string());
+    pub fn set_primary(&mut self, key: &str) {
+        if self.sessions.contains_key(key) {
+            self.primary = Some(key.to_string());
         }
     }
 
-    /// Get all active session usernames.
-    pub fn usernames(&self) -> impl Iterator<Item = &str> {
+    /// Get all active session account keys.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
         self.sessions.keys().map(String::as_str)
     }
 
-    /// Get a specific session by username.
-    #[allow(dead_code)]
-    pub fn get(&self, username: &str) -> Option<&Session> {
-        self.sessions.get(username)
+    /// Get a specific session by account key.
+    pub fn get(&self, key: &str) -> Option<&Session> {
+        self.sessions.get(key)
     }
 
     /// Number of active sessions.
@@ -138,3 +317,64 @@ impl SessionManager {
         self.sessions.len()
     }
 }
+
+/// Minimal profile for an account restored offline without a cached profile.
+fn placeholder_user(username: &str) -> UserInfo {
+    UserInfo {
+        login: username.to_string(),
+        name: None,
+        avatar_url: String::new(),
+        html_url: String::new(),
+        token: Default::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attention_backoff_and_reasons() {
+        let offline = SessionError::GitHub(GitHubError::Request("timeout".into()));
+        let revoked = SessionError::GitHub(GitHubError::Unauthorized);
+
+        let mut sessions = SessionManager::new();
+        sessions.mark_attention("octocat", None, &offline);
+        let first = sessions.attention("octocat").unwrap().retry_at.unwrap();
+        sessions.mark_attention("octocat", None, &offline);
+        let second = sessions.attention("octocat").unwrap().retry_at.unwrap();
+        assert!(second - first >= chrono::Duration::seconds(29));
+
+        // Due retries are handed out once.
+        let later = Utc::now() + chrono::Duration::hours(1);
+        assert_eq!(sessions.take_due_retries(later).len(), 1);
+        assert!(sessions.take_due_retries(later).is_empty());
+
+        // Revoked tokens wait for the user instead of retrying.
+        sessions.mark_attention("octocat", None, &revoked);
+        let attention = sessions.attention("octocat").unwrap();
+        assert_eq!(attention.reason, AttentionReason::Revoked);
+        assert!(attention.retry_at.is_none());
+    }
+
+    #[test]
+    fn test_same_login_on_two_hosts() {
+        let session = |api_base: Option<&str>| Session {
+            username: "octocat".to_string(),
+            client: GitHubClient::with_api_base("ghp_test", api_base).unwrap(),
+            user: placeholder_user("octocat"),
+        };
+
+        let mut sessions = SessionManager::new();
+        sessions.insert(session(None));
+        sessions.insert(session(Some("ghe.example.com")));
+        assert_eq!(sessions.len(), 2);
+        assert!(sessions.get("octocat").is_some());
+        assert!(sessions.get("octocat@ghe.example.com").is_some());
+
+        let offline = SessionError::GitHub(GitHubError::Request("timeout".into()));
+        sessions.mark_attention("octocat", Some("https://ghe.example.com/api/v3"), &offline);
+        assert!(sessions.attention("octocat").is_none());
+        assert!(sessions.attention("octocat@ghe.example.com").is_some());
+    }
+}
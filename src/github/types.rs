@@ -10,6 +10,68 @@ pub struct UserInfo {
     pub name: Option<String>,
     pub avatar_url: String,
     pub html_url: String,
+    /// Scopes and expiry of the token used to authenticate.
+    #[serde(default)]
+    pub token: TokenInfo,
+}
+
+/// Token metadata captured from response headers when the token is validated.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TokenInfo {
+    /// Granted scopes from `X-OAuth-Scopes`.
+    /// `None` for fine-grained tokens, which don't report scopes.
+    pub scopes: Option<Vec<String>>,
+    /// Expiry from `github-authentication-token-expiration`, if the token expires.
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl TokenInfo {
+    /// How many days ahead of expiry to start warning.
+    pub const EXPIRY_WARNING_DAYS: i64 = 7;
+
+    /// Whether a classic token lacks access to the notifications API.
+    /// The `repo` scope also grants it.
+    pub fn lacks_notifications_scope(&self) -> bool {
+        self.scopes.as_ref().is_some_and(|scopes| {
+            !scopes
+                .iter()
+                .any(|scope| scope == "notifications" || scope == "repo")
+        })
+    }
+
+    /// Whole days until the token expires (negative once expired).
+    pub fn days_until_expiry(&self) -> Option<i64> {
+        self.expires_at
+            .map(|expires| expires.signed_duration_since(Utc::now()).num_days())
+    }
+
+    /// Whether the token expires within the warning window.
+    pub fn expires_soon(&self) -> bool {
+        self.days_until_expiry()
+            .is_some_and(|days| days <= Self::EXPIRY_WARNING_DAYS)
+    }
+
+    /// Short user-facing warning about this token, if anything needs attention.
+    /// A missing scope comes first, since nothing loads until it's fixed.
+    pub fn warning(&self) -> Option<String> {
+        if self.lacks_notifications_scope() {
+            return Some("Token is missing the 'notifications' scope".to_string());
+        }
+        self.expiry_warning()
+    }
+
+    /// Short user-facing warning about the token's expiry alone.
+    pub fn expiry_warning(&self) -> Option<String> {
+        match self.days_until_expiry() {
+            Some(days) if days < 0 => Some("Token has expired".to_string()),
+            Some(0) => Some("Token expires today".to_string()),
+            Some(1) => Some("Token expires tomorrow".to_string()),
+            Some(days) if days <= Self::EXPIRY_WARNING_DAYS => {
+                Some(format!("Token expires in {} days", days))
+            }
+            _ => None,
+        }
+    }
 }
 
 /// A GitHub notification from the notifications API.
@@ -57,6 +119,16 @@ impl std::fmt::Display for SubjectType {
 }
 
 impl SubjectType {
+    pub const ALL: &'static [Self] = &[
+        Self::Issue,
+        Self::PullRequest,
+        Self::Release,
+        Self::Discussion,
+        Self::CheckSuite,
+        Self::Commit,
+        Self::RepositoryVulnerabilityAlert,
+    ];
+
     /// Returns a human-readable label for the subject type.
     #[allow(unused)]
     pub fn label(&self) -> &'static str {
@@ -178,7 +250,8 @@ pub struct Owner {
 }
 
 /// Frontend-friendly notification format for the UI.
-#[derive(Debug, Clone, PartialEq)]
+/// Serializable so the last fetched list can be cached for offline startup.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NotificationView {
     pub id: String,
     pub title: String,
@@ -194,7 +267,6 @@ pub struct NotificationView {
     pub latest_comment_url: Option<String>,
     #[allow(dead_code)] // Reserved for avatar display
     pub avatar_url: String,
-    #[allow(dead_code)] // Reserved for private repo indicator
     pub is_private: bool,
     /// The GitHub account (username) this notification belongs to.
     pub account: String,
@@ -3,9 +3,13 @@
 //! Stores user preferences like icon theme, app theme, and account list.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
+use crate::github::TokenInfo;
+use crate::github::client::account_key;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum IconTheme {
     #[default]
@@ -13,6 +17,52 @@ pub enum IconTheme {
     Emoji,
 }
 
+/// How the notification list is split into groups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum GroupingMode {
+    /// Today / This Week / Older.
+    #[default]
+    Time,
+    /// High / Normal / Low rule priority.
+    Priority,
+    /// One group per repository.
+    Repository,
+    /// One group per repository owner.
+    Organization,
+    /// One group per notification reason.
+    Reason,
+    /// One group per subject type.
+    SubjectType,
+    /// One group per signed-in account.
+    Account,
+}
+
+impl GroupingMode {
+    pub const ALL: &'static [Self] = &[
+        Self::Time,
+        Self::Priority,
+        Self::Repository,
+        Self::Organization,
+        Self::Reason,
+        Self::SubjectType,
+        Self::Account,
+    ];
+}
+
+impl std::fmt::Display for GroupingMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Time => write!(f, "By time"),
+            Self::Priority => write!(f, "By priority"),
+            Self::Repository => write!(f, "By repository"),
+            Self::Organization => write!(f, "By organization"),
+            Self::Reason => write!(f, "By reason"),
+            Self::SubjectType => write!(f, "By type"),
+            Self::Account => write!(f, "By account"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum AppTheme {
@@ -93,6 +143,20 @@ impl TryFrom<u8> for AppTheme {
 pub struct StoredAccount {
     pub username: String,
     pub is_active: bool,
+    /// GitHub Enterprise Server API base (`https://ghe.example.com/api/v3`).
+    /// `None` for github.com accounts.
+    #[serde(default)]
+    pub api_base: Option<String>,
+    /// Token scopes and expiry as of the last successful validation.
+    #[serde(default)]
+    pub token: TokenInfo,
+}
+
+impl StoredAccount {
+    /// Account key (username plus GHES host), see [`account_key`].
+    pub fn key(&self) -> String {
+        account_key(&self.username, self.api_base.as_deref())
+    }
 }
 
 /// Application settings.
@@ -122,6 +186,14 @@ pub struct AppSettings {
     pub power_mode: bool,
     #[serde(default = "default_show_details_panel")]
     pub show_details_panel: bool,
+    /// Upper bound on notification pages (100 items each) fetched per refresh.
+    #[serde(default = "default_max_notification_pages")]
+    pub max_notification_pages: usize,
+    #[serde(default)]
+    pub grouping_mode: GroupingMode,
+    /// Groups the user expanded or collapsed, by grouping mode and group title.
+    #[serde(default)]
+    pub group_expansion: HashMap<GroupingMode, HashMap<String, bool>>,
 }
 
 fn default_minimize_to_tray() -> bool {
@@ -152,6 +224,10 @@ fn default_show_details_panel() -> bool {
     true
 }
 
+fn default_max_notification_pages() -> usize {
+    10
+}
+
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
@@ -168,6 +244,9 @@ impl Default for AppSettings {
             window_height: 640.0,
             power_mode: false,
             show_details_panel: true,
+            max_notification_pages: 10,
+            grouping_mode: GroupingMode::default(),
+            group_expansion: HashMap::new(),
         }
     }
 }
@@ -197,10 +276,13 @@ impl AppSettings {
         fs::write(path, serde_json::to_string_pretty(self)?)
     }
 
-    pub fn set_active_account(&mut self, username: &str) {
+    /// Mark an account as active, adding it if it isn't stored yet.
+    /// `api_base` is the account's GHES API base, `None` for github.com.
+    pub fn set_active_account(&mut self, username: &str, api_base: Option<&str>) {
+        let key = account_key(username, api_base);
         let mut found = false;
         for acc in &mut self.accounts {
-            acc.is_active = acc.username == username;
+            acc.is_active = acc.key() == key;
             found |= acc.is_active;
         }
 
@@ -208,13 +290,27 @@ impl AppSettings {
             self.accounts.push(StoredAccount {
                 username: username.to_string(),
                 is_active: true,
+                api_base: api_base.map(String::from),
+                token: TokenInfo::default(),
             });
         }
     }
 
-    /// Remove an account by username.
-    pub fn remove_account(&mut self, username: &str) {
-        self.accounts.retain(|a| a.username != username);
+    /// Record the latest token scopes and expiry for an account key.
+    pub fn set_account_token(&mut self, key: &str, token: &TokenInfo) {
+        if let Some(acc) = self.accounts.iter_mut().find(|a| a.key() == key) {
+            acc.token = token.clone();
+        }
+    }
+
+    /// The stored account with this key.
+    pub fn account(&self, key: &str) -> Option<&StoredAccount> {
+        self.accounts.iter().find(|a| a.key() == key)
+    }
+
+    /// Remove an account by key.
+    pub fn remove_account(&mut self, key: &str) {
+        self.accounts.retain(|a| a.key() != key);
     }
 
     /// Apply theme and font scale settings globally.
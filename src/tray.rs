@@ -1,13 +1,29 @@
 //! System tray management for GitTop.
 
+use std::cell::RefCell;
 use std::sync::OnceLock;
 use tray_icon::{
     Icon, TrayIcon, TrayIconBuilder, TrayIconEvent,
-    menu::{Menu, MenuEvent, MenuId, MenuItem},
+    menu::{CheckMenuItem, Menu, MenuEvent, MenuId, MenuItem, Submenu},
 };
 
+use crate::ui::RuleSetStore;
+
 static MENU_IDS: OnceLock<MenuIds> = OnceLock::new();
 
+/// Menu ID prefix for rule set entries, followed by the set name.
+const RULE_SET_PREFIX: &str = "ruleset:";
+
+thread_local! {
+    /// Menu items aren't `Send`, so the rule set submenu lives on the UI thread.
+    static RULE_SET_MENU: RefCell<Option<RuleSetMenu>> = const { RefCell::new(None) };
+}
+
+struct RuleSetMenu {
+    submenu: Submenu,
+    items: Vec<CheckMenuItem>,
+}
+
 #[derive(Debug)]
 struct MenuIds {
     show: MenuId,
@@ -17,6 +33,7 @@ struct MenuIds {
 #[derive(Debug, Clone)]
 pub enum TrayCommand {
     ShowWindow,
+    SelectRuleSet(String),
     Quit,
 }
 
@@ -28,6 +45,7 @@ pub struct TrayManager {
 impl TrayManager {
     pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
         let show_item = MenuItem::new("Show GitTop", true, None);
+        let rule_sets = Submenu::new("Rule set", true);
         let quit_item = MenuItem::new("Quit", true, None);
 
         MENU_IDS
@@ -39,8 +57,15 @@ impl TrayManager {
 
         let menu = Menu::new();
         menu.append(&show_item)?;
+        menu.append(&rule_sets)?;
         menu.append(&quit_item)?;
 
+        RULE_SET_MENU.set(Some(RuleSetMenu {
+            submenu: rule_sets,
+            items: Vec::new(),
+        }));
+        Self::refresh_rule_sets();
+
         let icon = Self::create_icon()?;
         let tray = TrayIconBuilder::new()
             .with_menu(Box::new(menu))
@@ -67,6 +92,33 @@ impl TrayManager {
         Icon::from_rgba(img.into_raw(), width, height).map_err(Into::into)
     }
 
+    /// Rebuild the rule set submenu from disk, checking the set in force.
+    pub fn refresh_rule_sets() {
+        let store = RuleSetStore::load();
+        let active = &store.active_set(&chrono::Local::now()).name;
+
+        RULE_SET_MENU.with_borrow_mut(|menu| {
+            let Some(menu) = menu else {
+                return;
+            };
+            for item in menu.items.drain(..) {
+                let _ = menu.submenu.remove(&item);
+            }
+            for name in store.names() {
+                let item = CheckMenuItem::with_id(
+                    format!("{RULE_SET_PREFIX}{name}"),
+                    &name,
+                    true,
+                    name == *active,
+                    None,
+                );
+                if menu.submenu.append(&item).is_ok() {
+                    menu.items.push(item);
+                }
+            }
+        });
+    }
+
     pub fn poll_global_events() -> Option<TrayCommand> {
         // On Linux, pump GTK events so AppIndicator can process D-Bus messages.
         // Use main_iteration_do(false) for NON-BLOCKING iteration to avoid
@@ -83,6 +135,9 @@ impl TrayManager {
 
     fn poll_menu_events() -> Option<TrayCommand> {
         let event = MenuEvent::receiver().try_recv().ok()?;
+        if let Some(name) = event.id.0.strip_prefix(RULE_SET_PREFIX) {
+            return Some(TrayCommand::SelectRuleSet(name.to_string()));
+        }
         let ids = MENU_IDS.get()?;
 
         [
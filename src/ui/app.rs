@@ -1,21 +1,23 @@
 //! Main application state and logic.
 
+use std::collections::{HashMap, HashSet};
 use std::time::Duration;
 
 use iced::window::Id as WindowId;
 use iced::{Element, Event, Subscription, Task, Theme, event, exit, time, window};
 
-use crate::github::{SessionManager, auth};
+use crate::github::session::{Session, SessionError};
+use crate::github::{GitHubClient, SessionManager, auth};
 use crate::settings::AppSettings;
 use crate::tray::{TrayCommand, TrayManager};
-use crate::ui::screens::settings::rule_engine::rules::NotificationRuleSet;
+use crate::ui::screens::settings::rule_engine::rules::{NotificationRuleSet, RuleSetStore};
 use crate::ui::screens::{
     login::{LoginMessage, LoginScreen},
     notifications::NotificationsScreen,
-    notifications::messages::{NavigationMessage, NotificationMessage},
+    notifications::messages::{FilterMessage, NavigationMessage, NotificationMessage, ViewMessage},
     settings::{
         SettingsMessage, SettingsScreen,
-        rule_engine::{RuleEngineMessage, RuleEngineScreen},
+        rule_engine::{RuleEngineMessage, RuleEngineScreen, RuleSetMessage},
     },
 };
 use crate::ui::widgets::power::{details_panel, status_bar, top_bar};
@@ -29,12 +31,21 @@ use crate::ui::window_state;
 pub struct AppContext {
     pub settings: AppSettings,
     pub sessions: SessionManager,
+    /// Accounts already warned about an expiring token this run.
+    token_warnings_sent: HashSet<String>,
 }
 
 impl AppContext {
     /// Create a new context.
     pub fn new(settings: AppSettings, sessions: SessionManager) -> Self {
-        Self { settings, sessions }
+        let mut ctx = Self {
+            settings,
+            sessions,
+            token_warnings_sent: HashSet::new(),
+        };
+        ctx.sync_token_info();
+        ctx.warn_expiring_tokens();
+        ctx
     }
 
     /// Clone with updated settings.
@@ -42,12 +53,78 @@ impl AppContext {
         Self {
             settings,
             sessions: self.sessions.clone(),
+            token_warnings_sent: self.token_warnings_sent.clone(),
+        }
+    }
+
+    /// Copy token scopes/expiry from live sessions into the stored accounts.
+    fn sync_token_info(&mut self) {
+        let mut changed = false;
+        for key in self.account_names() {
+            if let Some(session) = self.sessions.get(&key)
+                && self
+                    .settings
+                    .account(&key)
+                    .is_some_and(|a| a.token != session.user.token)
+            {
+                self.settings.set_account_token(&key, &session.user.token);
+                changed = true;
+            }
+        }
+        if changed {
+            self.settings.save_silent();
+        }
+    }
+
+    /// Send a desktop notification for tokens about to expire (once per account per run).
+    pub fn warn_expiring_tokens(&mut self) {
+        for key in self.account_names() {
+            let Some(session) = self.sessions.get(&key) else {
+                continue;
+            };
+            if !session.user.token.expires_soon() || self.token_warnings_sent.contains(&key) {
+                continue;
+            }
+            let body = session
+                .user
+                .token
+                .expiry_warning()
+                .unwrap_or_else(|| "Token expires soon".to_string());
+            let title = format!("GitTop: token for {} needs renewal", key);
+            if let Err(e) = crate::platform::notify(&title, &body, None) {
+                eprintln!("Failed to send notification: {}", e);
+            }
+            self.token_warnings_sent.insert(key);
         }
     }
 
-    /// Get list of account usernames.
+    /// Accounts needing attention, as `(account key, summary)` in settings order.
+    pub fn attention_summaries(&self) -> Vec<(String, String)> {
+        self.settings
+            .accounts
+            .iter()
+            .filter_map(|account| {
+                let key = account.key();
+                let attention = self.sessions.attention(&key)?;
+                Some((key, attention.summary()))
+            })
+            .collect()
+    }
+
+    /// Clients for every signed-in account, keyed by account key.
+    pub fn account_clients(&self) -> HashMap<String, GitHubClient> {
+        self.account_names()
+            .into_iter()
+            .filter_map(|key| {
+                let client = self.sessions.get(&key)?.client.clone();
+                Some((key, client))
+            })
+            .collect()
+    }
+
+    /// Get list of account keys (username plus GHES host).
     pub fn account_names(&self) -> Vec<String> {
-        self.sessions.usernames().map(String::from).collect()
+        self.sessions.keys().map(String::from).collect()
     }
 }
 
@@ -60,7 +137,7 @@ pub enum Screen {
     /// Main notifications screen.
     Notifications(Box<NotificationsScreen>),
     /// Settings screen.
-    Settings(SettingsScreen),
+    Settings(Box<SettingsScreen>),
     /// Rule Engine screen.
     RuleEngine(Box<RuleEngineScreen>, RuleEngineOrigin),
 }
@@ -107,6 +184,9 @@ pub enum Message {
     RestoreComplete(SessionManager),
     /// Session restored (for account addition).
     SessionRestored(Result<crate::github::session::Session, String>),
+    /// Background validation of a restored account, or a retry for one needing
+    /// attention: username, API base, result.
+    AccountRetried(String, Option<String>, Result<Session, SessionError>),
 
     // -- UI Screens --
     /// Login screen messages.
@@ -135,50 +215,54 @@ const MINIMIZED_SIZE_THRESHOLD: f32 = 100.0;
 const TRAY_POLL_INTERVAL_HIDDEN_MS: u64 = 500;
 const TRAY_POLL_INTERVAL_ACTIVE_MS: u64 = 100;
 
-/// Auto-refresh interval for notifications.
+/// Auto-refresh interval for notifications, used until GitHub sends `X-Poll-Interval`.
 const REFRESH_INTERVAL_SECS: u64 = 60;
 
 impl App {
     pub fn new() -> (Self, Task<Message>) {
-        (
-            App::Loading,
-            Task::perform(
-                async {
-                    let mut sessions = SessionManager::new();
-                    let mut settings = AppSettings::load();
-                    let mut failed_accounts = Vec::new();
-
-                    // Restore all accounts, track failures
-                    for account in &settings.accounts {
-                        if sessions.restore_account(&account.username).await.is_err() {
-                            failed_accounts.push(account.username.clone());
-                        }
-                    }
+        let mut sessions = SessionManager::new();
+        let settings = AppSettings::load();
+
+        // Restore all accounts from the keyring and cached profiles so the cached inbox
+        // shows right away. Tokens are validated in the background; failures are kept as
+        // "needs attention" instead of being removed, so a boot without network loses nothing.
+        let mut validations = Vec::new();
+        for account in &settings.accounts {
+            match sessions.restore_cached(&account.username, account.api_base.as_deref()) {
+                Ok(()) => validations.push(Self::validate_account(
+                    account.username.clone(),
+                    account.api_base.clone(),
+                )),
+                Err(e) => eprintln!("Could not restore {}: {}", account.username, e),
+            }
+        }
 
-                    // Remove corrupted/failed accounts from settings
-                    if !failed_accounts.is_empty() {
-                        for username in failed_accounts {
-                            settings.remove_account(&username);
-                        }
-                        settings.save_silent();
-                    }
+        // Set primary: prefer marked active, fallback to first available
+        let primary = settings
+            .accounts
+            .iter()
+            .find(|a| a.is_active)
+            .or_else(|| settings.accounts.first())
+            .map(|a| a.key());
 
-                    // Set primary: prefer marked active, fallback to first available
-                    let primary = settings
-                        .accounts
-                        .iter()
-                        .find(|a| a.is_active)
-                        .or_else(|| settings.accounts.first())
-                        .map(|a| a.username.clone());
+        if let Some(key) = primary {
+            sessions.set_primary(&key);
+        }
 
-                    if let Some(username) = primary {
-                        sessions.set_primary(&username);
-                    }
+        let mut app = App::Loading;
+        let start_task = app.update_loading(Message::RestoreComplete(sessions));
+        validations.push(start_task);
+        (app, Task::batch(validations))
+    }
 
-                    sessions
-                },
-                Message::RestoreComplete,
-            ),
+    /// Validate an account's token against GitHub in the background.
+    fn validate_account(username: String, api_base: Option<String>) -> Task<Message> {
+        Task::perform(
+            async move {
+                let result = SessionManager::load_session(&username, api_base.as_deref()).await;
+                (username, api_base, result)
+            },
+            |(username, api_base, result)| Message::AccountRetried(username, api_base, result),
         )
     }
 
@@ -189,6 +273,7 @@ impl App {
             Message::Tick => return self.handle_tick(),
             Message::TrayPoll => return self.handle_tray_poll(),
             Message::WindowEvent(id, event) => return self.handle_window_event(*id, event.clone()),
+            Message::AccountRetried(..) => return self.handle_account_retried(message),
             _ => {}
         }
 
@@ -212,7 +297,8 @@ impl App {
         if let Message::RestoreComplete(sessions) = message {
             if let Some(session) = sessions.primary() {
                 let mut settings = AppSettings::load();
-                settings.set_active_account(&session.username);
+                settings
+                    .set_active_account(&session.username, session.client.enterprise_api_base());
                 settings.save_silent();
                 settings.apply_theme();
 
@@ -246,12 +332,16 @@ impl App {
         match login_msg {
             LoginMessage::LoginSuccess(client, user) => {
                 let mut settings = AppSettings::load();
-                settings.set_active_account(&user.login);
+                settings.set_active_account(&user.login, client.enterprise_api_base());
                 settings.save_silent();
                 settings.apply_theme();
 
                 let token = client.token().to_string();
-                let _ = crate::github::keyring::save_token(&user.login, &token);
+                let _ = crate::github::keyring::save_token(
+                    &user.login,
+                    client.enterprise_api_base(),
+                    &token,
+                );
 
                 // Create session and add to manager so navigation works
                 let mut sessions = SessionManager::new();
@@ -288,11 +378,11 @@ impl App {
         match notif_msg {
             NotificationMessage::Navigation(NavigationMessage::Logout) => {
                 // Remove only the current account, not all accounts
-                let current_username = ctx.sessions.primary().map(|s| s.username.clone());
+                let current_key = ctx.sessions.primary().map(|s| s.key());
 
-                if let Some(username) = current_username {
-                    let _ = ctx.sessions.remove_account(&username);
-                    ctx.settings.remove_account(&username);
+                if let Some(key) = current_key {
+                    let _ = ctx.sessions.remove_account(&key);
+                    ctx.settings.remove_account(&key);
                     ctx.settings.save_silent();
                 }
 
@@ -305,7 +395,10 @@ impl App {
 
                 // Switch to next available account
                 if let Some(session) = ctx.sessions.primary() {
-                    ctx.settings.set_active_account(&session.username);
+                    ctx.settings.set_active_account(
+                        &session.username,
+                        session.client.enterprise_api_base(),
+                    );
                     ctx.settings.save_silent();
 
                     let (notif_screen, task) =
@@ -328,28 +421,42 @@ impl App {
                 self.go_to_rule_engine(RuleEngineOrigin::Notifications)
             }
 
-            NotificationMessage::Navigation(NavigationMessage::SwitchAccount(username)) => {
-                // Skip if already on this account
-                if ctx
-                    .sessions
-                    .primary()
-                    .is_some_and(|s| s.username == username)
+            NotificationMessage::Navigation(NavigationMessage::InspectRule(rule_id)) => {
+                let task = self.go_to_rule_engine(RuleEngineOrigin::Notifications);
+                if let App::Authenticated(screen, _) = self
+                    && let Screen::RuleEngine(rule_engine, _) = &mut **screen
                 {
+                    rule_engine.inspect_rule(rule_id);
+                }
+                task
+            }
+
+            NotificationMessage::Navigation(NavigationMessage::ShowAllAccounts) => {
+                screen.set_account_clients(ctx.account_clients());
+                screen
+                    .update(NotificationMessage::Refresh)
+                    .map(Message::Notifications)
+            }
+
+            NotificationMessage::Navigation(NavigationMessage::SwitchAccount(key)) => {
+                // Skip if already on this account
+                if !screen.is_unified() && ctx.sessions.primary().is_some_and(|s| s.key() == key) {
                     return Task::none();
                 }
 
                 // Preserve cross-account priority notifications
                 let cross_account_priority = screen.get_cross_account_priority();
-                ctx.sessions.set_primary(&username);
-
-                // Persist the active account preference
-                ctx.settings.set_active_account(&username);
-                ctx.settings.save_silent();
+                ctx.sessions.set_primary(&key);
 
                 let Some(session) = ctx.sessions.primary() else {
                     return Task::none();
                 };
 
+                // Persist the active account preference
+                ctx.settings
+                    .set_active_account(&session.username, session.client.enterprise_api_base());
+                ctx.settings.save_silent();
+
                 let (mut notif_screen, task) =
                     NotificationsScreen::new(session.client.clone(), session.user.clone());
                 notif_screen.set_cross_account_priority(cross_account_priority);
@@ -364,8 +471,9 @@ impl App {
 
             NotificationMessage::Navigation(NavigationMessage::TogglePowerMode) => {
                 ctx.settings.power_mode = !ctx.settings.power_mode;
-                ctx.settings.save_silent();
                 screen.collapse_all_groups();
+                screen.store_grouping(&mut ctx.settings);
+                ctx.settings.save_silent();
 
                 ctx.settings
                     .power_mode
@@ -373,6 +481,14 @@ impl App {
                     .unwrap_or_else(Task::none)
             }
 
+            message @ (NotificationMessage::Filter(FilterMessage::SelectGrouping(_))
+            | NotificationMessage::View(ViewMessage::ToggleGroup(_))) => {
+                let task = screen.update(message);
+                screen.store_grouping(&mut ctx.settings);
+                ctx.settings.save_silent();
+                task.map(Message::Notifications)
+            }
+
             other => screen.update(other).map(Message::Notifications),
         }
     }
@@ -390,10 +506,16 @@ impl App {
         if let Message::SessionRestored(result) = message {
             match result {
                 Ok(session) => {
-                    let username = session.username.clone();
+                    let key = session.key();
+                    screen.settings.set_active_account(
+                        &session.username,
+                        session.client.enterprise_api_base(),
+                    );
+                    screen.settings.set_account_token(&key, &session.user.token);
                     ctx.sessions.add_session(session);
-                    screen.settings.set_active_account(&username);
                     screen.settings.save_silent();
+                    ctx.settings = screen.settings.clone();
+                    ctx.warn_expiring_tokens();
                 }
                 Err(e) => {
                     eprintln!("Failed to restore session: {}", e);
@@ -428,11 +550,11 @@ impl App {
                 return settings_task;
             }
 
-            SettingsMessage::RemoveAccount(username) => {
+            SettingsMessage::RemoveAccount(key) => {
                 // Remove from active sessions
-                let _ = ctx.sessions.remove_account(username);
+                let _ = ctx.sessions.remove_account(key);
                 // Also remove from ctx.settings to keep them in sync
-                ctx.settings.remove_account(username);
+                ctx.settings.remove_account(key);
                 ctx.settings.save_silent();
 
                 // If no accounts left, logout
@@ -444,7 +566,10 @@ impl App {
 
                 // If we still have an account, ensure settings match the new primary
                 if let Some(primary) = ctx.sessions.primary() {
-                    ctx.settings.set_active_account(&primary.username);
+                    ctx.settings.set_active_account(
+                        &primary.username,
+                        primary.client.enterprise_api_base(),
+                    );
                     ctx.settings.save_silent();
                 }
                 // Keep screen.settings in sync with ctx.settings
@@ -452,20 +577,23 @@ impl App {
                 return Task::none();
             }
 
-            SettingsMessage::TokenValidated(Ok(username)) => {
+            SettingsMessage::TokenValidated(Ok((username, api_base))) => {
                 let username = username.clone();
+                let api_base = api_base.clone();
                 // First update the screen to show success status
                 let screen_task = screen.update(settings_msg).map(Message::Settings);
 
                 // Then start session restoration in parallel
                 let restore_task = Task::perform(
                     async move {
-                        let token = crate::github::keyring::load_token(&username)
-                            .map_err(|e| e.to_string())?
-                            .ok_or_else(|| "Token not found in keyring".to_string())?;
+                        let token =
+                            crate::github::keyring::load_token(&username, api_base.as_deref())
+                                .map_err(|e| e.to_string())?
+                                .ok_or_else(|| "Token not found in keyring".to_string())?;
 
                         let client =
-                            crate::github::GitHubClient::new(&token).map_err(|e| e.to_string())?;
+                            crate::github::GitHubClient::with_api_base(&token, api_base.as_deref())
+                                .map_err(|e| e.to_string())?;
                         let user = client
                             .get_authenticated_user()
                             .await
@@ -502,12 +630,15 @@ impl App {
         };
 
         if let RuleEngineMessage::Back = rule_msg {
+            if !screen.confirm_leave() {
+                return Task::none();
+            }
             let settings = ctx.settings.clone();
             match origin {
                 RuleEngineOrigin::Settings => {
                     let settings_screen = SettingsScreen::new(settings.clone());
                     *self = App::Authenticated(
-                        Box::new(Screen::Settings(settings_screen)),
+                        Box::new(Screen::Settings(Box::new(settings_screen))),
                         ctx.with_settings(settings),
                     );
                 }
@@ -534,18 +665,75 @@ impl App {
     // ========================================================================
 
     fn handle_tick(&mut self) -> Task<Message> {
-        let App::Authenticated(boxed_screen, _) = self else {
+        let App::Authenticated(boxed_screen, ctx) = self else {
             return Task::none();
         };
+        ctx.warn_expiring_tokens();
+
+        let retries = ctx
+            .sessions
+            .take_due_retries(chrono::Utc::now())
+            .into_iter()
+            .map(|(username, api_base)| Self::validate_account(username, api_base));
+        let retry_task = Task::batch(retries);
+
+        // Snoozes run out whichever screen is open.
+        match &mut **boxed_screen {
+            Screen::Notifications(screen) => screen.wake_snoozed(),
+            Screen::Settings(_) | Screen::RuleEngine(_, _) => {
+                NotificationsScreen::wake_stored_snoozes();
+                // Keep the tray's rule set check in step with schedules.
+                TrayManager::refresh_rule_sets();
+            }
+        }
+
         let Screen::Notifications(screen) = &mut **boxed_screen else {
+            return retry_task;
+        };
+        // A rule set schedule may have opened or closed since the last tick.
+        if screen.sync_rule_set() {
+            TrayManager::refresh_rule_sets();
+        }
+        if screen.is_loading || screen.is_backing_off() {
+            return retry_task;
+        }
+        // Pick up accounts restored since the unified inbox was opened.
+        if screen.is_unified() {
+            screen.set_account_clients(ctx.account_clients());
+        }
+        let refresh_task = screen
+            .update(NotificationMessage::Refresh)
+            .map(Message::Notifications);
+        Task::batch([retry_task, refresh_task])
+    }
+
+    /// Apply the result of retrying an account that needed attention.
+    fn handle_account_retried(&mut self, message: Message) -> Task<Message> {
+        let Message::AccountRetried(username, api_base, result) = message else {
             return Task::none();
         };
-        if screen.is_loading {
+        let App::Authenticated(boxed_screen, ctx) = self else {
             return Task::none();
+        };
+
+        match result {
+            Ok(session) => {
+                if let Screen::Notifications(screen) = &mut **boxed_screen
+                    && screen.account == session.key()
+                {
+                    screen.user = session.user.clone();
+                }
+                ctx.sessions.add_session(session);
+                ctx.sync_token_info();
+                ctx.warn_expiring_tokens();
+            }
+            Err(e) => {
+                eprintln!("Retry for {} failed: {}", username, e);
+                ctx.sessions
+                    .mark_attention(&username, api_base.as_deref(), &e);
+            }
         }
-        screen
-            .update(NotificationMessage::Refresh)
-            .map(Message::Notifications)
+        Task::none()
     }
 
     fn handle_tray_poll(&mut self) -> Task<Message> {
@@ -591,10 +779,39 @@ impl App {
 
                 Task::batch([window_task, refresh_task])
             }
+            TrayCommand::SelectRuleSet(name) => self.select_rule_set(name),
             TrayCommand::Quit => exit(),
         }
     }
 
+    /// Switch the active rule set from the tray, through whichever screen is showing.
+    fn select_rule_set(&mut self, name: String) -> Task<Message> {
+        if let App::Authenticated(boxed_screen, _) = self {
+            match &mut **boxed_screen {
+                Screen::Notifications(screen) => {
+                    return screen
+                        .update(NotificationMessage::Filter(FilterMessage::SelectRuleSet(
+                            name,
+                        )))
+                        .map(Message::Notifications);
+                }
+                Screen::RuleEngine(screen, _) => {
+                    return screen
+                        .update(RuleEngineMessage::RuleSet(RuleSetMessage::Select(name)))
+                        .map(Message::RuleEngine);
+                }
+                Screen::Settings(_) => {}
+            }
+        }
+
+        let mut store = RuleSetStore::load();
+        if store.select(&name) {
+            let _ = store.save();
+        }
+        TrayManager::refresh_rule_sets();
+        Task::none()
+    }
+
     fn handle_window_event(&mut self, id: WindowId, event: window::Event) -> Task<Message> {
         window_state::set_window_id(id);
 
@@ -720,7 +937,7 @@ impl App {
         let settings = ctx.settings.clone();
         let settings_screen = SettingsScreen::new(settings.clone());
         *self = App::Authenticated(
-            Box::new(Screen::Settings(settings_screen)),
+            Box::new(Screen::Settings(Box::new(settings_screen))),
             ctx.with_settings(settings),
         );
         Task::none()
@@ -733,12 +950,16 @@ impl App {
             .cloned()
             .unwrap_or_else(AppSettings::load);
 
-        let App::Authenticated(_, ctx) = self else {
+        let App::Authenticated(screen, ctx) = self else {
             return Task::none();
         };
 
         let rules = NotificationRuleSet::load();
-        let rule_engine_screen = RuleEngineScreen::new(rules, settings.clone());
+        let mut rule_engine_screen = RuleEngineScreen::new(rules, settings.clone());
+        // Dry runs check against what's on screen rather than the cached snapshot.
+        if let Screen::Notifications(notif_screen) = &mut **screen {
+            rule_engine_screen.inbox = std::mem::take(&mut notif_screen.all_notifications);
+        }
         *self = App::Authenticated(
             Box::new(Screen::RuleEngine(Box::new(rule_engine_screen), origin)),
             ctx.with_settings(settings),
@@ -807,13 +1028,15 @@ impl App {
             App::Authenticated(boxed_screen, ctx) => match &**boxed_screen {
                 Screen::Notifications(notif_screen) => {
                     let accounts = ctx.account_names();
+                    let attention = ctx.attention_summaries();
 
                     if ctx.settings.power_mode {
-                        self.view_power_mode(notif_screen, &ctx.settings, accounts)
+                        self.view_power_mode(notif_screen, &ctx.settings, accounts, attention)
                     } else {
                         notif_screen
                             .view(
                                 accounts,
+                                attention,
                                 ctx.settings.icon_theme,
                                 ctx.settings.sidebar_width,
                                 false,
@@ -847,11 +1070,13 @@ impl App {
         screen: &'a NotificationsScreen,
         settings: &AppSettings,
         accounts: Vec<String>,
+        attention: Vec<(String, String)>,
     ) -> Element<'a, Message> {
         use iced::widget::{column, row};
 
         let content = screen.view(
             accounts.clone(),
+            attention,
             settings.icon_theme,
             settings.sidebar_width,
             true,
@@ -876,9 +1101,7 @@ impl App {
         // Build account info for top bar
         let account_infos: Vec<top_bar::AccountInfo> = accounts
             .iter()
-            .map(|username| top_bar::AccountInfo {
-                username: username.clone(),
-            })
+            .map(|key| top_bar::AccountInfo { key: key.clone() })
             .collect();
 
         let unread_count = screen
@@ -890,11 +1113,15 @@ impl App {
         let power_layout: iced::Element<NotificationMessage> = column![
             top_bar::view_top_bar(
                 &screen.user,
+                &screen.account,
                 account_infos,
+                screen.is_unified(),
                 screen.is_loading,
                 unread_count,
                 screen.filters.show_all,
                 screen.bulk_mode,
+                &screen.rule_set_names,
+                screen.active_rule_set(),
                 settings.icon_theme
             ),
             main_area,
@@ -936,14 +1163,24 @@ impl App {
             _ => None,
         });
 
-        let on_notifications = matches!(
-            self,
-            App::Authenticated(screen, _) if matches!(&**screen, Screen::Notifications(_))
-        );
+        // Ticks run on every signed-in screen so snoozes and account retries
+        // keep going; only the notifications screen refreshes on them.
+        // Honor the per-account X-Poll-Interval once the first fetch has reported it.
+        let refresh_interval = match self {
+            App::Authenticated(screen, _) => Some(match &**screen {
+                Screen::Notifications(notif_screen) => notif_screen
+                    .client
+                    .poll_interval()
+                    .unwrap_or(Duration::from_secs(REFRESH_INTERVAL_SECS)),
+                Screen::Settings(_) | Screen::RuleEngine(_, _) => {
+                    Duration::from_secs(REFRESH_INTERVAL_SECS)
+                }
+            }),
+            _ => None,
+        };
 
-        let tick_sub = on_notifications.then(|| {
-            time::every(Duration::from_secs(REFRESH_INTERVAL_SECS)).map(|_| Message::Tick)
-        });
+        let tick_sub =
+            refresh_interval.map(|interval| time::every(interval).map(|_| Message::Tick));
 
         let subs: Vec<_> = tick_sub.into_iter().chain([tray_sub, window_sub]).collect();
         Subscription::batch(subs)
@@ -114,6 +114,12 @@ impl_icons! {
     fn icon_filter(icondata_lu::LuSlidersHorizontal, "⚙");
     fn icon_external_link(icondata_lu::LuExternalLink, "↗");
     fn icon_building(icondata_lu::LuBuilding, "🏢");
+    fn icon_repo(icondata_lu::LuFolderGit2, "📁");
+    fn icon_title(icondata_lu::LuType, "T");
+    fn icon_composite(icondata_lu::LuLayers, "≡");
+    fn icon_clock(icondata_lu::LuClock, "🕒");
+    fn icon_lock(icondata_lu::LuLock, "🔒");
+    fn icon_globe(icondata_lu::LuGlobe, "🌐");
     fn icon_tag(icondata_lu::LuTag, "🏷");
     fn icon_chart(icondata_lu::LuLayoutDashboard, "📊");
     fn icon_inbox_empty(icondata_lu::LuArchive, "📭");
@@ -123,4 +129,5 @@ impl_icons! {
     fn icon_eye_off(icondata_lu::LuEyeOff, "👁‍🗨");
     fn icon_at(icondata_lu::LuAtSign, "@");
     fn icon_info(icondata_lu::LuInfo, "i");
+    fn icon_wifi_off(icondata_lu::LuWifiOff, "⚠");
 }
@@ -6,3 +6,4 @@ mod widgets;
 pub mod window_state;
 
 pub use app::App;
+pub(crate) use screens::settings::rule_engine::RuleSetStore;
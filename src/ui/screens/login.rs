@@ -1,26 +1,44 @@
-//! Login screen - Personal Access Token entry.
+//! Login screen - Personal Access Token entry or browser (device-flow) sign-in.
 
+use iced::task::Handle;
 use iced::widget::{Space, button, column, container, text, text_input};
 use iced::{Alignment, Element, Fill, Length, Task};
 
+use crate::github::client::{api_host, enterprise_api_base_input};
+use crate::github::device_flow::{self, DeviceCode, DeviceFlowConfig};
 use crate::github::{GitHubClient, UserInfo, auth};
 use crate::ui::theme;
 
 #[derive(Debug, Clone, Default)]
 pub struct LoginScreen {
     token_input: String,
+    /// Optional GitHub Enterprise Server address; empty means github.com.
+    api_base_input: String,
     is_loading: bool,
     error_message: Option<String>,
+    /// Pending browser sign-in, while waiting for the user to approve the code.
+    device_code: Option<DeviceCode>,
+    /// Stops polling for the pending sign-in's token.
+    device_poll: Option<Handle>,
 }
 
 #[derive(Debug, Clone)]
 pub enum LoginMessage {
     TokenInputChanged(String),
+    ApiBaseInputChanged(String),
     Submit,
     LoginSuccess(GitHubClient, UserInfo),
     LoginFailed(String),
     OpenTokenUrl,
     TokenUrlOpened,
+    StartDeviceFlow,
+    /// Device code requested against the given API base (`None` for github.com).
+    DeviceCodeReceived(Option<String>, Result<DeviceCode, String>),
+    OpenVerificationUrl,
+    CopyUserCode,
+    CancelDeviceFlow,
+    /// Token for the given device code and API base, or an error message.
+    DeviceTokenReceived(String, Option<String>, Result<String, String>),
 }
 
 impl LoginScreen {
@@ -35,6 +53,11 @@ impl LoginScreen {
                 self.error_message = None;
                 Task::none()
             }
+            LoginMessage::ApiBaseInputChanged(value) => {
+                self.api_base_input = value;
+                self.error_message = None;
+                Task::none()
+            }
             LoginMessage::Submit => {
                 if self.token_input.trim().is_empty() {
                     self.error_message = Some("Please enter your token".to_string());
@@ -50,8 +73,9 @@ impl LoginScreen {
                 self.error_message = None;
 
                 let token = self.token_input.clone();
+                let api_base = self.api_base();
                 Task::perform(
-                    async move { auth::authenticate(&token).await },
+                    async move { auth::authenticate(&token, api_base.as_deref()).await },
                     |result| match result {
                         Ok((client, user)) => LoginMessage::LoginSuccess(client, user),
                         Err(e) => LoginMessage::LoginFailed(e.to_string()),
@@ -74,8 +98,14 @@ impl LoginScreen {
 
                 let scopes = "notifications,repo";
 
-                let mut url = reqwest::Url::parse("https://github.com/settings/tokens/new")
-                    .expect("Base URL is valid");
+                let host = self
+                    .api_base()
+                    .map_or("github.com".to_string(), |base| api_host(&base).to_string());
+                let mut url = reqwest::Url::parse(&format!("https://{}/settings/tokens/new", host))
+                    .unwrap_or_else(|_| {
+                        reqwest::Url::parse("https://github.com/settings/tokens/new")
+                            .expect("Base URL is valid")
+                    });
                 url.query_pairs_mut()
                     .append_pair("scopes", scopes)
                     .append_pair("description", &description);
@@ -90,7 +120,115 @@ impl LoginScreen {
                 )
             }
             LoginMessage::TokenUrlOpened => Task::none(),
+            LoginMessage::StartDeviceFlow => {
+                let api_base = self.api_base();
+                let Some(config) = DeviceFlowConfig::for_api_base(api_base.as_deref()) else {
+                    self.error_message =
+                        Some(device_flow::DeviceFlowError::NotConfigured.to_string());
+                    return Task::none();
+                };
+
+                self.cancel_device_flow();
+                self.is_loading = true;
+                self.error_message = None;
+                Task::perform(
+                    async move {
+                        let result = device_flow::request_device_code(&config)
+                            .await
+                            .map_err(|e| e.to_string());
+                        (api_base, result)
+                    },
+                    |(api_base, result)| LoginMessage::DeviceCodeReceived(api_base, result),
+                )
+            }
+            LoginMessage::DeviceCodeReceived(api_base, result) => {
+                self.is_loading = false;
+                match result {
+                    Ok(code) => {
+                        let Some(config) = DeviceFlowConfig::for_api_base(api_base.as_deref())
+                        else {
+                            return Task::none();
+                        };
+                        self.device_code = Some(code.clone());
+
+                        let device_code = code.device_code.clone();
+                        let (task, handle) = Task::perform(
+                            async move {
+                                device_flow::poll_for_token(&config, &code)
+                                    .await
+                                    .map_err(|e| e.to_string())
+                            },
+                            move |result| {
+                                LoginMessage::DeviceTokenReceived(
+                                    device_code.clone(),
+                                    api_base.clone(),
+                                    result,
+                                )
+                            },
+                        )
+                        .abortable();
+                        self.device_poll = Some(handle);
+                        task
+                    }
+                    Err(error) => {
+                        self.error_message = Some(error);
+                        Task::none()
+                    }
+                }
+            }
+            LoginMessage::OpenVerificationUrl => {
+                if let Some(code) = &self.device_code {
+                    let _ = open::that(&code.verification_uri);
+                }
+                Task::none()
+            }
+            LoginMessage::CopyUserCode => match &self.device_code {
+                Some(code) => iced::clipboard::write(code.user_code.clone()),
+                None => Task::none(),
+            },
+            LoginMessage::CancelDeviceFlow => {
+                self.cancel_device_flow();
+                Task::none()
+            }
+            LoginMessage::DeviceTokenReceived(device_code, api_base, result) => {
+                // Ignore results from a flow the user already cancelled or restarted.
+                if self.device_code.as_ref().map(|c| &c.device_code) != Some(&device_code) {
+                    return Task::none();
+                }
+                self.device_code = None;
+                self.device_poll = None;
+
+                match result {
+                    Ok(token) => {
+                        self.is_loading = true;
+                        Task::perform(
+                            async move { auth::authenticate(&token, api_base.as_deref()).await },
+                            |result| match result {
+                                Ok((client, user)) => LoginMessage::LoginSuccess(client, user),
+                                Err(e) => LoginMessage::LoginFailed(e.to_string()),
+                            },
+                        )
+                    }
+                    Err(error) => {
+                        self.error_message = Some(error);
+                        Task::none()
+                    }
+                }
+            }
+        }
+    }
+
+    /// Drop the pending browser sign-in and stop polling for its token.
+    fn cancel_device_flow(&mut self) {
+        if let Some(handle) = self.device_poll.take() {
+            handle.abort();
         }
+        self.device_code = None;
+    }
+
+    /// Normalized GHES API base from the input, `None` for github.com.
+    fn api_base(&self) -> Option<String> {
+        enterprise_api_base_input(&self.api_base_input)
     }
 
     pub fn view(&self) -> Element<'_, LoginMessage> {
@@ -114,6 +252,14 @@ impl LoginScreen {
             .style(theme::text_input_style)
             .width(Fill);
 
+        let api_base_input = text_input("Enterprise Server URL (optional)", &self.api_base_input)
+            .on_input(LoginMessage::ApiBaseInputChanged)
+            .on_submit(LoginMessage::Submit)
+            .padding(10)
+            .size(12)
+            .style(theme::text_input_style)
+            .width(Fill);
+
         let submit_button = if self.is_loading {
             button(
                 text("Authenticating...")
@@ -143,6 +289,17 @@ impl LoginScreen {
             Space::new().width(0).height(0).into()
         };
 
+        let device_button = button(
+            text("Sign in with Browser")
+                .size(13)
+                .width(Fill)
+                .align_x(Alignment::Center),
+        )
+        .style(theme::ghost_button)
+        .on_press_maybe((!self.is_loading).then_some(LoginMessage::StartDeviceFlow))
+        .width(Fill)
+        .padding(10);
+
         let help_text = column![
             button(text("Generate New Token").size(12))
                 .style(theme::ghost_button)
@@ -160,15 +317,24 @@ impl LoginScreen {
             Space::new().height(8),
             token_input,
             Space::new().height(8),
+            api_base_input,
+            Space::new().height(8),
             error_text,
             Space::new().height(16),
             submit_button,
+            Space::new().height(8),
+            device_button,
             Space::new().height(24),
             help_text,
         ]
         .align_x(Alignment::Center)
         .width(Length::Fixed(320.0));
 
+        let form: Element<'_, LoginMessage> = match &self.device_code {
+            Some(code) => self.view_device_code(code),
+            None => form.into(),
+        };
+
         let content = column![
             logo,
             Space::new().height(8),
@@ -187,4 +353,54 @@ impl LoginScreen {
             .style(theme::app_container)
             .into()
     }
+
+    /// Shows the user code while waiting for browser approval.
+    fn view_device_code<'a>(&'a self, code: &'a DeviceCode) -> Element<'a, LoginMessage> {
+        let p = theme::palette();
+
+        column![
+            text("Enter this code on GitHub")
+                .size(12)
+                .style(theme::secondary_text),
+            Space::new().height(12),
+            text(&code.user_code).size(28).color(p.text_primary),
+            Space::new().height(16),
+            button(
+                text("Open Verification Page")
+                    .size(14)
+                    .width(Fill)
+                    .align_x(Alignment::Center),
+            )
+            .style(theme::primary_button)
+            .on_press(LoginMessage::OpenVerificationUrl)
+            .width(Fill)
+            .padding(12),
+            Space::new().height(8),
+            button(
+                text("Copy Code")
+                    .size(13)
+                    .width(Fill)
+                    .align_x(Alignment::Center),
+            )
+            .style(theme::ghost_button)
+            .on_press(LoginMessage::CopyUserCode)
+            .width(Fill)
+            .padding(10),
+            Space::new().height(16),
+            text(format!(
+                "Waiting for approval at {}...",
+                code.verification_uri
+            ))
+            .size(11)
+            .style(theme::muted_text),
+            Space::new().height(8),
+            button(text("Cancel").size(12))
+                .style(theme::ghost_button)
+                .on_press(LoginMessage::CancelDeviceFlow)
+                .padding(4),
+        ]
+        .align_x(Alignment::Center)
+        .width(Length::Fixed(320.0))
+        .into()
+    }
 }
@@ -4,13 +4,20 @@
 //! - `NotificationEngine`: Owns the RuleEngine and provides evaluation methods
 //! - `process_all()`: Single-passes all notifications for a view refresh.
 //! - `should_notify_desktop()`: Checks if we should annoy the user with a popup.
+//! - `queue_deferred()` / `due_deferred()` / `remove_deferred()`: Holds back notifications deferred
+//!   by an account schedule and releases them once the window opens.
+//! - `process_fetch()`: `process_all()` for a fetch, also counting which rule decided
+//!   each thread update, for cleanup stats.
+//! - `dry_run()`: Compares a draft rule set against the saved one before saving.
 //!
 //! Solves the "Double Evaluation" problem by processing once and storing results.
 
 use chrono::{DateTime, Local, Utc};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
+use crate::cache::{DiskCache, RuleHit};
 use crate::github::types::NotificationView;
+use crate::ui::screens::settings::rule_engine::rules::{RuleDecision, RuleDecisionReason};
 use crate::ui::screens::settings::rule_engine::{NotificationRuleSet, RuleAction, RuleEngine};
 
 use super::helper::ProcessedNotification;
@@ -40,20 +47,46 @@ impl NotificationEngine {
     pub fn process_all(&self, notifications: &[NotificationView]) -> Vec<ProcessedNotification> {
         notifications
             .iter()
-            .filter_map(|n| self.evaluate_single(n))
+            .filter_map(|n| self.evaluate_single(n, |_| {}))
             .collect()
     }
 
-    fn evaluate_single(&self, notification: &NotificationView) -> Option<ProcessedNotification> {
-        // This extraction is subtle we must use the exact same label as the rules expected.
-        let reason_label = Self::extract_reason_label(notification);
+    /// `process_all` for a completed fetch of `accounts`, also counting which rule
+    /// decided each thread in the rule hit statistics. Hidden threads count too;
+    /// they're what cleanup decisions hinge on.
+    pub fn process_fetch(
+        &self,
+        notifications: &[NotificationView],
+        accounts: &HashSet<&str>,
+    ) -> Vec<ProcessedNotification> {
+        let mut decided = Vec::new();
+        let processed = notifications
+            .iter()
+            .filter_map(|n| {
+                self.evaluate_single(n, |decision| {
+                    decided.push((decision.applied_rule_id.clone(), n));
+                })
+            })
+            .collect();
+        Self::record_hits(&decided, accounts);
+        processed
+    }
 
-        let (action, _decision) = self.engine.evaluate_detailed(
-            reason_label,
-            Some(notification.repo_owner()),
-            Some(&notification.account),
-            &self.evaluation_time,
-        );
+    /// `on_decision` sees the deciding rule of every thread, hidden ones included.
+    fn evaluate_single(
+        &self,
+        notification: &NotificationView,
+        on_decision: impl FnOnce(&RuleDecision),
+    ) -> Option<ProcessedNotification> {
+        let (action, decision) = self
+            .engine
+            .evaluate_detailed(notification, &self.evaluation_time);
+        if let Some(decision) = &decision {
+            on_decision(decision);
+        }
+        let deferred = decision
+            .as_ref()
+            .is_some_and(|d| matches!(d.reason, RuleDecisionReason::Deferred(_)));
 
         // Filter out hidden notifications entirely from the UI view model
         if action == RuleAction::Hide {
@@ -62,28 +95,196 @@ impl NotificationEngine {
             Some(ProcessedNotification {
                 notification: notification.clone(),
                 action,
+                deferred,
+                priority: decision.as_ref().map_or(0, |d| d.priority),
+                decision,
             })
         }
     }
 
-    /// Single source of truth for notification reason -> string conversion.
-    #[inline]
-    pub fn extract_reason_label(notification: &NotificationView) -> &str {
-        notification.reason.label()
-    }
-
     pub fn should_notify_desktop(
         processed: &ProcessedNotification,
         seen_timestamps: &HashMap<String, DateTime<Utc>>,
     ) -> bool {
-        let notif = &processed.notification;
-
         // Logic: Unread AND (Never seen OR Updated since seen) AND (Show OR Important)
+        Self::is_new_unread(&processed.notification, seen_timestamps)
+            && matches!(processed.action, RuleAction::Show | RuleAction::Important)
+    }
+
+    fn is_new_unread(
+        notif: &NotificationView,
+        seen_timestamps: &HashMap<String, DateTime<Utc>>,
+    ) -> bool {
         notif.unread
             && seen_timestamps
                 .get(&notif.id)
                 .is_none_or(|last_seen| notif.updated_at > *last_seen)
-            && matches!(processed.action, RuleAction::Show | RuleAction::Important)
+    }
+}
+
+// ============================================================================
+// Deferred Delivery
+// ============================================================================
+
+impl NotificationEngine {
+    /// Persist newly deferred notifications so a restart doesn't lose them.
+    /// Keyed by thread, so an update replaces the queued entry.
+    pub fn queue_deferred(
+        processed: &[ProcessedNotification],
+        seen_timestamps: &HashMap<String, DateTime<Utc>>,
+    ) {
+        let Some(cache) = DiskCache::shared() else {
+            return;
+        };
+        for p in processed
+            .iter()
+            .filter(|p| p.deferred && Self::is_new_unread(&p.notification, seen_timestamps))
+        {
+            if let Err(e) = cache.save_deferred(&p.notification) {
+                eprintln!("[DEFER] Failed to queue {}: {}", p.notification.id, e);
+            }
+        }
+    }
+
+    /// Queued notifications whose account is no longer deferring,
+    /// i.e. its active window has opened (or the rule was changed).
+    /// They stay queued until `remove_deferred` settles them.
+    pub fn due_deferred(&self) -> Vec<NotificationView> {
+        let Some(cache) = DiskCache::shared() else {
+            return Vec::new();
+        };
+        let queued = cache.load_deferred().unwrap_or_else(|e| {
+            eprintln!("[DEFER] Failed to load queue: {}", e);
+            Vec::new()
+        });
+
+        let rules = self.engine.rules();
+        queued
+            .into_iter()
+            .filter(|n| !rules.is_deferring(&n.account, &self.evaluation_time))
+            .collect()
+    }
+
+    /// Drop delivered (or since read) notifications from the queue.
+    pub fn remove_deferred(ids: &[String]) {
+        let Some(cache) = DiskCache::shared() else {
+            return;
+        };
+        for id in ids {
+            if let Err(e) = cache.remove_deferred(id) {
+                eprintln!("[DEFER] Failed to dequeue {}: {}", id, e);
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Rule Hits
+// ============================================================================
+
+impl NotificationEngine {
+    /// Store the deciding rule of each thread from one fetch in one transaction.
+    fn record_hits(decided: &[(String, &NotificationView)], accounts: &HashSet<&str>) {
+        let Some(cache) = DiskCache::shared() else {
+            return;
+        };
+        let hits = decided.iter().map(|(rule_id, n)| RuleHit {
+            account: &n.account,
+            rule_id,
+            thread_id: &n.id,
+            updated_at: n.updated_at,
+        });
+        if let Err(e) = cache.record_rule_hits(accounts, hits) {
+            eprintln!("[RULES] Failed to record rule hits: {}", e);
+        }
+    }
+}
+
+// ============================================================================
+// Dry Run
+// ============================================================================
+
+/// How many threads a rule set hides, silences or marks important.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ActionCounts {
+    pub hidden: usize,
+    pub silent: usize,
+    pub important: usize,
+}
+
+impl ActionCounts {
+    fn add(&mut self, action: RuleAction) {
+        match action {
+            RuleAction::Hide => self.hidden += 1,
+            RuleAction::Silent => self.silent += 1,
+            RuleAction::Important => self.important += 1,
+            RuleAction::Show => {}
+        }
+    }
+}
+
+/// A thread whose outcome differs between the saved and the draft rules.
+#[derive(Debug, Clone)]
+pub struct DryRunChange {
+    pub notification: NotificationView,
+    pub before: RuleAction,
+    pub after: RuleAction,
+}
+
+/// What switching from the saved rules to a draft would do to the inbox.
+#[derive(Debug, Clone, Default)]
+pub struct DryRunReport {
+    pub total: usize,
+    pub before: ActionCounts,
+    pub after: ActionCounts,
+    pub changes: Vec<DryRunChange>,
+}
+
+impl NotificationEngine {
+    /// Run both rule sets over the same notifications and collect the differences.
+    /// Nothing is saved; threads `process_all` drops count as hidden.
+    pub fn dry_run(
+        saved: &NotificationRuleSet,
+        draft: &NotificationRuleSet,
+        notifications: &[NotificationView],
+    ) -> DryRunReport {
+        // Both passes share one "now" so schedules can't flip between them.
+        let now = Local::now();
+        let actions = |rules: &NotificationRuleSet| -> HashMap<String, RuleAction> {
+            let engine = Self {
+                engine: RuleEngine::new(rules.clone()),
+                evaluation_time: now,
+            };
+            engine
+                .process_all(notifications)
+                .into_iter()
+                .map(|p| (p.notification.id, p.action))
+                .collect()
+        };
+        let saved_actions = actions(saved);
+        let draft_actions = actions(draft);
+        let action_of = |actions: &HashMap<String, RuleAction>, id: &str| {
+            actions.get(id).copied().unwrap_or(RuleAction::Hide)
+        };
+
+        let mut report = DryRunReport {
+            total: notifications.len(),
+            ..Default::default()
+        };
+        for notification in notifications {
+            let before = action_of(&saved_actions, &notification.id);
+            let after = action_of(&draft_actions, &notification.id);
+            report.before.add(before);
+            report.after.add(after);
+            if before != after {
+                report.changes.push(DryRunChange {
+                    notification: notification.clone(),
+                    before,
+                    after,
+                });
+            }
+        }
+        report
     }
 }
 
@@ -209,4 +410,116 @@ mod tests {
             &seen
         ));
     }
+
+    #[test]
+    fn test_dry_run_reports_changes() {
+        use crate::ui::screens::settings::rule_engine::rules::TypeRule;
+
+        let saved = NotificationRuleSet {
+            enabled: true,
+            ..Default::default()
+        };
+        let mut draft = saved.clone();
+        let mut hide_subscribed =
+            TypeRule::new(Some(NotificationReason::Subscribed), None, None, 0);
+        hide_subscribed.action = RuleAction::Hide;
+        draft.type_rules.push(hide_subscribed);
+
+        let notifications = vec![
+            make_notification("1", true, NotificationReason::Mention),
+            make_notification("2", true, NotificationReason::Subscribed),
+        ];
+        let report = NotificationEngine::dry_run(&saved, &draft, &notifications);
+
+        assert_eq!(report.total, 2);
+        assert_eq!(report.before, ActionCounts::default());
+        assert_eq!(report.after.hidden, 1);
+        assert_eq!(report.changes.len(), 1);
+        assert_eq!(report.changes[0].notification.id, "2");
+        assert_eq!(report.changes[0].before, RuleAction::Show);
+        assert_eq!(report.changes[0].after, RuleAction::Hide);
+    }
+
+    #[test]
+    fn test_priority_orders_groups() {
+        use crate::settings::GroupingMode;
+        use crate::ui::screens::notifications::helper::group_processed_notifications;
+        use crate::ui::screens::settings::rule_engine::rules::{PRIORITY_HIGH, TypeRule};
+
+        let mut rules = NotificationRuleSet {
+            enabled: true,
+            ..Default::default()
+        };
+        rules.type_rules.push(TypeRule::new(
+            Some(NotificationReason::Mention),
+            None,
+            None,
+            PRIORITY_HIGH,
+        ));
+
+        let engine = NotificationEngine::new(rules);
+        let processed = engine.process_all(&[
+            make_notification("1", true, NotificationReason::Subscribed),
+            make_notification("2", true, NotificationReason::Mention),
+        ]);
+        assert_eq!(processed[1].priority, PRIORITY_HIGH);
+
+        let by_time = group_processed_notifications(&processed, true, GroupingMode::Time);
+        assert_eq!(by_time[0].notifications[0].notification.id, "2");
+
+        let by_priority = group_processed_notifications(&processed, true, GroupingMode::Priority);
+        let titles: Vec<_> = by_priority.iter().map(|g| g.title.as_str()).collect();
+        assert_eq!(titles, ["High Priority", "Normal", "Low Priority"]);
+        assert_eq!(by_priority[0].notifications[0].notification.id, "2");
+        assert_eq!(by_priority[1].notifications[0].notification.id, "1");
+    }
+
+    #[test]
+    fn test_group_by_organization() {
+        use crate::settings::GroupingMode;
+        use crate::ui::screens::notifications::helper::group_processed_notifications;
+
+        let mut other = make_notification("2", true, NotificationReason::Mention);
+        other.repo_full_name = "Acme/tools".to_string();
+        let notifications = vec![
+            make_notification("1", true, NotificationReason::Mention),
+            other,
+            make_notification("3", false, NotificationReason::Subscribed),
+        ];
+        let processed =
+            NotificationEngine::new(NotificationRuleSet::default()).process_all(&notifications);
+
+        let groups = group_processed_notifications(&processed, true, GroupingMode::Organization);
+        let titles: Vec<_> = groups.iter().map(|g| g.title.as_str()).collect();
+        assert_eq!(titles, ["Acme", "owner"]);
+        assert_eq!(groups[1].notifications.len(), 2);
+    }
+
+    #[test]
+    fn test_defer_marks_notifications() {
+        use crate::ui::screens::settings::rule_engine::rules::{
+            AccountRule, OutsideScheduleBehavior,
+        };
+
+        let mut rules = NotificationRuleSet {
+            enabled: true,
+            ..Default::default()
+        };
+        let mut acc_rule = AccountRule::new("testuser");
+        acc_rule.active_days.clear(); // Never active
+        acc_rule.outside_behavior = OutsideScheduleBehavior::Defer;
+        rules.account_rules.push(acc_rule);
+        assert!(rules.is_deferring("TestUser", &Local::now()));
+
+        let engine = NotificationEngine::new(rules);
+        let processed =
+            engine.process_all(&[make_notification("1", true, NotificationReason::Mention)]);
+
+        assert_eq!(processed[0].action, RuleAction::Silent);
+        assert!(processed[0].deferred);
+        assert!(!NotificationEngine::should_notify_desktop(
+            &processed[0],
+            &HashMap::new()
+        ));
+    }
 }
@@ -1,12 +1,17 @@
 //! Architecture Notes:
 //! - `ProcessedNotification` and `NotificationGroup` hold our view data.
-//! - `group_processed_notifications` handles the presentation logic (time buckets).
+//! - `group_processed_notifications` handles the presentation logic (time, priority,
+//!   or per-repo/org/reason/type/account buckets, ordered by rule priority within each group).
 //! - `apply_filters`, `count_by_type`, `count_by_repo` are just pure data transformations.
 //!
 //! Note: For rule evaluation, check `engine.rs` instead.
 
 use crate::github::{NotificationView, SubjectType};
+use crate::settings::GroupingMode;
 use crate::ui::screens::settings::rule_engine::RuleAction;
+use crate::ui::screens::settings::rule_engine::rules::{
+    PRIORITY_HIGH, PRIORITY_LOW, RepoVisibility, RuleDecision,
+};
 use chrono::Local;
 use std::collections::HashMap;
 
@@ -14,6 +19,12 @@ use std::collections::HashMap;
 pub struct ProcessedNotification {
     pub notification: NotificationView,
     pub action: RuleAction,
+    /// Held back by an account schedule set to Defer; delivered later as a digest.
+    pub deferred: bool,
+    /// The rule that decided `action`; `None` when no rule matched.
+    pub decision: Option<RuleDecision>,
+    /// Numeric priority of the deciding rule, 0 without one. Orders items within a group.
+    pub priority: i32,
 }
 
 impl ProcessedNotification {
@@ -38,11 +49,134 @@ pub struct FilterSettings {
     pub selected_type: Option<SubjectType>,
     /// None means "All Repos"
     pub selected_repo: Option<String>,
+    pub visibility: RepoVisibility,
+    pub grouping: GroupingMode,
 }
 
 pub fn group_processed_notifications(
     processed: &[ProcessedNotification],
     show_priority_group: bool,
+    mode: GroupingMode,
+) -> Vec<NotificationGroup> {
+    let mut groups = match mode {
+        GroupingMode::Time => group_by_time(processed, show_priority_group),
+        GroupingMode::Priority => group_by_priority(processed, show_priority_group),
+        GroupingMode::Repository => {
+            group_by_key(processed, show_priority_group, |n| n.repo_full_name.clone())
+        }
+        GroupingMode::Organization => group_by_key(processed, show_priority_group, |n| {
+            n.repo_owner().to_string()
+        }),
+        GroupingMode::Reason => group_by_key(processed, show_priority_group, |n| {
+            n.reason.label().to_string()
+        }),
+        GroupingMode::SubjectType => group_by_key(processed, show_priority_group, |n| {
+            n.subject_type.label().to_string()
+        }),
+        GroupingMode::Account => {
+            group_by_key(processed, show_priority_group, |n| n.account.clone())
+        }
+    };
+    // Higher priority first. The sort is stable, so equal priorities stay newest first.
+    for group in &mut groups {
+        group
+            .notifications
+            .sort_by_key(|p| std::cmp::Reverse(p.priority));
+    }
+    groups
+}
+
+/// One expanded group per distinct key, sorted by name.
+fn group_by_key(
+    processed: &[ProcessedNotification],
+    show_priority_group: bool,
+    key: impl Fn(&NotificationView) -> String,
+) -> Vec<NotificationGroup> {
+    let mut important = Vec::new();
+    let mut buckets: HashMap<String, Vec<ProcessedNotification>> = HashMap::new();
+    for notif in processed {
+        if show_priority_group && notif.action == RuleAction::Important {
+            important.push(notif.clone());
+        } else {
+            buckets
+                .entry(key(&notif.notification))
+                .or_default()
+                .push(notif.clone());
+        }
+    }
+
+    let mut buckets: Vec<_> = buckets.into_iter().collect();
+    buckets.sort_by_cached_key(|(title, _)| title.to_lowercase());
+
+    let mut groups = Vec::with_capacity(buckets.len() + 1);
+    if !important.is_empty() {
+        groups.push(NotificationGroup {
+            title: "Important".to_string(),
+            notifications: important,
+            is_expanded: true,
+            is_priority: true,
+        });
+    }
+    groups.extend(
+        buckets
+            .into_iter()
+            .map(|(title, notifications)| NotificationGroup {
+                title,
+                notifications,
+                is_expanded: true,
+                is_priority: false,
+            }),
+    );
+    groups
+}
+
+fn group_by_priority(
+    processed: &[ProcessedNotification],
+    show_priority_group: bool,
+) -> Vec<NotificationGroup> {
+    let mut important = Vec::new();
+    let mut high = Vec::new();
+    let mut normal = Vec::new();
+    let mut low = Vec::new();
+    for notif in processed {
+        if show_priority_group && notif.action == RuleAction::Important {
+            important.push(notif.clone());
+        } else if notif.priority >= PRIORITY_HIGH {
+            high.push(notif.clone());
+        } else if notif.priority <= PRIORITY_LOW {
+            low.push(notif.clone());
+        } else {
+            normal.push(notif.clone());
+        }
+    }
+
+    let mut groups = Vec::with_capacity(4);
+    if !important.is_empty() {
+        groups.push(NotificationGroup {
+            title: "Important".to_string(),
+            notifications: important,
+            is_expanded: true,
+            is_priority: true,
+        });
+    }
+    for (title, notifications, is_expanded) in [
+        ("High Priority", high, true),
+        ("Normal", normal, true),
+        ("Low Priority", low, false),
+    ] {
+        groups.push(NotificationGroup {
+            title: title.to_string(),
+            notifications,
+            is_expanded,
+            is_priority: false,
+        });
+    }
+    groups
+}
+
+fn group_by_time(
+    processed: &[ProcessedNotification],
+    show_priority_group: bool,
 ) -> Vec<NotificationGroup> {
     let now_date = Local::now().date_naive();
     let one_week_ago = now_date - chrono::Duration::days(7);
@@ -124,7 +258,8 @@ pub fn apply_filters(
                 .selected_repo
                 .as_ref()
                 .is_none_or(|r| &n.repo_full_name == r);
-            passes_read && passes_type && passes_repo
+            let passes_visibility = filters.visibility.matches(Some(n.is_private));
+            passes_read && passes_type && passes_repo && passes_visibility
         })
         .cloned()
         .collect()
@@ -164,8 +299,15 @@ pub fn count_by_repo(notifications: &[NotificationView]) -> Vec<(String, usize)>
     result
 }
 
+/// Converts a REST API URL to its web page URL.
+///
+/// github.com: `https://api.github.com/repos/o/r/pulls/1` → `https://github.com/o/r/pull/1`
+/// GHES: `https://ghe.example.com/api/v3/repos/o/r/pulls/1` → `https://ghe.example.com/o/r/pull/1`
 pub fn api_url_to_web_url(api_url: &str) -> String {
-    api_url
-        .replace("api.github.com/repos", "github.com")
-        .replace("/pulls/", "/pull/")
+    let web_url = if let Some((host, path)) = api_url.split_once("/api/v3/repos/") {
+        format!("{}/{}", host, path)
+    } else {
+        api_url.replace("api.github.com/repos", "github.com")
+    };
+    web_url.replace("/pulls/", "/pull/")
 }
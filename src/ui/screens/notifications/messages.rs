@@ -1,9 +1,15 @@
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
 use crate::github::{GitHubError, NotificationView, SubjectType};
+use crate::settings::GroupingMode;
+use crate::ui::screens::settings::rule_engine::rules::{QuickRule, RepoVisibility};
 
 #[derive(Debug, Clone)]
 pub enum NotificationMessage {
     Refresh,
     RefreshComplete(Result<Vec<NotificationView>, GitHubError>),
+    /// Per-account fetch results for the unified inbox, by account key.
+    UnifiedRefreshComplete(Vec<(String, Result<Vec<NotificationView>, GitHubError>)>),
 
     Filter(FilterMessage),
     Thread(ThreadMessage),
@@ -17,6 +23,10 @@ pub enum FilterMessage {
     ToggleShowAll,
     SelectType(Option<SubjectType>),
     SelectRepo(Option<String>),
+    SelectVisibility(RepoVisibility),
+    SelectGrouping(GroupingMode),
+    /// Switch the active rule set by name.
+    SelectRuleSet(String),
 }
 
 #[derive(Debug, Clone)]
@@ -28,6 +38,118 @@ pub enum ThreadMessage {
     MarkAsDoneComplete(String, Result<(), GitHubError>),
     MarkAllAsRead,
     MarkAllAsReadComplete(Result<(), GitHubError>),
+    /// Keep a thread unread locally (GitHub has no API for it).
+    MarkAsUnread(String),
+    /// Stop notifications until mentioned or participating again.
+    Unsubscribe(String),
+    /// Ignore all future notifications for the thread.
+    Mute(String),
+    Resubscribe(String),
+    /// Hide a thread until the chosen time or its next update.
+    Snooze(String, SnoozeUntil),
+    /// Ask for a snooze time for one thread.
+    ChooseSnoozeTime(String),
+    SnoozeTimeChanged(String),
+    /// Snooze until the time typed into the prompt.
+    SnoozeAtChosenTime,
+    CancelSnoozeTime,
+    /// Save a rule built from the thread and apply it right away.
+    CreateRule(String, QuickRule),
+    DismissRuleNotice,
+    SubscriptionComplete(String, SubscriptionChange, Result<(), GitHubError>),
+}
+
+/// A change to a thread's subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubscriptionChange {
+    Unsubscribe,
+    Mute,
+    Resubscribe,
+}
+
+/// When a snoozed thread comes back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnoozeUntil {
+    OneHour,
+    ThreeHours,
+    /// 09:00 tomorrow.
+    TomorrowMorning,
+    /// 09:00 next Monday.
+    NextWeek,
+    /// A time the user typed in.
+    At(DateTime<Utc>),
+}
+
+impl SnoozeUntil {
+    pub const ALL: &'static [Self] = &[
+        Self::OneHour,
+        Self::ThreeHours,
+        Self::TomorrowMorning,
+        Self::NextWeek,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::OneHour => "Snooze 1 hour",
+            Self::ThreeHours => "Snooze 3 hours",
+            Self::TomorrowMorning => "Snooze until tomorrow",
+            Self::NextWeek => "Snooze until next week",
+            Self::At(_) => "Snooze until a chosen time",
+        }
+    }
+
+    /// Read a typed wake-up time: `17:30` (the next occurrence),
+    /// `2025-01-20 17:30`, or `2025-01-20` (09:00). Only future times are accepted.
+    pub fn parse(input: &str, now: DateTime<Local>) -> Option<Self> {
+        let input = input.trim();
+        let at = if let Ok(time) = NaiveTime::parse_from_str(input, "%H:%M") {
+            let today = now.date_naive().and_time(time);
+            if today > now.naive_local() {
+                today
+            } else {
+                today + Duration::days(1)
+            }
+        } else if let Ok(at) = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M") {
+            at
+        } else {
+            NaiveDate::parse_from_str(input, "%Y-%m-%d")
+                .ok()?
+                .and_hms_opt(9, 0, 0)?
+        };
+        let at = at.and_local_timezone(Local).earliest()?.with_timezone(&Utc);
+        (at > now).then_some(Self::At(at))
+    }
+
+    /// The wake-up time, counted from `now`.
+    pub fn resolve(self, now: DateTime<Local>) -> DateTime<Utc> {
+        let morning = |days: i64| {
+            let date = now.date_naive() + Duration::days(days);
+            date.and_hms_opt(9, 0, 0)
+                .and_then(|t| t.and_local_timezone(Local).earliest())
+                .map(|t| t.with_timezone(&Utc))
+                .unwrap_or_else(|| now.with_timezone(&Utc) + Duration::days(days))
+        };
+        match self {
+            Self::OneHour => (now + Duration::hours(1)).with_timezone(&Utc),
+            Self::ThreeHours => (now + Duration::hours(3)).with_timezone(&Utc),
+            Self::TomorrowMorning => morning(1),
+            Self::NextWeek => morning(7 - i64::from(now.weekday().num_days_from_monday())),
+            Self::At(at) => at,
+        }
+    }
+}
+
+impl std::fmt::Display for SnoozeUntil {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::At(at) => write!(
+                f,
+                "Snooze until {}",
+                at.with_timezone(&Local).format("%a %d %b %H:%M")
+            ),
+            _ => f.write_str(self.label()),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -37,7 +159,12 @@ pub enum BulkMessage {
     SelectAll,
     Clear,
     MarkAsRead,
+    MarkAsUnread,
     MarkAsDone,
+    ChangeSubscription(SubscriptionChange),
+    Snooze(SnoozeUntil),
+    /// Ask for a snooze time for the selection.
+    ChooseSnoozeTime,
     Complete,
 }
 
@@ -58,6 +185,81 @@ pub enum NavigationMessage {
     Logout,
     OpenSettings,
     OpenRuleEngine,
+    /// Open the Rule Engine with a rule in the inspector.
+    InspectRule(String),
     SwitchAccount(String),
+    /// Merge every signed-in account into one inbox.
+    ShowAllAccounts,
     TogglePowerMode,
 }
+
+/// Account pick-list entry for the unified inbox.
+pub const ALL_ACCOUNTS: &str = "All accounts";
+
+impl NavigationMessage {
+    /// Message for an entry chosen in an account pick-list.
+    pub fn from_account_choice(choice: String) -> Self {
+        if choice == ALL_ACCOUNTS {
+            Self::ShowAllAccounts
+        } else {
+            Self::SwitchAccount(choice)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn local(day: u32, hour: u32, min: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(2025, 1, day, hour, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_snooze_resolve() {
+        // 2025-01-08 is a Wednesday.
+        let wednesday = local(8, 15, 30);
+        let utc = |t: DateTime<Local>| t.with_timezone(&Utc);
+        assert_eq!(
+            SnoozeUntil::OneHour.resolve(wednesday),
+            utc(local(8, 16, 30))
+        );
+        assert_eq!(
+            SnoozeUntil::ThreeHours.resolve(wednesday),
+            utc(local(8, 18, 30))
+        );
+        assert_eq!(
+            SnoozeUntil::TomorrowMorning.resolve(wednesday),
+            utc(local(9, 9, 0))
+        );
+        assert_eq!(
+            SnoozeUntil::NextWeek.resolve(wednesday),
+            utc(local(13, 9, 0))
+        );
+        // From a Monday or a Sunday, next week is the following Monday.
+        assert_eq!(
+            SnoozeUntil::NextWeek.resolve(local(6, 8, 0)),
+            utc(local(13, 9, 0))
+        );
+        assert_eq!(
+            SnoozeUntil::NextWeek.resolve(local(12, 23, 0)),
+            utc(local(13, 9, 0))
+        );
+    }
+
+    #[test]
+    fn test_snooze_parse() {
+        let now = local(8, 15, 30);
+        let at = |t: DateTime<Local>| Some(SnoozeUntil::At(t.with_timezone(&Utc)));
+        assert_eq!(SnoozeUntil::parse("17:00", now), at(local(8, 17, 0)));
+        assert_eq!(SnoozeUntil::parse(" 09:15 ", now), at(local(9, 9, 15)));
+        assert_eq!(
+            SnoozeUntil::parse("2025-01-10 14:00", now),
+            at(local(10, 14, 0))
+        );
+        assert_eq!(SnoozeUntil::parse("2025-01-10", now), at(local(10, 9, 0)));
+        assert_eq!(SnoozeUntil::parse("2025-01-07 10:00", now), None);
+        assert_eq!(SnoozeUntil::parse("tomorrow", now), None);
+    }
+}
@@ -6,5 +6,5 @@ mod view;
 
 // Public API exports for external consumers
 #[allow(unused_imports)]
-pub use engine::{DesktopNotificationBatch, NotificationEngine};
+pub use engine::{DesktopNotificationBatch, DryRunReport, NotificationEngine};
 pub use screen::NotificationsScreen;
@@ -5,12 +5,16 @@
 //! - `rebuild_groups()` operates on already-processed notifications to avoid redundant work
 //! - `send_desktop_notifications()` reuses this data for consistency
 
+use iced::futures::future::join_all;
 use iced::widget::row;
 use iced::{Element, Fill, Task};
 
+use crate::cache::{DiskCache, SnoozedThread};
+use crate::github::client::account_key;
 use crate::github::{GitHubClient, GitHubError, NotificationView, SubjectType, UserInfo};
-use crate::settings::IconTheme;
-use crate::ui::screens::settings::rule_engine::{NotificationRuleSet, RuleAction};
+use crate::settings::{AppSettings, GroupingMode, IconTheme};
+use crate::tray::TrayManager;
+use crate::ui::screens::settings::rule_engine::{NotificationRuleSet, RuleAction, RuleSetStore};
 use crate::ui::window_state;
 
 use super::engine::{DesktopNotificationBatch, NotificationEngine};
@@ -19,7 +23,8 @@ use super::helper::{
     count_by_repo, count_by_type, group_processed_notifications,
 };
 use super::messages::{
-    BulkMessage, FilterMessage, NavigationMessage, NotificationMessage, ThreadMessage, ViewMessage,
+    BulkMessage, FilterMessage, NavigationMessage, NotificationMessage, SnoozeUntil,
+    SubscriptionChange, ThreadMessage, ViewMessage,
 };
 use super::view::{SidebarState, view_sidebar};
 
@@ -30,6 +35,8 @@ use std::collections::{HashMap, HashSet};
 pub struct NotificationsScreen {
     pub client: GitHubClient,
     pub user: UserInfo,
+    /// Account key (username plus GHES host) of `user`; tags fetched threads and keys caches.
+    pub account: String,
     pub all_notifications: Vec<NotificationView>,
     pub filtered_notifications: Vec<NotificationView>,
     /// Processed notifications with rule actions applied (Silent, Important, etc).
@@ -37,12 +44,14 @@ pub struct NotificationsScreen {
     pub groups: Vec<NotificationGroup>,
     pub filters: FilterSettings,
     pub is_loading: bool,
-    pub error_message: Option<String>,
+    pub error: Option<GitHubError>,
     pub type_counts: Vec<(SubjectType, usize)>,
     pub repo_counts: Vec<(String, usize)>,
     /// Tracks notification timestamps to detect updates vs new items.
     seen_notification_timestamps: HashMap<String, chrono::DateTime<chrono::Utc>>,
     rules: NotificationRuleSet,
+    /// Names of every stored rule set, for the top-bar switcher.
+    pub rule_set_names: Vec<String>,
     /// Important notifications from ALL accounts.
     /// Always pinned to top regardless of current account.
     cross_account_priority: Vec<ProcessedNotification>,
@@ -56,24 +65,50 @@ pub struct NotificationsScreen {
     pub selected_ids: HashSet<String>,
     /// Whether bulk selection mode is active.
     pub bulk_mode: bool,
+    /// Maximum number of notification pages fetched per refresh.
+    max_notification_pages: usize,
+    /// Auto-refresh is paused until this time after hitting a rate limit.
+    rate_limited_until: Option<chrono::DateTime<chrono::Utc>>,
+    /// Showing the cached snapshot from disk rather than a fresh fetch.
+    pub is_stale: bool,
+    /// Clients for every signed-in account while showing the unified inbox, by account key.
+    /// Empty when only the current account is shown.
+    account_clients: HashMap<String, GitHubClient>,
+    /// Accounts whose last fetch failed in the unified inbox, by account key.
+    account_errors: HashMap<String, GitHubError>,
+    /// Threads hidden until a chosen time, by thread ID.
+    snoozed: HashMap<String, SnoozedThread>,
+    /// Threads waiting for a typed snooze time, and the text typed so far.
+    pub snooze_prompt: Option<(HashSet<String>, String)>,
+    /// Outcome of a rule added from the context menu that needs the user's attention.
+    pub rule_notice: Option<String>,
+    /// Groups the user expanded or collapsed, by grouping mode and group title.
+    group_expansion: HashMap<GroupingMode, HashMap<String, bool>>,
 }
 
 impl NotificationsScreen {
     pub fn new(client: GitHubClient, user: UserInfo) -> (Self, Task<NotificationMessage>) {
-        let screen = Self {
+        let settings = AppSettings::load();
+        let account = account_key(&user.login, client.enterprise_api_base());
+        let mut screen = Self {
             client,
             user,
+            account,
             all_notifications: Vec::new(),
             filtered_notifications: Vec::new(),
             processed_notifications: Vec::new(),
             groups: Vec::new(),
-            filters: FilterSettings::default(),
+            filters: FilterSettings {
+                grouping: settings.grouping_mode,
+                ..Default::default()
+            },
             is_loading: true,
-            error_message: None,
+            error: None,
             type_counts: Vec::new(),
             repo_counts: Vec::new(),
             seen_notification_timestamps: HashMap::new(),
             rules: NotificationRuleSet::load(),
+            rule_set_names: RuleSetStore::load().names(),
             cross_account_priority: Vec::new(),
             scroll_offset: 0.0,
             viewport_height: 600.0, // Default, updated on first scroll
@@ -82,24 +117,175 @@ impl NotificationsScreen {
             is_loading_details: false,
             selected_ids: HashSet::new(),
             bulk_mode: false,
+            max_notification_pages: settings.max_notification_pages,
+            rate_limited_until: None,
+            is_stale: false,
+            account_clients: HashMap::new(),
+            account_errors: HashMap::new(),
+            snoozed: DiskCache::shared()
+                .and_then(|cache| cache.load_snoozes().ok())
+                .unwrap_or_default()
+                .into_iter()
+                .map(|s| (s.notification.id.clone(), s))
+                .collect(),
+            snooze_prompt: None,
+            rule_notice: None,
+            group_expansion: settings.group_expansion,
         };
+        screen.load_cached_notifications();
         let task = screen.fetch_notifications();
         (screen, task)
     }
 
+    /// Show the last persisted notification list immediately, marked stale,
+    /// so startup doesn't wait on (or fail without) the network.
+    fn load_cached_notifications(&mut self) {
+        let Some(mut cached) = DiskCache::shared()
+            .and_then(|cache| cache.load_notifications(&self.account).ok().flatten())
+        else {
+            return;
+        };
+
+        // Already-cached items shouldn't pop up as new desktop notifications.
+        for n in &cached {
+            self.seen_notification_timestamps
+                .insert(n.id.clone(), n.updated_at);
+        }
+        self.apply_snoozes(&mut cached);
+        self.all_notifications = cached;
+        self.is_stale = true;
+        self.rebuild_groups();
+    }
+
+    /// Persist the unread list (the startup view) for offline startup.
+    /// Accounts whose fetch failed keep their last good snapshot.
+    fn save_notification_snapshot(&self, notifications: &[NotificationView]) {
+        if self.filters.show_all {
+            return;
+        }
+        let Some(cache) = DiskCache::shared() else {
+            return;
+        };
+        if !self.is_unified() {
+            let _ = cache.save_notifications(&self.account, notifications);
+            return;
+        }
+        for account in self
+            .account_clients
+            .keys()
+            .filter(|account| !self.account_errors.contains_key(*account))
+        {
+            let own: Vec<_> = notifications
+                .iter()
+                .filter(|n| &n.account == account)
+                .cloned()
+                .collect();
+            let _ = cache.save_notifications(account, &own);
+        }
+    }
+
+    /// Whether the list merges all signed-in accounts.
+    pub fn is_unified(&self) -> bool {
+        !self.account_clients.is_empty()
+    }
+
+    /// Show the unified inbox for these accounts (username → client).
+    /// Also used to keep the clients current while the unified inbox is open.
+    pub fn set_account_clients(&mut self, clients: HashMap<String, GitHubClient>) {
+        self.account_errors
+            .retain(|account, _| clients.contains_key(account));
+        self.account_clients = clients;
+    }
+
+    /// Client for the account a notification belongs to.
+    fn client_for(&self, id: &str) -> GitHubClient {
+        self.all_notifications
+            .iter()
+            .find(|n| n.id == id)
+            .and_then(|n| self.account_clients.get(&n.account))
+            .unwrap_or(&self.client)
+            .clone()
+    }
+
+    /// Group thread IDs by the client that owns them.
+    fn clients_for_ids(&self, ids: &HashSet<String>) -> Vec<(GitHubClient, Vec<String>)> {
+        let mut by_account: HashMap<&str, Vec<String>> = HashMap::new();
+        for n in self
+            .all_notifications
+            .iter()
+            .filter(|n| ids.contains(&n.id))
+        {
+            by_account
+                .entry(n.account.as_str())
+                .or_default()
+                .push(n.id.clone());
+        }
+        by_account
+            .into_iter()
+            .map(|(account, ids)| {
+                let client = self.account_clients.get(account).unwrap_or(&self.client);
+                (client.clone(), ids)
+            })
+            .collect()
+    }
+
     fn fetch_notifications(&self) -> Task<NotificationMessage> {
-        let client = self.client.clone();
         let show_all = self.filters.show_all;
-        let account = self.user.login.clone();
+        let max_pages = self.max_notification_pages;
+
+        if self.is_unified() {
+            let accounts: Vec<(String, GitHubClient)> = self
+                .account_clients
+                .iter()
+                .map(|(account, client)| (account.clone(), client.clone()))
+                .collect();
+            return Task::perform(
+                async move {
+                    join_all(accounts.into_iter().map(|(account, client)| async move {
+                        let result = client
+                            .get_notification_views(show_all, &account, max_pages)
+                            .await;
+                        (account, result)
+                    }))
+                    .await
+                },
+                NotificationMessage::UnifiedRefreshComplete,
+            );
+        }
+
+        let client = self.client.clone();
+        let account = self.account.clone();
         Task::perform(
-            async move { client.get_notification_views(show_all, &account).await },
+            async move {
+                client
+                    .get_notification_views(show_all, &account, max_pages)
+                    .await
+            },
             NotificationMessage::RefreshComplete,
         )
     }
 
+    /// Whether auto-refresh should wait for a rate limit to reset.
+    pub fn is_backing_off(&self) -> bool {
+        self.rate_limited_until
+            .is_some_and(|until| chrono::Utc::now() < until)
+    }
+
+    /// Copy the grouping mode and remembered group expansion into `settings`.
+    pub fn store_grouping(&self, settings: &mut AppSettings) {
+        settings.grouping_mode = self.filters.grouping;
+        settings.group_expansion = self.group_expansion.clone();
+    }
+
+    /// Collapse every group, remembering it for the current grouping mode.
     pub fn collapse_all_groups(&mut self) {
+        let expansion = self
+            .group_expansion
+            .entry(self.filters.grouping)
+            .or_default();
         for group in &mut self.groups {
             group.is_expanded = false;
+            expansion.insert(group.title.clone(), false);
         }
     }
 
@@ -115,7 +301,7 @@ impl NotificationsScreen {
         self.type_counts = Vec::new();
         self.repo_counts = Vec::new();
         self.cross_account_priority = Vec::new();
-        self.error_message = None;
+        self.error = None;
 
         self.scroll_offset = 0.0;
         self.viewport_height = 600.0;
@@ -161,14 +347,17 @@ impl NotificationsScreen {
         eprintln!(
             "[DEBUG] update_cross_account_priority: found {} important from current account @{}",
             current_priority.len(),
-            self.user.login
+            self.account
         );
 
         // Merge with existing cross-account priority (remove duplicates by ID)
-        // and remove old entries from the same account (they'll be replaced)
-        let current_account = &self.user.login;
-        self.cross_account_priority
-            .retain(|p| p.notification.account != *current_account);
+        // and remove old entries from the shown accounts (they'll be replaced)
+        let current_account = &self.account;
+        let account_clients = &self.account_clients;
+        self.cross_account_priority.retain(|p| {
+            p.notification.account != *current_account
+                && !account_clients.contains_key(&p.notification.account)
+        });
 
         // Add current account's unread Important notifications
         self.cross_account_priority.extend(current_priority);
@@ -179,6 +368,27 @@ impl NotificationsScreen {
         );
     }
 
+    /// Pick up a rule set change from a schedule opening or closing.
+    /// Returns true if the rules in force changed.
+    pub fn sync_rule_set(&mut self) -> bool {
+        let store = RuleSetStore::load();
+        if store.active_set(&chrono::Local::now()).name == self.rules.name {
+            return false;
+        }
+        self.reload_rules(store);
+        true
+    }
+
+    fn reload_rules(&mut self, store: RuleSetStore) {
+        self.rules = store.active_set(&chrono::Local::now()).clone();
+        self.rule_set_names = store.names();
+        self.rebuild_groups();
+    }
+
+    pub fn active_rule_set(&self) -> &str {
+        &self.rules.name
+    }
+
     fn process_notifications(&mut self) {
         let engine = NotificationEngine::new(self.rules.clone());
 
@@ -239,11 +449,14 @@ impl NotificationsScreen {
         self.update_cross_account_priority();
 
         // Only show cross-account priority in "Unread" mode.
-        let all_processed = if self.filters.show_all {
-            eprintln!("[DEBUG] rebuild_groups: show_all mode, skipping cross-account priority");
+        // The unified inbox already contains every account.
+        let all_processed = if self.filters.show_all || self.is_unified() {
+            eprintln!(
+                "[DEBUG] rebuild_groups: show_all/unified mode, skipping cross-account priority"
+            );
             self.processed_notifications.clone()
         } else {
-            let current_account = &self.user.login;
+            let current_account = &self.account;
             let other_account_priority: Vec<ProcessedNotification> = self
                 .cross_account_priority
                 .iter()
@@ -288,11 +501,20 @@ impl NotificationsScreen {
 
         // Important group only shown in "Unread" mode.
         let show_priority_group = !self.filters.show_all;
-        self.groups = group_processed_notifications(&all_processed, show_priority_group);
+        self.groups = group_processed_notifications(
+            &all_processed,
+            show_priority_group,
+            self.filters.grouping,
+        );
 
-        // Restore expansion state for groups that existed before
+        // Restore expansion state for groups that existed before, falling back
+        // to what the user last chose for this grouping mode
+        let saved_expansion = self.group_expansion.get(&self.filters.grouping);
         for group in &mut self.groups {
-            if let Some(&was_expanded) = previous_expansion.get(&group.title) {
+            if let Some(&was_expanded) = previous_expansion
+                .get(&group.title)
+                .or_else(|| saved_expansion.and_then(|saved| saved.get(&group.title)))
+            {
                 group.is_expanded = was_expanded;
             }
         }
@@ -389,14 +611,85 @@ impl NotificationsScreen {
         crate::platform::trim_memory();
     }
 
+    /// Accounts the last fetch succeeded for.
+    fn fetched_accounts(&self) -> HashSet<&str> {
+        if self.is_unified() {
+            self.account_clients
+                .keys()
+                .filter(|account| !self.account_errors.contains_key(*account))
+                .map(String::as_str)
+                .collect()
+        } else {
+            HashSet::from([self.account.as_str()])
+        }
+    }
+
+    /// Deliver notifications held back by a Defer schedule as a single digest,
+    /// returning the IDs it settled. Threads read in the meantime are dropped
+    /// when their account was just fetched; those of accounts whose fetch
+    /// failed wait in the queue for the next one.
+    fn send_deferred_digest(
+        &self,
+        due: &[NotificationView],
+        current: &[NotificationView],
+    ) -> Vec<String> {
+        let fetched_accounts = self.fetched_accounts();
+        let settled: Vec<&NotificationView> = due
+            .iter()
+            .filter(|d| !(self.is_unified() && self.account_errors.contains_key(&d.account)))
+            .collect();
+        let pending: Vec<&NotificationView> = settled
+            .iter()
+            .copied()
+            .filter(|d| {
+                !fetched_accounts.contains(d.account.as_str())
+                    || current.iter().any(|n| n.id == d.id && n.unread)
+            })
+            .collect();
+        let settled_ids = settled.iter().map(|d| d.id.clone()).collect();
+
+        if pending.is_empty() {
+            return settled_ids;
+        }
+
+        let title = if pending.len() == 1 {
+            "1 notification while you were away".to_string()
+        } else {
+            format!("{} notifications while you were away", pending.len())
+        };
+        let mut body = pending
+            .iter()
+            .take(3)
+            .map(|n| format!("• {} ({})", n.title, n.repo_full_name))
+            .collect::<Vec<_>>()
+            .join("\n");
+        if pending.len() > 3 {
+            body.push_str(&format!("\n...and {} more", pending.len() - 3));
+        }
+        let url = match pending.as_slice() {
+            [single] => single.url.as_ref().map(|u| api_url_to_web_url(u)),
+            _ => None,
+        };
+
+        if let Err(e) = crate::platform::notify(&title, &body, url.as_deref()) {
+            eprintln!("Failed to send deferred digest: {}", e);
+        }
+        settled_ids
+    }
+
     pub fn update(&mut self, message: NotificationMessage) -> Task<NotificationMessage> {
         match message {
             NotificationMessage::Refresh => {
                 self.is_loading = true;
-                self.error_message = None;
+                self.error = None;
                 self.fetch_notifications()
             }
             NotificationMessage::RefreshComplete(result) => self.handle_refresh_complete(result),
+            NotificationMessage::UnifiedRefreshComplete(results) => {
+                let (result, failures) = merge_account_results(results);
+                self.account_errors = failures;
+                self.handle_refresh_complete(result)
+            }
             NotificationMessage::Filter(msg) => self.update_filter(msg),
             NotificationMessage::Thread(msg) => self.update_thread(msg),
             NotificationMessage::Bulk(msg) => self.update_bulk(msg),
@@ -425,6 +718,29 @@ impl NotificationsScreen {
                 self.rebuild_groups();
                 Task::none()
             }
+            FilterMessage::SelectGrouping(mode) => {
+                self.filters.grouping = mode;
+                self.scroll_offset = 0.0;
+                // Group titles differ per mode; start from the mode's saved state.
+                self.groups.clear();
+                self.rebuild_groups();
+                Task::none()
+            }
+            FilterMessage::SelectVisibility(visibility) => {
+                self.filters.visibility = visibility;
+                self.scroll_offset = 0.0;
+                self.rebuild_groups();
+                Task::none()
+            }
+            FilterMessage::SelectRuleSet(name) => {
+                let mut store = RuleSetStore::load();
+                if store.select(&name) {
+                    let _ = store.save();
+                }
+                self.reload_rules(store);
+                TrayManager::refresh_rule_sets();
+                Task::none()
+            }
         }
     }
 
@@ -437,7 +753,7 @@ impl NotificationsScreen {
                     let web_url = api_url_to_web_url(url);
                     let _ = open::that(&web_url);
                 }
-                let client = self.client.clone();
+                let client = self.client_for(&id);
                 let notif_id = id.clone();
                 Task::perform(
                     async move { client.mark_as_read(&notif_id).await },
@@ -450,7 +766,7 @@ impl NotificationsScreen {
                 )
             }
             ThreadMessage::MarkAsRead(id) => {
-                let client = self.client.clone();
+                let client = self.client_for(&id);
                 let notif_id = id.clone();
                 Task::perform(
                     async move { client.mark_as_read(&notif_id).await },
@@ -467,6 +783,7 @@ impl NotificationsScreen {
                     && let Some(notif) = self.all_notifications.iter_mut().find(|n| n.id == id)
                 {
                     notif.unread = false;
+                    clear_unread_mark(&id);
                     self.rebuild_groups();
                 }
                 Task::none()
@@ -474,20 +791,33 @@ impl NotificationsScreen {
             ThreadMessage::MarkAllAsRead => {
                 for notif in &mut self.all_notifications {
                     notif.unread = false;
+                    clear_unread_mark(&notif.id);
                 }
                 self.rebuild_groups();
 
-                let client = self.client.clone();
-                Task::perform(async move { client.mark_all_as_read().await }, |result| {
-                    NotificationMessage::Thread(ThreadMessage::MarkAllAsReadComplete(result))
-                })
+                let clients: Vec<GitHubClient> = if self.is_unified() {
+                    self.account_clients.values().cloned().collect()
+                } else {
+                    vec![self.client.clone()]
+                };
+                Task::perform(
+                    async move {
+                        join_all(clients.iter().map(|client| client.mark_all_as_read()))
+                            .await
+                            .into_iter()
+                            .collect::<Result<(), GitHubError>>()
+                    },
+                    |result| {
+                        NotificationMessage::Thread(ThreadMessage::MarkAllAsReadComplete(result))
+                    },
+                )
             }
             ThreadMessage::MarkAllAsReadComplete(_result) => {
                 self.is_loading = true;
                 self.fetch_notifications()
             }
             ThreadMessage::MarkAsDone(id) => {
-                let client = self.client.clone();
+                let client = self.client_for(&id);
                 let notif_id = id.clone();
                 Task::perform(
                     async move { client.mark_thread_as_done(&notif_id).await },
@@ -502,10 +832,236 @@ impl NotificationsScreen {
             ThreadMessage::MarkAsDoneComplete(id, result) => {
                 if result.is_ok() {
                     self.all_notifications.retain(|n| n.id != id);
+                    clear_unread_mark(&id);
                     self.rebuild_groups();
                 }
                 Task::none()
             }
+            ThreadMessage::MarkAsUnread(id) => {
+                self.mark_unread_locally(&HashSet::from([id]));
+                Task::none()
+            }
+            ThreadMessage::Snooze(id, until) => {
+                self.snooze(&HashSet::from([id]), until);
+                Task::none()
+            }
+            ThreadMessage::ChooseSnoozeTime(id) => {
+                self.snooze_prompt = Some((HashSet::from([id]), String::new()));
+                Task::none()
+            }
+            ThreadMessage::SnoozeTimeChanged(input) => {
+                if let Some((_, text)) = &mut self.snooze_prompt {
+                    *text = input;
+                }
+                Task::none()
+            }
+            ThreadMessage::SnoozeAtChosenTime => {
+                let Some((ids, input)) = &self.snooze_prompt else {
+                    return Task::none();
+                };
+                // Submitting with Enter bypasses the disabled button.
+                let Some(until) = SnoozeUntil::parse(input, chrono::Local::now()) else {
+                    return Task::none();
+                };
+                let ids = ids.clone();
+                self.snooze_prompt = None;
+                self.snooze(&ids, until);
+                Task::none()
+            }
+            ThreadMessage::CancelSnoozeTime => {
+                self.snooze_prompt = None;
+                Task::none()
+            }
+            ThreadMessage::CreateRule(id, quick) => {
+                let Some(notification) = self.all_notifications.iter().find(|n| n.id == id) else {
+                    return Task::none();
+                };
+                self.rule_notice = match self.rules.add_quick_rule(quick, notification) {
+                    Ok(()) => {
+                        let _ = self.rules.save();
+                        self.rebuild_groups();
+                        (!self.rules.enabled).then(|| {
+                            format!(
+                                "Rule added, but the \"{}\" rule set is turned off. Turn it on in the Rule Engine to apply it.",
+                                self.rules.name
+                            )
+                        })
+                    }
+                    Err(conflict) => Some(conflict.to_string()),
+                };
+                Task::none()
+            }
+            ThreadMessage::DismissRuleNotice => {
+                self.rule_notice = None;
+                Task::none()
+            }
+            ThreadMessage::Unsubscribe(id) => {
+                self.change_subscription(id, SubscriptionChange::Unsubscribe)
+            }
+            ThreadMessage::Mute(id) => self.change_subscription(id, SubscriptionChange::Mute),
+            ThreadMessage::Resubscribe(id) => {
+                self.change_subscription(id, SubscriptionChange::Resubscribe)
+            }
+            ThreadMessage::SubscriptionComplete(id, change, result) => {
+                if let Err(e) = result {
+                    eprintln!("[ERROR] Failed to {:?} thread {}: {}", change, id, e);
+                }
+                Task::none()
+            }
+        }
+    }
+
+    /// Apply a subscription change to a thread with the owning account's client.
+    fn change_subscription(
+        &self,
+        id: String,
+        change: SubscriptionChange,
+    ) -> Task<NotificationMessage> {
+        let client = self.client_for(&id);
+        let thread_id = id.clone();
+        Task::perform(
+            async move { apply_subscription_change(&client, &thread_id, change).await },
+            move |result| {
+                NotificationMessage::Thread(ThreadMessage::SubscriptionComplete(
+                    id.clone(),
+                    change,
+                    result,
+                ))
+            },
+        )
+    }
+
+    /// Keep threads unread locally, persisting them so refreshes don't undo it.
+    fn mark_unread_locally(&mut self, ids: &HashSet<String>) {
+        let cache = DiskCache::shared();
+        for notif in self
+            .all_notifications
+            .iter_mut()
+            .filter(|n| ids.contains(&n.id))
+        {
+            notif.unread = true;
+            if let Some(cache) = cache {
+                let _ = cache.save_unread_mark(notif);
+            }
+        }
+        self.rebuild_groups();
+    }
+
+    /// Hide threads until `until`, persisting the snooze across restarts.
+    fn snooze(&mut self, ids: &HashSet<String>, until: SnoozeUntil) {
+        let until = until.resolve(chrono::Local::now());
+        let cache = DiskCache::shared();
+        for notif in self
+            .all_notifications
+            .iter()
+            .filter(|n| ids.contains(&n.id))
+        {
+            let snooze = SnoozedThread {
+                notification: notif.clone(),
+                until,
+            };
+            if let Some(cache) = cache {
+                let _ = cache.save_snooze(&snooze);
+            }
+            self.snoozed.insert(notif.id.clone(), snooze);
+        }
+        self.all_notifications.retain(|n| !ids.contains(&n.id));
+        self.rebuild_groups();
+    }
+
+    /// Drop snoozed threads from a fetch.
+    /// A snooze ends early once GitHub reports newer activity on the thread.
+    fn apply_snoozes(&mut self, notifications: &mut Vec<NotificationView>) {
+        for id in filter_snoozed(&mut self.snoozed, notifications) {
+            if let Some(cache) = DiskCache::shared() {
+                let _ = cache.remove_snooze(&id);
+            }
+        }
+    }
+
+    /// Wake snoozes from the cache while another screen is open.
+    /// The threads come back with the next fetch once the list is shown again.
+    pub fn wake_stored_snoozes() {
+        let Some(cache) = DiskCache::shared() else {
+            return;
+        };
+        let now = chrono::Utc::now();
+        let woken: Vec<NotificationView> = cache
+            .load_snoozes()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|s| s.until <= now)
+            .map(|s| {
+                let _ = cache.remove_snooze(&s.notification.id);
+                s.notification
+            })
+            .collect();
+        notify_woken(&woken);
+    }
+
+    /// Bring back threads whose snooze has run out, with a desktop notification.
+    /// Called from the refresh tick.
+    pub fn wake_snoozed(&mut self) {
+        let now = chrono::Utc::now();
+        let due_ids: Vec<String> = self
+            .snoozed
+            .values()
+            .filter(|s| s.until <= now)
+            .map(|s| s.notification.id.clone())
+            .collect();
+        if due_ids.is_empty() {
+            return;
+        }
+
+        let cache = DiskCache::shared();
+        let mut woken = Vec::new();
+        for id in due_ids {
+            let Some(snooze) = self.snoozed.remove(&id) else {
+                continue;
+            };
+            if let Some(cache) = cache {
+                let _ = cache.remove_snooze(&id);
+            }
+            let notif = snooze.notification;
+            if self.shows_account(&notif.account)
+                && !self.all_notifications.iter().any(|n| n.id == notif.id)
+            {
+                self.all_notifications.push(notif.clone());
+            }
+            woken.push(notif);
+        }
+        self.all_notifications
+            .sort_by_key(|n| std::cmp::Reverse(n.updated_at));
+        self.rebuild_groups();
+        notify_woken(&woken);
+    }
+
+    /// Whether threads of `account` belong in the current list.
+    fn shows_account(&self, account: &str) -> bool {
+        account == self.account || self.account_clients.contains_key(account)
+    }
+
+    /// Re-apply local unread marks to a fresh fetch.
+    /// A mark is dropped once GitHub reports newer activity on the thread.
+    fn apply_unread_marks(&self, notifications: &mut Vec<NotificationView>) {
+        let Some(cache) = DiskCache::shared() else {
+            return;
+        };
+        let marks = cache.load_unread_marks().unwrap_or_default();
+        for mark in marks {
+            if !self.shows_account(&mark.account) {
+                continue;
+            }
+            match notifications.iter_mut().find(|n| n.id == mark.id) {
+                Some(n) if n.updated_at > mark.updated_at => {
+                    let _ = cache.remove_unread_mark(&mark.id);
+                }
+                Some(n) => n.unread = true,
+                None => notifications.push(NotificationView {
+                    unread: true,
+                    ..mark
+                }),
+            }
         }
     }
 
@@ -542,18 +1098,57 @@ impl NotificationsScreen {
                     if let Some(notif) = self.all_notifications.iter_mut().find(|n| &n.id == id) {
                         notif.unread = false;
                     }
+                    clear_unread_mark(id);
                 }
                 self.rebuild_groups();
 
-                let client = self.client.clone();
-                let ids: Vec<String> = self.selected_ids.iter().cloned().collect();
+                let batches = self.clients_for_ids(&self.selected_ids);
                 self.selected_ids.clear();
                 self.bulk_mode = false;
 
                 Task::perform(
                     async move {
-                        for id in ids {
-                            let _ = client.mark_as_read(&id).await;
+                        for (client, ids) in batches {
+                            for id in ids {
+                                let _ = client.mark_as_read(&id).await;
+                            }
+                        }
+                        Ok::<(), GitHubError>(())
+                    },
+                    |_| NotificationMessage::Bulk(BulkMessage::Complete),
+                )
+            }
+            BulkMessage::MarkAsUnread => {
+                let ids = std::mem::take(&mut self.selected_ids);
+                self.bulk_mode = false;
+                self.mark_unread_locally(&ids);
+                Task::none()
+            }
+            // The snooze picker can't be disabled, so ignore it with nothing selected.
+            BulkMessage::Snooze(_) if self.selected_ids.is_empty() => Task::none(),
+            BulkMessage::Snooze(until) => {
+                let ids = std::mem::take(&mut self.selected_ids);
+                self.bulk_mode = false;
+                self.snooze(&ids, until);
+                Task::none()
+            }
+            BulkMessage::ChooseSnoozeTime => {
+                let ids = std::mem::take(&mut self.selected_ids);
+                self.bulk_mode = false;
+                self.snooze_prompt = Some((ids, String::new()));
+                Task::none()
+            }
+            BulkMessage::ChangeSubscription(change) => {
+                let batches = self.clients_for_ids(&self.selected_ids);
+                self.selected_ids.clear();
+                self.bulk_mode = false;
+
+                Task::perform(
+                    async move {
+                        for (client, ids) in batches {
+                            for id in ids {
+                                let _ = apply_subscription_change(&client, &id, change).await;
+                            }
                         }
                         Ok::<(), GitHubError>(())
                     },
@@ -561,19 +1156,23 @@ impl NotificationsScreen {
                 )
             }
             BulkMessage::MarkAsDone => {
-                let ids_to_remove: Vec<String> = self.selected_ids.iter().cloned().collect();
+                let batches = self.clients_for_ids(&self.selected_ids);
+                for id in &self.selected_ids {
+                    clear_unread_mark(id);
+                }
                 self.all_notifications
                     .retain(|n| !self.selected_ids.contains(&n.id));
                 self.rebuild_groups();
 
-                let client = self.client.clone();
                 self.selected_ids.clear();
                 self.bulk_mode = false;
 
                 Task::perform(
                     async move {
-                        for id in ids_to_remove {
-                            let _ = client.mark_thread_as_done(&id).await;
+                        for (client, ids) in batches {
+                            for id in ids {
+                                let _ = client.mark_thread_as_done(&id).await;
+                            }
                         }
                         Ok::<(), GitHubError>(())
                     },
@@ -589,6 +1188,10 @@ impl NotificationsScreen {
             ViewMessage::ToggleGroup(index) => {
                 if let Some(group) = self.groups.get_mut(index) {
                     group.is_expanded = !group.is_expanded;
+                    self.group_expansion
+                        .entry(self.filters.grouping)
+                        .or_default()
+                        .insert(group.title.clone(), group.is_expanded);
                 }
                 Task::none()
             }
@@ -603,7 +1206,7 @@ impl NotificationsScreen {
                     self.selected_notification_details = None;
                     self.is_loading_details = true;
 
-                    let client = self.client.clone();
+                    let client = self.client_for(&id);
                     let subject_type = notif.subject_type;
                     let subject_url = notif.url.clone();
                     let latest_comment_url = notif.latest_comment_url.clone();
@@ -666,7 +1269,9 @@ impl NotificationsScreen {
             NavigationMessage::Logout => Task::none(),
             NavigationMessage::OpenSettings => Task::none(),
             NavigationMessage::OpenRuleEngine => Task::none(),
+            NavigationMessage::InspectRule(_) => Task::none(),
             NavigationMessage::SwitchAccount(_) => Task::none(),
+            NavigationMessage::ShowAllAccounts => Task::none(),
             NavigationMessage::TogglePowerMode => Task::none(),
         }
     }
@@ -678,18 +1283,33 @@ impl NotificationsScreen {
         self.is_loading = false;
         match result {
             Ok(mut notifications) => {
+                self.apply_unread_marks(&mut notifications);
+                self.apply_snoozes(&mut notifications);
+                self.save_notification_snapshot(&notifications);
+                self.is_stale = false;
+
                 let mock_count =
                     crate::MOCK_NOTIFICATION_COUNT.load(std::sync::atomic::Ordering::Relaxed);
                 if mock_count > 0 {
-                    let mock =
-                        crate::specs::generate_mock_notifications(mock_count, &self.user.login);
+                    let mock = crate::specs::generate_mock_notifications(mock_count, &self.account);
                     notifications.extend(mock);
                 }
 
                 let engine = NotificationEngine::new(self.rules.clone());
-                let processed_for_desktop = engine.process_all(&notifications);
+                let processed_for_desktop =
+                    engine.process_fetch(&notifications, &self.fetched_accounts());
                 let is_hidden = window_state::is_hidden();
 
+                NotificationEngine::queue_deferred(
+                    &processed_for_desktop,
+                    &self.seen_notification_timestamps,
+                );
+                let due = engine.due_deferred();
+                if !due.is_empty() {
+                    let settled = self.send_deferred_digest(&due, &notifications);
+                    NotificationEngine::remove_deferred(&settled);
+                }
+
                 // Show desktop notifications when window is hidden or unfocused
                 let should_notify = is_hidden || !window_state::is_focused();
                 if should_notify {
@@ -714,10 +1334,12 @@ impl NotificationsScreen {
                     self.rebuild_groups();
                     crate::platform::trim_memory();
                 }
-                self.error_message = None;
+                self.error = None;
+                self.rate_limited_until = None;
             }
             Err(e) => {
-                self.error_message = Some(e.to_string());
+                self.rate_limited_until = e.retry_at();
+                self.error = Some(e);
             }
         }
         Task::none()
@@ -726,10 +1348,21 @@ impl NotificationsScreen {
     pub fn view<'a>(
         &'a self,
         accounts: Vec<String>,
+        mut attention: Vec<(String, String)>,
         icon_theme: IconTheme,
         sidebar_width: f32,
         power_mode: bool,
     ) -> Element<'a, NotificationMessage> {
+        // Accounts that failed the last unified fetch get the same badge as restore failures.
+        let mut failed: Vec<_> = self
+            .account_errors
+            .iter()
+            .filter(|(account, _)| !attention.iter().any(|(key, _)| key == *account))
+            .map(|(account, e)| (account.clone(), format!("Fetch failed: {}", e)))
+            .collect();
+        failed.sort();
+        attention.extend(failed);
+
         // Compute dynamic sidebar totals
         let total_count = if let Some(ref repo) = self.filters.selected_repo {
             self.all_notifications
@@ -740,6 +1373,19 @@ impl NotificationsScreen {
             self.all_notifications.len()
         };
 
+        // Counted like `total_count`, so Public is the remainder.
+        let private_count = self
+            .all_notifications
+            .iter()
+            .filter(|n| n.is_private)
+            .filter(|n| {
+                self.filters
+                    .selected_repo
+                    .as_ref()
+                    .is_none_or(|repo| &n.repo_full_name == repo)
+            })
+            .count();
+
         let total_repo_count = if let Some(ref selected_type) = self.filters.selected_type {
             self.all_notifications
                 .iter()
@@ -753,11 +1399,16 @@ impl NotificationsScreen {
             // Sidebar
             view_sidebar(SidebarState {
                 user: &self.user,
+                account: &self.account,
                 accounts,
+                unified: self.is_unified(),
+                attention,
                 type_counts: &self.type_counts,
                 repo_counts: &self.repo_counts,
                 selected_type: self.filters.selected_type,
                 selected_repo: self.filters.selected_repo.as_deref(),
+                private_count,
+                selected_visibility: self.filters.visibility,
                 total_count,
                 total_repo_count,
                 icon_theme,
@@ -781,3 +1432,185 @@ impl NotificationsScreen {
         self.selected_notification_details.as_ref()
     }
 }
+
+/// Drop snoozed threads from a fetch, returning the IDs whose snooze ended early
+/// because GitHub reports newer activity than when they were snoozed.
+fn filter_snoozed(
+    snoozed: &mut HashMap<String, SnoozedThread>,
+    notifications: &mut Vec<NotificationView>,
+) -> Vec<String> {
+    let updated: Vec<String> = notifications
+        .iter()
+        .filter(|n| {
+            snoozed
+                .get(&n.id)
+                .is_some_and(|s| n.updated_at > s.notification.updated_at)
+        })
+        .map(|n| n.id.clone())
+        .collect();
+    for id in &updated {
+        snoozed.remove(id);
+    }
+    notifications.retain(|n| !snoozed.contains_key(&n.id));
+    updated
+}
+
+/// Send one desktop notification for threads whose snooze ran out.
+fn notify_woken(woken: &[NotificationView]) {
+    let (title, body, url) = match woken {
+        [] => return,
+        [single] => (
+            "Snoozed notification is back".to_string(),
+            format!("{} ({})", single.title, single.repo_full_name),
+            single.url.as_ref().map(|u| api_url_to_web_url(u)),
+        ),
+        many => (
+            format!("{} snoozed notifications are back", many.len()),
+            many.iter()
+                .take(3)
+                .map(|n| format!("• {} ({})", n.title, n.repo_full_name))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            None,
+        ),
+    };
+    if let Err(e) = crate::platform::notify(&title, &body, url.as_deref()) {
+        eprintln!("Failed to send snooze reminder: {}", e);
+    }
+}
+
+/// Merge per-account fetches, newest first, returning the failures by account key.
+/// One unreachable account doesn't hide the others; the list is only an error if all failed.
+fn merge_account_results(
+    results: Vec<(String, Result<Vec<NotificationView>, GitHubError>)>,
+) -> (
+    Result<Vec<NotificationView>, GitHubError>,
+    HashMap<String, GitHubError>,
+) {
+    let mut merged = Vec::new();
+    let mut failures = HashMap::new();
+    let mut first_error = None;
+    let mut any_ok = false;
+    for (account, result) in results {
+        match result {
+            Ok(notifications) => {
+                any_ok = true;
+                merged.extend(notifications);
+            }
+            Err(e) => {
+                eprintln!(
+                    "[ERROR] Failed to fetch notifications for {}: {}",
+                    account, e
+                );
+                first_error.get_or_insert_with(|| e.clone());
+                failures.insert(account, e);
+            }
+        }
+    }
+    if !any_ok && let Some(e) = first_error {
+        return (Err(e), failures);
+    }
+    merged.sort_by_key(|n| std::cmp::Reverse(n.updated_at));
+    (Ok(merged), failures)
+}
+
+/// Send a subscription change to GitHub. The thread's unread state is left alone.
+async fn apply_subscription_change(
+    client: &GitHubClient,
+    thread_id: &str,
+    change: SubscriptionChange,
+) -> Result<(), GitHubError> {
+    match change {
+        SubscriptionChange::Unsubscribe => client.delete_thread_subscription(thread_id).await,
+        SubscriptionChange::Mute => client.set_thread_subscription(thread_id, true).await,
+        SubscriptionChange::Resubscribe => client.set_thread_subscription(thread_id, false).await,
+    }
+}
+
+/// Forget a local unread mark once the thread is read or done.
+fn clear_unread_mark(id: &str) {
+    if let Some(cache) = DiskCache::shared() {
+        let _ = cache.remove_unread_mark(id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::types::NotificationReason;
+    use chrono::{Duration, Utc};
+
+    fn make_notification(id: &str, account: &str, age_mins: i64) -> NotificationView {
+        NotificationView {
+            id: id.to_string(),
+            unread: true,
+            reason: NotificationReason::Mention,
+            updated_at: Utc::now() - Duration::minutes(age_mins),
+            title: "Test".to_string(),
+            repo_name: "repo".to_string(),
+            repo_full_name: "owner/repo".to_string(),
+            url: None,
+            latest_comment_url: None,
+            avatar_url: String::new(),
+            is_private: false,
+            subject_type: SubjectType::Issue,
+            account: account.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_merge_account_results_keeps_failures() {
+        let offline = || GitHubError::Request("timeout".to_string());
+
+        let (merged, failures) = merge_account_results(vec![
+            (
+                "alice".to_string(),
+                Ok(vec![make_notification("1", "alice", 10)]),
+            ),
+            ("bob".to_string(), Err(offline())),
+            (
+                "carol@ghe.example.com".to_string(),
+                Ok(vec![make_notification("2", "carol@ghe.example.com", 5)]),
+            ),
+        ]);
+        let ids: Vec<_> = merged.unwrap().into_iter().map(|n| n.id).collect();
+        assert_eq!(ids, ["2", "1"]);
+        assert_eq!(failures.keys().collect::<Vec<_>>(), ["bob"]);
+
+        let (merged, failures) = merge_account_results(vec![
+            ("alice".to_string(), Err(offline())),
+            ("bob".to_string(), Err(offline())),
+        ]);
+        assert!(merged.is_err());
+        assert_eq!(failures.len(), 2);
+    }
+
+    #[test]
+    fn test_snooze_ends_on_newer_activity() {
+        let snooze = |notification: NotificationView| SnoozedThread {
+            notification,
+            until: Utc::now() + Duration::hours(1),
+        };
+        let quiet = make_notification("1", "alice", 30);
+        let active = make_notification("2", "alice", 30);
+        let mut snoozed = HashMap::from([
+            ("1".to_string(), snooze(quiet.clone())),
+            ("2".to_string(), snooze(active.clone())),
+        ]);
+
+        let mut fetched = vec![
+            quiet,
+            NotificationView {
+                updated_at: active.updated_at + Duration::minutes(5),
+                ..active
+            },
+            make_notification("3", "alice", 10),
+        ];
+        let ended = filter_snoozed(&mut snoozed, &mut fetched);
+
+        assert_eq!(ended, ["2"]);
+        assert_eq!(snoozed.keys().collect::<Vec<_>>(), ["1"]);
+        let ids: Vec<_> = fetched.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, ["2", "3"]);
+    }
+}
@@ -1,12 +1,14 @@
 //! Bulk action bar view for Power Mode.
 
-use iced::widget::{Space, button, container, row, text};
+use iced::widget::{Space, button, container, pick_list, row, text};
 use iced::{Alignment, Element, Fill};
 
 use crate::settings::IconTheme;
 use crate::ui::{icons, theme};
 
-use crate::ui::screens::notifications::messages::{BulkMessage, NotificationMessage};
+use crate::ui::screens::notifications::messages::{
+    BulkMessage, NotificationMessage, SnoozeUntil, SubscriptionChange,
+};
 use crate::ui::screens::notifications::screen::NotificationsScreen;
 
 impl NotificationsScreen {
@@ -92,6 +94,23 @@ impl NotificationsScreen {
             (selection_count > 0).then_some(NotificationMessage::Bulk(BulkMessage::MarkAsDone)),
         );
 
+        // Secondary actions: local unread mark and thread subscription.
+        let secondary_btn = |label: &'static str, message: BulkMessage| {
+            button(text(label).size(12).color(p.text_secondary))
+                .style(theme::ghost_button)
+                .padding([6, 10])
+                .on_press_maybe((selection_count > 0).then_some(NotificationMessage::Bulk(message)))
+        };
+
+        let snooze_picker = pick_list(SnoozeUntil::ALL, None::<SnoozeUntil>, |until| {
+            NotificationMessage::Bulk(BulkMessage::Snooze(until))
+        })
+        .placeholder("Snooze...")
+        .text_size(12)
+        .padding([5, 8])
+        .style(theme::pick_list_style)
+        .menu_style(theme::menu_style);
+
         container(
             row![
                 text(selection_text).size(13).color(p.text_primary),
@@ -99,6 +118,22 @@ impl NotificationsScreen {
                 select_all_btn,
                 clear_btn,
                 Space::new().width(Fill),
+                secondary_btn("Mark Unread", BulkMessage::MarkAsUnread),
+                snooze_picker,
+                secondary_btn("Snooze until...", BulkMessage::ChooseSnoozeTime),
+                secondary_btn(
+                    "Unsubscribe",
+                    BulkMessage::ChangeSubscription(SubscriptionChange::Unsubscribe)
+                ),
+                secondary_btn(
+                    "Mute",
+                    BulkMessage::ChangeSubscription(SubscriptionChange::Mute)
+                ),
+                secondary_btn(
+                    "Re-subscribe",
+                    BulkMessage::ChangeSubscription(SubscriptionChange::Resubscribe)
+                ),
+                Space::new().width(8),
                 mark_read_btn,
                 Space::new().width(8),
                 archive_btn,
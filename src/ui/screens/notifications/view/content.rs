@@ -1,6 +1,6 @@
 //! Main content view - notification list with virtual scrolling.
 
-use iced::widget::{Space, button, column, container, row, scrollable};
+use iced::widget::{Space, button, column, container, row, scrollable, text, text_input};
 use iced::{Alignment, Element, Fill};
 
 use crate::settings::IconTheme;
@@ -10,7 +10,9 @@ use crate::ui::{icons, theme};
 use super::group::view_group_header;
 use super::states::{view_empty, view_error, view_loading};
 
-use crate::ui::screens::notifications::messages::{BulkMessage, NotificationMessage, ViewMessage};
+use crate::ui::screens::notifications::messages::{
+    BulkMessage, NotificationMessage, SnoozeUntil, ThreadMessage, ViewMessage,
+};
 use crate::ui::screens::notifications::screen::NotificationsScreen;
 
 impl NotificationsScreen {
@@ -23,6 +25,8 @@ impl NotificationsScreen {
             // In power mode, add bulk action bar above content
             column![
                 self.view_bulk_action_bar(icon_theme),
+                self.view_snooze_prompt(),
+                self.view_rule_notice(icon_theme),
                 self.view_content(icon_theme, power_mode)
             ]
             .width(Fill)
@@ -31,6 +35,8 @@ impl NotificationsScreen {
         } else {
             column![
                 self.view_content_header(icon_theme),
+                self.view_snooze_prompt(),
+                self.view_rule_notice(icon_theme),
                 self.view_content(icon_theme, power_mode)
             ]
             .width(Fill)
@@ -49,7 +55,10 @@ impl NotificationsScreen {
             return view_loading();
         }
 
-        if let Some(ref error) = self.error_message {
+        // With a cached snapshot on screen, errors show as a banner instead.
+        if let Some(ref error) = self.error
+            && (!self.is_stale || self.all_notifications.is_empty())
+        {
             return view_error(error, icon_theme);
         }
 
@@ -65,6 +74,7 @@ impl NotificationsScreen {
         }
 
         let in_bulk_mode = self.bulk_mode && power_mode;
+        let unified = self.is_unified();
         let pp = theme::palette();
 
         // === HEIGHT ESTIMATES FOR VIRTUAL SCROLLING ===
@@ -129,8 +139,14 @@ impl NotificationsScreen {
                     for p in &group.notifications[render_start..render_end] {
                         let item_element: Element<'_, NotificationMessage> = if in_bulk_mode {
                             // Bulk mode: checkbox + notification item
-                            let item =
-                                notification_item(p, icon_theme, power_mode, is_priority, false);
+                            let item = notification_item(
+                                p,
+                                icon_theme,
+                                power_mode,
+                                is_priority,
+                                false,
+                                unified,
+                            );
                             let id = p.notification.id.clone();
                             let is_selected = self.selected_ids.contains(&id);
 
@@ -187,7 +203,7 @@ impl NotificationsScreen {
                             .into()
                         } else {
                             // Normal mode: just the notification item
-                            notification_item(p, icon_theme, power_mode, is_priority, true)
+                            notification_item(p, icon_theme, power_mode, is_priority, true, unified)
                         };
 
                         content = content.push(item_element);
@@ -210,7 +226,7 @@ impl NotificationsScreen {
 
         content = content.push(Space::new().height(content_padding));
 
-        container(
+        let list = container(
             scrollable(content)
                 .on_scroll(|v| NotificationMessage::View(ViewMessage::OnScroll(v)))
                 .height(Fill)
@@ -219,7 +235,122 @@ impl NotificationsScreen {
         )
         .style(theme::app_container)
         .height(Fill)
+        .width(Fill);
+
+        if self.is_stale {
+            column![self.view_stale_banner(icon_theme), list].into()
+        } else {
+            list.into()
+        }
+    }
+
+    /// Prompt for a typed snooze time, shown after "Snooze until...".
+    fn view_snooze_prompt(&self) -> Element<'_, NotificationMessage> {
+        let Some((ids, input)) = &self.snooze_prompt else {
+            return Space::new().height(0).into();
+        };
+        let p = theme::palette();
+        let thread = NotificationMessage::Thread;
+        let until = SnoozeUntil::parse(input, chrono::Local::now());
+
+        let label = match ids.len() {
+            1 => "Snooze until".to_string(),
+            n => format!("Snooze {} threads until", n),
+        };
+        let hint = match until {
+            Some(until) => until.to_string(),
+            None if input.trim().is_empty() => String::new(),
+            None => "Enter a future time".to_string(),
+        };
+
+        container(
+            row![
+                text(label).size(12).color(p.text_primary),
+                Space::new().width(8),
+                text_input("17:30 or 2025-01-20 09:00", input)
+                    .on_input(move |s| thread(ThreadMessage::SnoozeTimeChanged(s)))
+                    .on_submit(thread(ThreadMessage::SnoozeAtChosenTime))
+                    .padding(6)
+                    .size(12)
+                    .style(theme::text_input_style)
+                    .width(180),
+                Space::new().width(8),
+                text(hint).size(11).color(p.text_muted),
+                Space::new().width(Fill),
+                button(text("Snooze").size(12))
+                    .style(theme::primary_button)
+                    .padding([6, 12])
+                    .on_press_maybe(until.map(|_| thread(ThreadMessage::SnoozeAtChosenTime))),
+                Space::new().width(8),
+                button(text("Cancel").size(12).color(p.text_secondary))
+                    .style(theme::ghost_button)
+                    .padding([6, 10])
+                    .on_press(thread(ThreadMessage::CancelSnoozeTime)),
+            ]
+            .align_y(Alignment::Center),
+        )
+        .padding([6, 12])
+        .width(Fill)
+        .style(move |_| container::Style {
+            background: Some(iced::Background::Color(p.bg_control)),
+            ..Default::default()
+        })
+        .into()
+    }
+
+    /// Notice about a rule added from the context menu.
+    fn view_rule_notice(&self, icon_theme: IconTheme) -> Element<'_, NotificationMessage> {
+        let Some(notice) = &self.rule_notice else {
+            return Space::new().height(0).into();
+        };
+        let p = theme::palette();
+
+        container(
+            row![
+                icons::icon_alert(12.0, p.accent_warning, icon_theme),
+                Space::new().width(8),
+                text(notice.as_str()).size(12).color(p.text_secondary),
+                Space::new().width(Fill),
+                button(icons::icon_x(12.0, p.text_secondary, icon_theme))
+                    .style(theme::ghost_button)
+                    .padding(4)
+                    .on_press(NotificationMessage::Thread(
+                        ThreadMessage::DismissRuleNotice
+                    )),
+            ]
+            .align_y(Alignment::Center),
+        )
+        .padding([6, 12])
+        .width(Fill)
+        .style(move |_| container::Style {
+            background: Some(iced::Background::Color(p.accent_warning.scale_alpha(0.12))),
+            ..Default::default()
+        })
+        .into()
+    }
+
+    /// Banner shown while the list comes from the offline cache.
+    fn view_stale_banner(&self, icon_theme: IconTheme) -> Element<'_, NotificationMessage> {
+        let p = theme::palette();
+        let detail = match &self.error {
+            Some(error) => format!("Showing cached notifications: {}", error),
+            None => "Showing cached notifications while reconnecting...".to_string(),
+        };
+
+        container(
+            row![
+                icons::icon_wifi_off(12.0, p.accent_warning, icon_theme),
+                Space::new().width(8),
+                text(detail).size(12).color(p.text_secondary),
+            ]
+            .align_y(Alignment::Center),
+        )
+        .padding([6, 12])
         .width(Fill)
+        .style(move |_| container::Style {
+            background: Some(iced::Background::Color(p.bg_control)),
+            ..Default::default()
+        })
         .into()
     }
 
@@ -235,6 +366,7 @@ impl NotificationsScreen {
         let content_padding: f32 = 8.0;
         let pp = theme::palette();
         let in_bulk_mode = self.bulk_mode && power_mode;
+        let unified = self.is_unified();
 
         let mut content = column![]
             .spacing(column_spacing)
@@ -252,7 +384,14 @@ impl NotificationsScreen {
 
                 for p in &group.notifications {
                     let item_element: Element<'_, NotificationMessage> = if in_bulk_mode {
-                        let item = notification_item(p, icon_theme, power_mode, is_priority, false);
+                        let item = notification_item(
+                            p,
+                            icon_theme,
+                            power_mode,
+                            is_priority,
+                            false,
+                            unified,
+                        );
                         let id = p.notification.id.clone();
                         let is_selected = self.selected_ids.contains(&id);
 
@@ -308,7 +447,7 @@ impl NotificationsScreen {
                         .width(Fill)
                         .into()
                     } else {
-                        notification_item(p, icon_theme, power_mode, is_priority, true)
+                        notification_item(p, icon_theme, power_mode, is_priority, true, unified)
                     };
 
                     content = content.push(item_element);
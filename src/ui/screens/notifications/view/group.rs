@@ -1,4 +1,4 @@
-//! Notification group component - collapsible time-based groups.
+//! Notification group component - collapsible notification groups.
 
 use iced::widget::{Space, button, container, row, text};
 use iced::{Alignment, Element, Fill};
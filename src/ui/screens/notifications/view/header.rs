@@ -1,9 +1,9 @@
 //! Content header view - title, sync status, filters, actions.
 
-use iced::widget::{Space, button, container, row, text};
+use iced::widget::{Space, button, container, pick_list, row, text};
 use iced::{Alignment, Color, Element, Fill};
 
-use crate::settings::IconTheme;
+use crate::settings::{GroupingMode, IconTheme};
 use crate::ui::{icons, theme};
 
 use crate::ui::screens::notifications::messages::{
@@ -22,7 +22,15 @@ impl NotificationsScreen {
 
         let title = text("Notifications").size(18).color(p.text_primary);
 
-        let sync_status: Element<'_, NotificationMessage> = if self.is_loading {
+        let sync_status: Element<'_, NotificationMessage> = if self.is_stale && !self.is_loading {
+            row![
+                icons::icon_wifi_off(11.0, p.accent_warning, icon_theme),
+                Space::new().width(4),
+                text("Offline").size(11).color(p.accent_warning),
+            ]
+            .align_y(Alignment::Center)
+            .into()
+        } else if self.is_loading {
             row![
                 icons::icon_refresh(11.0, p.text_muted, icon_theme),
                 Space::new().width(4),
@@ -86,6 +94,34 @@ impl NotificationsScreen {
             has_unread.then_some(NotificationMessage::Thread(ThreadMessage::MarkAllAsRead)),
         );
 
+        // Rule set switcher, only worth showing with more than one set.
+        let rule_set_picker: Element<'_, NotificationMessage> = if self.rule_set_names.len() > 1 {
+            row![
+                icons::icon_filter(12.0, p.text_muted, icon_theme),
+                Space::new().width(6),
+                pick_list(
+                    self.rule_set_names.clone(),
+                    Some(self.active_rule_set().to_string()),
+                    |name| NotificationMessage::Filter(FilterMessage::SelectRuleSet(name)),
+                )
+                .text_size(12)
+                .padding([4, 8])
+                .style(theme::pick_list_style),
+                Space::new().width(12),
+            ]
+            .align_y(Alignment::Center)
+            .into()
+        } else {
+            Space::new().width(0).into()
+        };
+
+        let grouping_picker = pick_list(GroupingMode::ALL, Some(self.filters.grouping), |mode| {
+            NotificationMessage::Filter(FilterMessage::SelectGrouping(mode))
+        })
+        .text_size(12)
+        .padding([4, 8])
+        .style(theme::pick_list_style);
+
         let refresh_btn = button(icons::icon_refresh(14.0, p.text_secondary, icon_theme))
             .style(theme::ghost_button)
             .padding(8)
@@ -96,6 +132,9 @@ impl NotificationsScreen {
             Space::new().width(12),
             sync_status,
             Space::new().width(Fill),
+            rule_set_picker,
+            grouping_picker,
+            Space::new().width(12),
             filter_segment,
             Space::new().width(12),
             mark_all_btn,
@@ -6,8 +6,9 @@ use iced::{Alignment, Element, Fill, Length, Padding};
 use crate::github::{SubjectType, UserInfo};
 use crate::settings::IconTheme;
 use crate::ui::screens::notifications::messages::{
-    FilterMessage, NavigationMessage, NotificationMessage,
+    ALL_ACCOUNTS, FilterMessage, NavigationMessage, NotificationMessage,
 };
+use crate::ui::screens::settings::rule_engine::rules::RepoVisibility;
 use crate::ui::{icons, theme};
 
 use super::sidebar_state::SidebarState;
@@ -35,6 +36,12 @@ fn view_standard_sidebar<'a>(state: SidebarState<'a>) -> Element<'a, Notificatio
             state.total_repo_count,
             state.icon_theme,
         ))
+        .push(view_visibility_section(
+            state.private_count,
+            state.total_count,
+            state.selected_visibility,
+            state.icon_theme,
+        ))
         .spacing(0)
         .padding([16, 12]);
 
@@ -45,7 +52,10 @@ fn view_standard_sidebar<'a>(state: SidebarState<'a>) -> Element<'a, Notificatio
                 .style(theme::scrollbar),
             container(view_user_section(
                 state.user,
+                state.account,
                 &state.accounts,
+                state.unified,
+                &state.attention,
                 state.icon_theme,
             ))
             .padding(Padding {
@@ -79,6 +89,12 @@ fn view_power_sidebar<'a>(state: SidebarState<'a>) -> Element<'a, NotificationMe
             state.selected_repo,
             state.total_repo_count,
             state.icon_theme,
+        ),
+        view_visibility_section(
+            state.private_count,
+            state.total_count,
+            state.selected_visibility,
+            state.icon_theme,
         )
     ]
     .spacing(0)
@@ -198,17 +214,84 @@ fn view_repos_section(
     col.into()
 }
 
+/// Private/public filter. Hidden while every repository is public and no filter is set.
+fn view_visibility_section(
+    private_count: usize,
+    total_count: usize,
+    selected: RepoVisibility,
+    icon_theme: IconTheme,
+) -> Element<'static, NotificationMessage> {
+    if private_count == 0 && selected == RepoVisibility::Any {
+        return Space::new().height(0).into();
+    }
+    let p = theme::palette();
+    let icon_color = |visibility: RepoVisibility| {
+        if selected == visibility {
+            p.accent
+        } else {
+            p.text_primary
+        }
+    };
+    let item = |icon, label: &str, count, visibility: RepoVisibility| {
+        sidebar_item(
+            icon,
+            label.to_owned(),
+            count,
+            selected == visibility,
+            NotificationMessage::Filter(FilterMessage::SelectVisibility(visibility)),
+        )
+    };
+
+    column![
+        Space::new().height(16),
+        text("Visibility")
+            .size(theme::sidebar_scaled(11.0))
+            .color(p.text_secondary),
+        Space::new().height(8),
+        item(
+            icons::icon_inbox(14.0, icon_color(RepoVisibility::Any), icon_theme),
+            "All",
+            total_count,
+            RepoVisibility::Any,
+        ),
+        item(
+            icons::icon_lock(14.0, icon_color(RepoVisibility::Private), icon_theme),
+            "Private",
+            private_count,
+            RepoVisibility::Private,
+        ),
+        item(
+            icons::icon_globe(14.0, icon_color(RepoVisibility::Public), icon_theme),
+            "Public",
+            total_count.saturating_sub(private_count),
+            RepoVisibility::Public,
+        ),
+    ]
+    .spacing(2)
+    .into()
+}
+
 fn view_user_section<'a>(
     user: &'a UserInfo,
+    account: &str,
     accounts: &[String],
+    unified: bool,
+    attention: &[(String, String)],
     icon_theme: IconTheme,
 ) -> Element<'a, NotificationMessage> {
     let p = theme::palette();
 
     // Account selector or just label
     let account_control: Element<'_, _, _, iced::Renderer> = if accounts.len() > 1 {
-        iced::widget::pick_list(accounts.to_vec(), Some(user.login.clone()), |s| {
-            NotificationMessage::Navigation(NavigationMessage::SwitchAccount(s))
+        let mut choices = accounts.to_vec();
+        choices.push(ALL_ACCOUNTS.to_string());
+        let selected = if unified {
+            ALL_ACCOUNTS.to_string()
+        } else {
+            account.to_string()
+        };
+        iced::widget::pick_list(choices, Some(selected), |s| {
+            NotificationMessage::Navigation(NavigationMessage::from_account_choice(s))
         })
         .text_size(13)
         .padding([4, 8])
@@ -244,6 +327,27 @@ fn view_user_section<'a>(
         ]
         .align_y(Alignment::Center),
     ]
+    .push(user.token.warning().map(|warning| {
+        row![
+            icons::icon_alert(11.0, p.accent_warning, icon_theme),
+            Space::new().width(6),
+            text(warning).size(11).color(p.accent_warning),
+        ]
+        .align_y(Alignment::Center)
+        .padding([4, 0])
+    }))
+    .extend(attention.iter().map(|(username, summary)| {
+        row![
+            icons::icon_alert(11.0, p.accent_warning, icon_theme),
+            Space::new().width(6),
+            text(format!("@{}: {}", username, summary))
+                .size(11)
+                .color(p.accent_warning),
+        ]
+        .align_y(Alignment::Center)
+        .padding([4, 0])
+        .into()
+    }))
     .into()
 }
 
@@ -2,14 +2,23 @@
 
 use crate::github::{SubjectType, UserInfo};
 use crate::settings::IconTheme;
+use crate::ui::screens::settings::rule_engine::rules::RepoVisibility;
 
 pub struct SidebarState<'a> {
     pub user: &'a UserInfo,
+    /// Key of the account being shown.
+    pub account: &'a str,
     pub accounts: Vec<String>,
+    /// Showing the merged inbox of all accounts.
+    pub unified: bool,
+    /// Accounts needing attention, as `(account key, summary)`.
+    pub attention: Vec<(String, String)>,
     pub type_counts: &'a [(SubjectType, usize)],
     pub repo_counts: &'a [(String, usize)],
     pub selected_type: Option<SubjectType>,
     pub selected_repo: Option<&'a str>,
+    pub private_count: usize,
+    pub selected_visibility: RepoVisibility,
     pub total_count: usize,
     pub total_repo_count: usize,
     pub icon_theme: IconTheme,
@@ -3,6 +3,7 @@
 use iced::widget::{Space, button, column, container, text};
 use iced::{Alignment, Element, Fill};
 
+use crate::github::GitHubError;
 use crate::settings::IconTheme;
 use crate::ui::screens::notifications::messages::NotificationMessage;
 use crate::ui::{icons, theme};
@@ -22,16 +23,45 @@ pub fn view_loading<'a>() -> Element<'a, NotificationMessage> {
     .into()
 }
 
-pub fn view_error<'a>(error: &'a str, icon_theme: IconTheme) -> Element<'a, NotificationMessage> {
+pub fn view_error<'a>(
+    error: &'a GitHubError,
+    icon_theme: IconTheme,
+) -> Element<'a, NotificationMessage> {
     let p = theme::palette();
+    let (title, hint) = match error {
+        GitHubError::RateLimited { .. } => (
+            "Rate limited by GitHub",
+            Some("Auto-refresh is paused until the limit resets."),
+        ),
+        GitHubError::SsoRequired { .. } => (
+            "Single sign-on required",
+            Some("Authorize this token for the organization in your GitHub token settings."),
+        ),
+        GitHubError::InsufficientScope(_) => (
+            "Token lacks permission",
+            Some("Create a token with the notifications (or repo) scope."),
+        ),
+        GitHubError::Unauthorized => ("Token rejected", None),
+        _ => ("Failed to load notifications", None),
+    };
+
+    let hint: Element<'a, NotificationMessage> = match hint {
+        Some(hint) => column![
+            Space::new().height(4),
+            text(hint).size(12).color(p.text_muted)
+        ]
+        .align_x(Alignment::Center)
+        .into(),
+        None => Space::new().into(),
+    };
+
     let content = column![
         icons::icon_alert(32.0, p.accent_warning, icon_theme),
         Space::new().height(16),
-        text("Failed to load notifications")
-            .size(16)
-            .color(p.text_primary),
+        text(title).size(16).color(p.text_primary),
         Space::new().height(8),
-        text(error).size(12).color(p.text_secondary),
+        text(error.to_string()).size(12).color(p.text_secondary),
+        hint,
         Space::new().height(24),
         button(text("Retry").size(14))
             .style(theme::primary_button)
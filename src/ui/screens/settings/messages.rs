@@ -22,6 +22,8 @@ pub enum SettingsMessage {
     TogglePowerMode(bool),
     OpenRuleEngine,
     TokenInputChanged(String),
+    ApiBaseInputChanged(String),
     SubmitToken,
-    TokenValidated(Result<String, String>),
+    /// Username and GHES API base (`None` for github.com) of the added account.
+    TokenValidated(Result<(String, Option<String>), String>),
 }
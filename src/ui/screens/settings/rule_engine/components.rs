@@ -5,10 +5,15 @@ use iced::{Alignment, Element, Fill};
 use iced_aw::ContextMenu;
 
 use crate::settings::IconTheme;
-use crate::ui::screens::settings::rule_engine::rules::{OrgRule, RuleAction, TypeRule};
+use crate::ui::screens::settings::rule_engine::rules::{
+    CompositeRule, OrgRule, RepoRule, RuleAction, TitleRule, TypeRule,
+};
 use crate::ui::{icons, theme};
 
-use super::messages::{InspectorMessage, OrgMessage, RuleEngineMessage, TypeMessage};
+use super::messages::{
+    CompositeMessage, InspectorMessage, OrgMessage, RepoMessage, RuleEngineMessage, TitleMessage,
+    TypeMessage,
+};
 
 // ============================================================================
 // Empty State
@@ -185,6 +190,338 @@ pub fn view_org_rule_card(
     .into()
 }
 
+// ============================================================================
+// Repo Rule Card
+// ============================================================================
+
+pub fn view_repo_rule_card(
+    rule: &RepoRule,
+    icon_theme: IconTheme,
+) -> Element<'static, RuleEngineMessage> {
+    let p = theme::palette();
+    let id = rule.id.clone();
+    let id_toggle = id.clone();
+    let id_dup = id.clone();
+    let id_dup2 = id.clone();
+    let id_delete = id.clone();
+    let id_delete2 = id.clone();
+    let id_select = id;
+    let enabled = rule.enabled;
+
+    let priority = format!("Priority: {}", rule.priority);
+    let action_str = format!("Action: {}", rule.action.display_label());
+
+    let mut info_column = column![
+        text(rule.label()).size(14).color(p.text_primary),
+        text(priority).size(12).color(p.text_secondary),
+        text(action_str).size(11).color(p.text_muted),
+    ]
+    .width(Fill);
+
+    if rule.priority > 100 || rule.priority < -100 {
+        info_column = info_column.push(Space::new().height(4));
+        info_column = info_column.push(view_warning_row("Non-standard priority", icon_theme));
+    }
+    if rule.action == RuleAction::Hide {
+        info_column = info_column.push(Space::new().height(4));
+        info_column = info_column.push(view_warning_row("Hides notifications", icon_theme));
+    }
+
+    // Make info content clickable to open inspector
+    let clickable_info = button(info_column)
+        .style(theme::ghost_button)
+        .padding(0)
+        .on_press(RuleEngineMessage::Inspector(InspectorMessage::Select(
+            id_select,
+        )));
+
+    // Visible action buttons
+    let dup_btn = button(icons::icon_plus(14.0, p.text_muted, icon_theme))
+        .style(theme::ghost_button)
+        .padding(6)
+        .on_press(RuleEngineMessage::Repo(RepoMessage::Duplicate(id_dup)));
+
+    let delete_btn = button(icons::icon_trash(14.0, p.text_muted, icon_theme))
+        .style(theme::ghost_button)
+        .padding(6)
+        .on_press(RuleEngineMessage::Repo(RepoMessage::Delete(id_delete)));
+
+    let action_buttons = row![dup_btn, delete_btn,].spacing(2);
+
+    let card_content = container(
+        row![
+            clickable_info,
+            Space::new().width(Fill),
+            action_buttons,
+            Space::new().width(8),
+            toggler(enabled)
+                .on_toggle(move |e| RuleEngineMessage::Repo(RepoMessage::Toggle(
+                    id_toggle.clone(),
+                    e
+                )))
+                .size(18),
+        ]
+        .align_y(Alignment::Center)
+        .padding(14),
+    )
+    .style(|_| theme::rule_card_container());
+
+    ContextMenu::new(card_content, move || {
+        container(
+            column![
+                view_context_menu_item(
+                    "Duplicate",
+                    RuleEngineMessage::Repo(RepoMessage::Duplicate(id_dup2.clone()))
+                ),
+                view_context_menu_item(
+                    "Delete",
+                    RuleEngineMessage::Repo(RepoMessage::Delete(id_delete2.clone()))
+                ),
+            ]
+            .spacing(2),
+        )
+        .style(|_| theme::context_menu_container())
+        .padding(4)
+        .width(140)
+        .into()
+    })
+    .into()
+}
+
+// ============================================================================
+// Title Rule Card
+// ============================================================================
+
+pub fn view_title_rule_card(
+    rule: &TitleRule,
+    icon_theme: IconTheme,
+) -> Element<'static, RuleEngineMessage> {
+    let p = theme::palette();
+    let id = rule.id.clone();
+    let id_toggle = id.clone();
+    let id_dup = id.clone();
+    let id_dup2 = id.clone();
+    let id_delete = id.clone();
+    let id_delete2 = id.clone();
+    let id_select = id;
+    let enabled = rule.enabled;
+
+    let case = if rule.case_sensitive {
+        "case-sensitive"
+    } else {
+        "ignores case"
+    };
+    let mode = format!(
+        "{} · {} · Priority: {}",
+        rule.match_mode, case, rule.priority
+    );
+    let action_str = format!("Action: {}", rule.action.display_label());
+
+    let mut info_column = column![
+        text(rule.pattern.clone()).size(14).color(p.text_primary),
+        text(mode).size(12).color(p.text_secondary),
+        text(action_str).size(11).color(p.text_muted),
+    ]
+    .width(Fill);
+
+    if rule.priority > 100 || rule.priority < -100 {
+        info_column = info_column.push(Space::new().height(4));
+        info_column = info_column.push(view_warning_row("Non-standard priority", icon_theme));
+    }
+    if rule.validate().is_err() {
+        info_column = info_column.push(Space::new().height(4));
+        info_column =
+            info_column.push(view_warning_row("Invalid regex, never matches", icon_theme));
+    }
+    if rule.action == RuleAction::Hide {
+        info_column = info_column.push(Space::new().height(4));
+        info_column = info_column.push(view_warning_row("Hides notifications", icon_theme));
+    }
+
+    // Make info content clickable to open inspector
+    let clickable_info = button(info_column)
+        .style(theme::ghost_button)
+        .padding(0)
+        .on_press(RuleEngineMessage::Inspector(InspectorMessage::Select(
+            id_select,
+        )));
+
+    // Visible action buttons
+    let dup_btn = button(icons::icon_plus(14.0, p.text_muted, icon_theme))
+        .style(theme::ghost_button)
+        .padding(6)
+        .on_press(RuleEngineMessage::Title(TitleMessage::Duplicate(id_dup)));
+
+    let delete_btn = button(icons::icon_trash(14.0, p.text_muted, icon_theme))
+        .style(theme::ghost_button)
+        .padding(6)
+        .on_press(RuleEngineMessage::Title(TitleMessage::Delete(id_delete)));
+
+    let action_buttons = row![dup_btn, delete_btn,].spacing(2);
+
+    let card_content = container(
+        row![
+            clickable_info,
+            Space::new().width(Fill),
+            action_buttons,
+            Space::new().width(8),
+            toggler(enabled)
+                .on_toggle(move |e| RuleEngineMessage::Title(TitleMessage::Toggle(
+                    id_toggle.clone(),
+                    e
+                )))
+                .size(18),
+        ]
+        .align_y(Alignment::Center)
+        .padding(14),
+    )
+    .style(|_| theme::rule_card_container());
+
+    ContextMenu::new(card_content, move || {
+        container(
+            column![
+                view_context_menu_item(
+                    "Duplicate",
+                    RuleEngineMessage::Title(TitleMessage::Duplicate(id_dup2.clone()))
+                ),
+                view_context_menu_item(
+                    "Delete",
+                    RuleEngineMessage::Title(TitleMessage::Delete(id_delete2.clone()))
+                ),
+            ]
+            .spacing(2),
+        )
+        .style(|_| theme::context_menu_container())
+        .padding(4)
+        .width(140)
+        .into()
+    })
+    .into()
+}
+
+// ============================================================================
+// Composite Rule Card
+// ============================================================================
+
+pub fn view_composite_rule_card(
+    rule: &CompositeRule,
+    icon_theme: IconTheme,
+) -> Element<'static, RuleEngineMessage> {
+    let p = theme::palette();
+    let id = rule.id.clone();
+    let id_toggle = id.clone();
+    let id_dup = id.clone();
+    let id_dup2 = id.clone();
+    let id_delete = id.clone();
+    let id_delete2 = id.clone();
+    let id_edit = id.clone();
+    let id_edit2 = id.clone();
+    let id_select = id;
+    let enabled = rule.enabled;
+
+    let summary = rule.condition.to_string();
+    let priority = format!("Priority: {}", rule.priority);
+    let action_str = format!("Action: {}", rule.action.display_label());
+
+    let mut info_column = column![
+        text(rule.name.clone()).size(14).color(p.text_primary),
+        text(summary).size(12).color(p.text_secondary),
+        text(priority).size(11).color(p.text_muted),
+        text(action_str).size(11).color(p.text_muted),
+    ]
+    .width(Fill);
+
+    if rule.priority > 100 || rule.priority < -100 {
+        info_column = info_column.push(Space::new().height(4));
+        info_column = info_column.push(view_warning_row("Non-standard priority", icon_theme));
+    }
+    if rule.condition.validate().is_err() {
+        info_column = info_column.push(Space::new().height(4));
+        info_column = info_column.push(view_warning_row("Invalid condition", icon_theme));
+    }
+    if rule.action == RuleAction::Hide {
+        info_column = info_column.push(Space::new().height(4));
+        info_column = info_column.push(view_warning_row("Hides notifications", icon_theme));
+    }
+
+    // Make info content clickable to open inspector
+    let clickable_info = button(info_column)
+        .style(theme::ghost_button)
+        .padding(0)
+        .on_press(RuleEngineMessage::Inspector(InspectorMessage::Select(
+            id_select,
+        )));
+
+    // Visible action buttons
+    let edit_btn = button(icons::icon_settings(14.0, p.text_muted, icon_theme))
+        .style(theme::ghost_button)
+        .padding(6)
+        .on_press(RuleEngineMessage::Composite(CompositeMessage::Edit(
+            id_edit,
+        )));
+
+    let dup_btn = button(icons::icon_plus(14.0, p.text_muted, icon_theme))
+        .style(theme::ghost_button)
+        .padding(6)
+        .on_press(RuleEngineMessage::Composite(CompositeMessage::Duplicate(
+            id_dup,
+        )));
+
+    let delete_btn = button(icons::icon_trash(14.0, p.text_muted, icon_theme))
+        .style(theme::ghost_button)
+        .padding(6)
+        .on_press(RuleEngineMessage::Composite(CompositeMessage::Delete(
+            id_delete,
+        )));
+
+    let action_buttons = row![edit_btn, dup_btn, delete_btn,].spacing(2);
+
+    let card_content = container(
+        row![
+            clickable_info,
+            Space::new().width(Fill),
+            action_buttons,
+            Space::new().width(8),
+            toggler(enabled)
+                .on_toggle(
+                    move |e| RuleEngineMessage::Composite(CompositeMessage::Toggle(
+                        id_toggle.clone(),
+                        e
+                    ))
+                )
+                .size(18),
+        ]
+        .align_y(Alignment::Center)
+        .padding(14),
+    )
+    .style(|_| theme::rule_card_container());
+
+    ContextMenu::new(card_content, move || {
+        container(
+            column![
+                view_context_menu_item(
+                    "Edit",
+                    RuleEngineMessage::Composite(CompositeMessage::Edit(id_edit2.clone()))
+                ),
+                view_context_menu_item(
+                    "Duplicate",
+                    RuleEngineMessage::Composite(CompositeMessage::Duplicate(id_dup2.clone()))
+                ),
+                view_context_menu_item(
+                    "Delete",
+                    RuleEngineMessage::Composite(CompositeMessage::Delete(id_delete2.clone()))
+                ),
+            ]
+            .spacing(2),
+        )
+        .style(|_| theme::context_menu_container())
+        .padding(4)
+        .width(140)
+        .into()
+    })
+    .into()
+}
+
 // ============================================================================
 // Type Rule Card
 // ============================================================================
@@ -208,9 +545,7 @@ pub fn view_type_rule_card(
     let action_str = format!("Action: {}", rule.action.display_label());
 
     let mut info_column = column![
-        text(rule.notification_type.clone())
-            .size(14)
-            .color(p.text_primary),
+        text(rule.target_label()).size(14).color(p.text_primary),
         Space::new().height(4),
         row![
             text(account).size(12).color(p.text_secondary),
@@ -0,0 +1,165 @@
+//! Dry Run component - previews what unsaved rule edits would do to the inbox.
+
+use iced::widget::{Space, button, column, container, row, scrollable, text};
+use iced::{Alignment, Element, Fill, Length};
+
+use crate::settings::IconTheme;
+use crate::ui::screens::notifications::DryRunReport;
+use crate::ui::screens::settings::rule_engine::rules::RuleAction;
+use crate::ui::{icons, theme};
+
+use super::messages::{DryRunMessage, RuleEngineMessage};
+
+/// Changed threads listed before the rest are summarized.
+const MAX_LISTED_CHANGES: usize = 50;
+
+/// View the dry run panel. `leave_requested` warns that Back will discard the edits.
+pub fn view_dry_run(
+    report: &DryRunReport,
+    leave_requested: bool,
+    icon_theme: IconTheme,
+) -> Element<'static, RuleEngineMessage> {
+    let p = theme::palette();
+
+    let header = row![
+        icons::icon_zap(14.0, p.accent, icon_theme),
+        Space::new().width(8),
+        text("Dry Run").size(14).color(p.text_primary),
+    ]
+    .align_y(Alignment::Center);
+
+    let intro = text(format!(
+        "Edits are held back and checked against {} loaded notifications. Nothing is saved until you press Save.",
+        report.total
+    ))
+    .size(11)
+    .color(p.text_secondary);
+
+    let count_row = |label: &'static str, before: usize, after: usize| {
+        let delta = after as i64 - before as i64;
+        let delta_color = match delta {
+            0 => p.text_muted,
+            d if d > 0 => p.accent_warning,
+            _ => p.accent_success,
+        };
+        row![
+            text(label).size(12).color(p.text_secondary),
+            Space::new().width(Fill),
+            text(format!("{before} → {after}"))
+                .size(12)
+                .color(p.text_primary),
+            Space::new().width(8),
+            text(format!("{delta:+}")).size(11).color(delta_color),
+        ]
+        .align_y(Alignment::Center)
+    };
+
+    let counts = column![
+        count_row("Hidden", report.before.hidden, report.after.hidden),
+        count_row("Silent", report.before.silent, report.after.silent),
+        count_row("Important", report.before.important, report.after.important),
+    ]
+    .spacing(6);
+
+    let changes_title = text(format!("Changed threads ({})", report.changes.len()))
+        .size(11)
+        .color(p.text_muted);
+
+    let mut changes = column![].spacing(8);
+    if report.changes.is_empty() {
+        changes = changes.push(
+            text("No thread changes with these edits.")
+                .size(12)
+                .color(p.text_muted),
+        );
+    }
+    for change in report.changes.iter().take(MAX_LISTED_CHANGES) {
+        let n = &change.notification;
+        changes = changes.push(
+            column![
+                text(n.title.clone()).size(12).color(p.text_primary),
+                row![
+                    text(n.repo_full_name.clone()).size(10).color(p.text_muted),
+                    Space::new().width(Fill),
+                    text(change.before.display_label())
+                        .size(10)
+                        .color(action_color(change.before)),
+                    text(" → ").size(10).color(p.text_muted),
+                    text(change.after.display_label())
+                        .size(10)
+                        .color(action_color(change.after)),
+                ]
+                .align_y(Alignment::Center),
+            ]
+            .spacing(2),
+        );
+    }
+    if report.changes.len() > MAX_LISTED_CHANGES {
+        changes = changes.push(
+            text(format!(
+                "…and {} more",
+                report.changes.len() - MAX_LISTED_CHANGES
+            ))
+            .size(11)
+            .color(p.text_muted),
+        );
+    }
+
+    let actions = row![
+        button(text("Save").size(12))
+            .style(theme::primary_button)
+            .padding([6, 14])
+            .on_press(RuleEngineMessage::DryRun(DryRunMessage::Save)),
+        Space::new().width(8),
+        button(text("Discard").size(12).color(p.text_secondary))
+            .style(theme::ghost_button)
+            .padding([6, 14])
+            .on_press(RuleEngineMessage::DryRun(DryRunMessage::Discard)),
+    ]
+    .align_y(Alignment::Center);
+
+    let leave_warning = leave_requested.then(|| {
+        text("Press Back again to discard these edits, or Save to keep them.")
+            .size(11)
+            .color(p.accent_warning)
+    });
+
+    let content = column![
+        header,
+        Space::new().height(8),
+        intro,
+        Space::new().height(16),
+        counts,
+        Space::new().height(16),
+        actions,
+        leave_warning,
+        Space::new().height(16),
+        changes_title,
+        Space::new().height(8),
+        scrollable(changes).height(Fill),
+    ]
+    .padding(16);
+
+    container(content)
+        .width(Length::Fixed(300.0))
+        .height(Fill)
+        .style(move |_| container::Style {
+            background: Some(iced::Background::Color(p.bg_card)),
+            border: iced::Border {
+                color: p.border_subtle,
+                width: 1.0,
+                radius: 0.0.into(),
+            },
+            ..Default::default()
+        })
+        .into()
+}
+
+fn action_color(action: RuleAction) -> iced::Color {
+    let p = theme::palette();
+    match action {
+        RuleAction::Hide => p.accent_warning,
+        RuleAction::Important => p.accent,
+        _ => p.text_primary,
+    }
+}
@@ -8,7 +8,9 @@ use iced::{Alignment, Element, Fill};
 
 use crate::settings::IconTheme;
 use crate::ui::icons;
-use crate::ui::screens::settings::rule_engine::rules::{NotificationRuleSet, RuleAction};
+use crate::ui::screens::settings::rule_engine::rules::{
+    NotificationRuleSet, RuleAction, RuleContext,
+};
 use crate::ui::theme;
 
 use super::messages::RuleEngineMessage;
@@ -20,8 +22,7 @@ use chrono::Local;
 /// View the explanation panel.
 pub fn view_explain_panel(
     rules: &NotificationRuleSet,
-    test_type: &str,
-    test_account: Option<&str>,
+    context: &RuleContext<'_>,
     icon_theme: IconTheme,
 ) -> Element<'static, RuleEngineMessage> {
     let p = theme::palette();
@@ -38,7 +39,7 @@ pub fn view_explain_panel(
         .color(p.text_secondary);
 
     // Simulate matching using the actual engine logic
-    let matches = rules.trace(test_type, None, test_account, &Local::now(), true);
+    let matches = rules.trace(context, &Local::now(), true);
 
     let result_content = if matches.is_empty() {
         column![
@@ -152,7 +153,14 @@ pub fn view_explain_panel(
         col
     };
 
-    let test_type_owned = test_type.to_string();
+    let reason_label = context.reason.map_or("any reason", |reason| reason.label());
+    let mut testing_label = format!("Testing: {}", reason_label);
+    if let Some(repo) = context.repo_full_name {
+        testing_label.push_str(&format!(" in {}", repo));
+    }
+    if let Some(title) = context.title {
+        testing_label.push_str(&format!(" titled \"{}\"", title));
+    }
 
     container(
         column![
@@ -160,9 +168,7 @@ pub fn view_explain_panel(
             Space::new().height(4),
             description,
             Space::new().height(12),
-            text(format!("Testing: {}", test_type_owned))
-                .size(12)
-                .color(p.text_secondary),
+            text(testing_label).size(12).color(p.text_secondary),
             result_content,
         ]
         .padding(16),
@@ -3,10 +3,12 @@
 use iced::widget::{Space, button, column, container, row, text};
 use iced::{Alignment, Element, Fill, Length};
 
+use crate::cache::RuleHitStats;
 use crate::settings::IconTheme;
 use crate::ui::icons;
 use crate::ui::screens::settings::rule_engine::rules::{
-    AccountRule, NotificationRuleSet, OrgRule, OutsideScheduleBehavior, RuleAction, TypeRule,
+    AccountRule, CompositeRule, NotificationRuleSet, OrgRule, OutsideScheduleBehavior, RepoRule,
+    RuleAction, TitleRule, TypeRule,
 };
 use crate::ui::theme;
 use chrono::Local;
@@ -18,6 +20,9 @@ use super::messages::{InspectorMessage, RuleEngineMessage};
 pub enum FoundRule {
     Account(AccountRule),
     Org(OrgRule),
+    Repo(RepoRule),
+    Title(TitleRule),
+    Composite(CompositeRule),
     Type(TypeRule),
 }
 
@@ -27,6 +32,9 @@ impl FoundRule {
         match self {
             FoundRule::Account(r) => r.enabled,
             FoundRule::Org(r) => r.enabled,
+            FoundRule::Repo(r) => r.enabled,
+            FoundRule::Title(r) => r.enabled,
+            FoundRule::Composite(r) => r.enabled,
             FoundRule::Type(r) => r.enabled,
         }
     }
@@ -45,6 +53,9 @@ impl FoundRule {
                 }
             }
             FoundRule::Org(r) => r.action,
+            FoundRule::Repo(r) => r.action,
+            FoundRule::Title(r) => r.action,
+            FoundRule::Composite(r) => r.action,
             FoundRule::Type(r) => r.action,
         }
     }
@@ -54,6 +65,9 @@ impl FoundRule {
         match self {
             FoundRule::Account(_) => "Account Rule",
             FoundRule::Org(_) => "Org Rule",
+            FoundRule::Repo(_) => "Repo Rule",
+            FoundRule::Title(_) => "Title Rule",
+            FoundRule::Composite(_) => "Composite Rule",
             FoundRule::Type(_) => "Type Rule",
         }
     }
@@ -67,6 +81,15 @@ pub fn find_rule_by_id(rules: &NotificationRuleSet, id: &str) -> Option<FoundRul
     if let Some(r) = rules.org_rules.iter().find(|r| r.id == id) {
         return Some(FoundRule::Org(r.clone()));
     }
+    if let Some(r) = rules.repo_rules.iter().find(|r| r.id == id) {
+        return Some(FoundRule::Repo(r.clone()));
+    }
+    if let Some(r) = rules.title_rules.iter().find(|r| r.id == id) {
+        return Some(FoundRule::Title(r.clone()));
+    }
+    if let Some(r) = rules.composite_rules.iter().find(|r| r.id == id) {
+        return Some(FoundRule::Composite(r.clone()));
+    }
     if let Some(r) = rules.type_rules.iter().find(|r| r.id == id) {
         return Some(FoundRule::Type(r.clone()));
     }
@@ -77,6 +100,7 @@ pub fn find_rule_by_id(rules: &NotificationRuleSet, id: &str) -> Option<FoundRul
 pub fn view_inspector(
     rules: &NotificationRuleSet,
     selected_rule_id: &str,
+    hit_stats: Option<&RuleHitStats>,
     icon_theme: IconTheme,
 ) -> Element<'static, RuleEngineMessage> {
     let p = theme::palette();
@@ -204,6 +228,22 @@ pub fn view_inspector(
         None
     };
 
+    // How often the rule has decided a thread
+    let activity_text = match hit_stats {
+        Some(stats) => format!(
+            "Matched {} thread update{}, most recently {}",
+            stats.hits,
+            if stats.hits == 1 { "" } else { "s" },
+            stats.last_hit.with_timezone(&Local).format("%b %d, %H:%M")
+        ),
+        None => "Hasn't matched anything yet".to_string(),
+    };
+    let activity_section = column![
+        text("Activity").size(11).color(p.text_muted),
+        Space::new().height(4),
+        text(activity_text).size(12).color(p.text_primary),
+    ];
+
     // Rule-specific details
     let details_section = match &rule {
         FoundRule::Account(r) => {
@@ -229,13 +269,71 @@ pub fn view_inspector(
                 text(format!("{}", priority)).size(13).color(p.text_primary),
             ]
         }
+        FoundRule::Repo(r) => {
+            let repo_pattern = r.repo.clone();
+            let priority = r.priority;
+            let pattern_hint = if repo_pattern.contains(['*', '?']) {
+                "Pattern (wildcards match several repositories)"
+            } else {
+                "Exact repository"
+            };
+            column![
+                text("Repository").size(11).color(p.text_muted),
+                text(repo_pattern).size(13).color(p.text_primary),
+                text(pattern_hint).size(11).color(p.text_secondary),
+                Space::new().height(8),
+                text("Visibility").size(11).color(p.text_muted),
+                text(r.visibility.to_string())
+                    .size(13)
+                    .color(p.text_primary),
+                Space::new().height(8),
+                text("Priority").size(11).color(p.text_muted),
+                text(format!("{}", priority)).size(13).color(p.text_primary),
+            ]
+        }
+        FoundRule::Title(r) => {
+            let pattern = r.pattern.clone();
+            let match_text = match r.validate() {
+                Ok(()) if r.case_sensitive => format!("{} (case-sensitive)", r.match_mode),
+                Ok(()) => format!("{} (ignores case)", r.match_mode),
+                Err(e) => format!("Invalid regex: {}", e),
+            };
+            let priority = r.priority;
+            column![
+                text("Title Pattern").size(11).color(p.text_muted),
+                text(pattern).size(13).color(p.text_primary),
+                text(match_text).size(11).color(p.text_secondary),
+                Space::new().height(8),
+                text("Priority").size(11).color(p.text_muted),
+                text(format!("{}", priority)).size(13).color(p.text_primary),
+            ]
+        }
+        FoundRule::Composite(r) => {
+            let name = r.name.clone();
+            let condition = r.condition.to_string();
+            let priority = r.priority;
+            column![
+                text("Name").size(11).color(p.text_muted),
+                text(name).size(13).color(p.text_primary),
+                Space::new().height(8),
+                text("Matches when").size(11).color(p.text_muted),
+                text(condition).size(13).color(p.text_primary),
+                Space::new().height(8),
+                text("Priority").size(11).color(p.text_muted),
+                text(format!("{}", priority)).size(13).color(p.text_primary),
+            ]
+        }
         FoundRule::Type(r) => {
-            let notification_type = r.notification_type.clone();
+            let reason = r.reason.map_or("Any", |reason| reason.label());
+            let subject_type = r.subject_type.map_or("Any", |subject| subject.label());
             let account_text = r.account.clone().unwrap_or_else(|| "Global".to_string());
             let priority = r.priority;
             column![
-                text("Notification Type").size(11).color(p.text_muted),
-                text(notification_type).size(13).color(p.text_primary),
+                text("Reason").size(11).color(p.text_muted),
+                text(reason).size(13).color(p.text_primary),
+                Space::new().height(8),
+                text("Subject Type").size(11).color(p.text_muted),
+                text(subject_type).size(13).color(p.text_primary),
                 Space::new().height(8),
                 text("Scope").size(11).color(p.text_muted),
                 text(account_text).size(13).color(p.text_primary),
@@ -256,6 +354,8 @@ pub fn view_inspector(
         Space::new().height(16),
         action_section,
         Space::new().height(16),
+        activity_section,
+        Space::new().height(16),
     ]
     .spacing(0);
 
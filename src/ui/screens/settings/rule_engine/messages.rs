@@ -1,6 +1,9 @@
-use crate::github::types::NotificationReason;
-use crate::ui::screens::settings::rule_engine::rules::{OutsideScheduleBehavior, RuleAction};
+use crate::github::types::{NotificationReason, SubjectType};
+use crate::ui::screens::settings::rule_engine::rules::{
+    OutsideScheduleBehavior, RepoVisibility, RuleAction, TitleMatchMode,
+};
 use chrono::Weekday;
+use iced::widget::text_editor;
 
 #[derive(Debug, Clone)]
 pub enum RuleEngineMessage {
@@ -8,12 +11,35 @@ pub enum RuleEngineMessage {
     SelectTab(RuleTab),
     ToggleEnabled(bool),
     ToggleHandbook,
+    /// Drop every queued deferred notification.
+    ClearDeferred,
+    /// Forget every rule's hit statistics.
+    ClearRuleHits,
+    DismissLoadError,
+    /// Replace the rule sets with the fixed `rule_sets.json.bak`.
+    RestoreBackup,
     NoOp,
     Account(AccountMessage),
     Org(OrgMessage),
+    Repo(RepoMessage),
+    Title(TitleMessage),
+    Composite(CompositeMessage),
     Type(TypeMessage),
     Inspector(InspectorMessage),
     Explain(ExplainMessage),
+    RuleSet(RuleSetMessage),
+    DryRun(DryRunMessage),
+}
+
+/// Previewing edits against the loaded inbox before they're saved.
+#[derive(Debug, Clone)]
+pub enum DryRunMessage {
+    /// Hold back edits from now on and preview them.
+    Start,
+    /// Save the held-back edits.
+    Save,
+    /// Throw the held-back edits away.
+    Discard,
 }
 
 #[derive(Debug, Clone)]
@@ -33,6 +59,49 @@ pub enum OrgMessage {
     Duplicate(String),
 }
 
+/// Repository rule messages.
+#[derive(Debug, Clone)]
+pub enum RepoMessage {
+    Toggle(String, bool),
+    Delete(String),
+    Duplicate(String),
+    FormRepoChanged(String),
+    FormPriorityChanged(i32),
+    FormActionChanged(RuleAction),
+    FormVisibilityChanged(RepoVisibility),
+    Add,
+}
+
+/// Title rule messages.
+#[derive(Debug, Clone)]
+pub enum TitleMessage {
+    Toggle(String, bool),
+    Delete(String),
+    Duplicate(String),
+    FormPatternChanged(String),
+    FormModeChanged(TitleMatchMode),
+    FormCaseSensitiveChanged(bool),
+    FormPriorityChanged(i32),
+    FormActionChanged(RuleAction),
+    Add,
+}
+
+/// Composite (condition tree) rule messages.
+#[derive(Debug, Clone)]
+pub enum CompositeMessage {
+    Toggle(String, bool),
+    Delete(String),
+    Duplicate(String),
+    /// Load an existing rule into the editor.
+    Edit(String),
+    CancelEdit,
+    FormNameChanged(String),
+    FormConditionEdited(text_editor::Action),
+    FormPriorityChanged(i32),
+    FormActionChanged(RuleAction),
+    Save,
+}
+
 /// Type rule messages.
 #[derive(Debug, Clone)]
 pub enum TypeMessage {
@@ -40,13 +109,39 @@ pub enum TypeMessage {
     Delete(String),
     Duplicate(String),
     ToggleGroup(String),
-    FormTypeChanged(NotificationReason),
+    FormTypeChanged(Option<NotificationReason>),
+    FormSubjectTypeChanged(Option<SubjectType>),
     FormAccountChanged(String),
     FormPriorityChanged(i32),
     FormActionChanged(RuleAction),
     Add,
 }
 
+/// Rule set management messages.
+#[derive(Debug, Clone)]
+pub enum RuleSetMessage {
+    /// Edit the named set and make it the manual choice.
+    Select(String),
+    Delete(String),
+    NewNameChanged(String),
+    /// Create an empty set.
+    Create,
+    /// Create a set as a copy of the one being edited.
+    Duplicate,
+    RenameChanged(String),
+    Rename,
+    ToggleSchedule(bool),
+    ToggleDay(Weekday),
+    StartChanged(String),
+    EndChanged(String),
+    FilePathChanged(String),
+    ToggleMerge(bool),
+    /// Write the edited set to the chosen file.
+    Export,
+    /// Read a set from the chosen file.
+    Import,
+}
+
 #[derive(Debug, Clone)]
 pub enum InspectorMessage {
     Select(String),
@@ -54,8 +149,11 @@ pub enum InspectorMessage {
 }
 
 #[derive(Debug, Clone)]
+#[allow(clippy::enum_variant_names)]
 pub enum ExplainMessage {
     SetTestType(String),
+    SetTestRepo(String),
+    SetTestTitle(String),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -64,5 +162,9 @@ pub enum RuleTab {
     Overview,
     AccountRules,
     OrgRules,
+    RepoRules,
+    TitleRules,
+    CompositeRules,
     TypeRules,
+    RuleSets,
 }
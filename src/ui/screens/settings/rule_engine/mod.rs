@@ -1,4 +1,5 @@
 mod components;
+mod dry_run;
 mod explain_decision;
 mod inspector;
 mod messages;
@@ -7,6 +8,6 @@ mod tabs;
 
 pub mod rules;
 
-pub use messages::RuleEngineMessage;
-pub use rules::{NotificationRuleSet, RuleAction, RuleEngine};
+pub use messages::{RuleEngineMessage, RuleSetMessage};
+pub use rules::{NotificationRuleSet, RuleAction, RuleEngine, RuleSetStore};
 pub use screen::RuleEngineScreen;
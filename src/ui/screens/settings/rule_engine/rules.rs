@@ -4,12 +4,17 @@
 //! with priority organization support.
 
 use chrono::{Datelike, Local, NaiveTime, Weekday};
+use regex::{Regex, RegexBuilder};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use thiserror::Error;
 use uuid::Uuid;
 
+use crate::github::types::{NotificationReason, NotificationView, SubjectType};
+
 // ============================================================================
 // RULE ACTIONS
 // ============================================================================
@@ -54,6 +59,70 @@ impl std::fmt::Display for RuleAction {
     }
 }
 
+/// Repository visibility, for repo rules and the inbox filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum RepoVisibility {
+    #[default]
+    Any,
+    Private,
+    Public,
+}
+
+impl RepoVisibility {
+    pub const ALL: &'static [Self] = &[Self::Any, Self::Private, Self::Public];
+
+    /// An unknown visibility only satisfies `Any`.
+    pub fn matches(self, is_private: Option<bool>) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Private => is_private == Some(true),
+            Self::Public => is_private == Some(false),
+        }
+    }
+}
+
+/// One-click rules offered from a notification's context menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuickRule {
+    /// Hide everything from the notification's repository.
+    HideRepo,
+    /// Silence the notification's reason on its account.
+    SilenceReason,
+    /// Mark everything from the repository owner as Important.
+    ImportantOrg,
+}
+
+impl QuickRule {
+    pub const ALL: &'static [Self] = &[Self::HideRepo, Self::SilenceReason, Self::ImportantOrg];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::HideRepo => "Hide all from this repo",
+            Self::SilenceReason => "Silence this reason for this account",
+            Self::ImportantOrg => "Always important from this org",
+        }
+    }
+}
+
+/// A quick rule that would have changed what an existing rule does.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{target} already has a rule set to {}; change it in the Rule Engine", .existing.display_label())]
+pub struct QuickRuleConflict {
+    /// The repository, reason or org the rules target.
+    pub target: String,
+    pub existing: RuleAction,
+}
+
+impl std::fmt::Display for RepoVisibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Any => write!(f, "Any visibility"),
+            Self::Private => write!(f, "Private"),
+            Self::Public => write!(f, "Public"),
+        }
+    }
+}
+
 // ============================================================================
 // PRIORITY LEVELS
 // ============================================================================
@@ -84,6 +153,13 @@ pub struct HighImpactRule {
     pub action: RuleAction,
 }
 
+/// A rule's id and display name, for listings such as hit statistics.
+#[derive(Debug, Clone)]
+pub struct RuleSummary {
+    pub id: String,
+    pub name: String,
+}
+
 // ============================================================================
 // RULE TYPES
 // ============================================================================
@@ -93,8 +169,8 @@ pub struct HighImpactRule {
 pub enum OutsideScheduleBehavior {
     /// **Suppress**: Hide notifications completely (Action::Hide).
     Suppress,
-    /// NOTE: "Defer" currently means "Silent outside schedule".
-    /// No queuing or delayed delivery is performed.
+    /// **Defer**: Keep notifications in the list without a desktop popup (Action::Silent),
+    /// queue them, and deliver one digest when the next active window opens.
     #[default]
     Defer,
 }
@@ -168,26 +244,25 @@ impl AccountRule {
 
         // Check time range if specified
         if let (Some(start), Some(end)) = (self.start_time, self.end_time) {
-            let current_time = now.time();
-
-            if start <= end {
-                // Standard range (e.g. 09:00 to 17:00)
-                if current_time < start || current_time >= end {
-                    return false;
-                }
-            } else {
-                // Crossing midnight (e.g. 22:00 to 07:00)
-                // Active if time >= 22:00 OR time < 07:00
-                if current_time < start && current_time >= end {
-                    return false;
-                }
-            }
+            return time_in_window(now.time(), start, end);
         }
 
         true
     }
 }
 
+/// Whether `time` falls in `[start, end)`, where a window ending before it
+/// starts crosses midnight (e.g. 22:00 to 07:00).
+fn time_in_window(time: NaiveTime, start: NaiveTime, end: NaiveTime) -> bool {
+    if start <= end {
+        // Standard range (e.g. 09:00 to 17:00)
+        time >= start && time < end
+    } else {
+        // Crossing midnight: active if time >= 22:00 OR time < 07:00
+        time >= start || time < end
+    }
+}
+
 /// Organization-level priority and filtering.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrgRule {
@@ -201,7 +276,6 @@ pub struct OrgRule {
 }
 
 impl OrgRule {
-    #[allow(dead_code)]
     pub fn new(org: impl Into<String>, priority: i32) -> Self {
         Self {
             id: Uuid::new_v4().to_string(),
@@ -213,13 +287,402 @@ impl OrgRule {
     }
 }
 
-/// Notification type suppression rule.
+/// Repository-level priority and filtering.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoRule {
+    pub id: String,
+    pub enabled: bool,
+    /// Repository full name (`owner/repo`), may contain `*` and `?` wildcards.
+    pub repo: String,
+    /// Only match private or public repositories.
+    #[serde(default)]
+    pub visibility: RepoVisibility,
+    /// Priority level (higher = more important).
+    pub priority: i32,
+    pub action: RuleAction,
+}
+
+impl RepoRule {
+    pub fn new(repo: impl Into<String>, priority: i32) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            enabled: true,
+            repo: repo.into(),
+            visibility: RepoVisibility::Any,
+            priority,
+            action: RuleAction::Show,
+        }
+    }
+
+    /// The pattern, with the visibility when restricted, e.g. "my-org/* (private)".
+    pub fn label(&self) -> String {
+        match self.visibility {
+            RepoVisibility::Any => self.repo.clone(),
+            RepoVisibility::Private => format!("{} (private)", self.repo),
+            RepoVisibility::Public => format!("{} (public)", self.repo),
+        }
+    }
+
+    /// Check the pattern against a repository full name (case-insensitive).
+    pub fn matches(&self, repo_full_name: &str) -> bool {
+        glob_matches(&self.repo, repo_full_name)
+    }
+}
+
+/// Case-insensitive wildcard match: `*` matches any run of characters, `?` exactly one.
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` and the text index it is currently absorbing up to.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star, absorbed)) = backtrack {
+            p = star + 1;
+            t = absorbed + 1;
+            backtrack = Some((star, t));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// How a title rule's pattern is compared against the notification title.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum TitleMatchMode {
+    /// The title contains the pattern anywhere.
+    #[default]
+    Contains,
+    /// The title starts with the pattern.
+    StartsWith,
+    /// The pattern is a regular expression.
+    Regex,
+}
+
+impl TitleMatchMode {
+    pub const ALL: &'static [Self] = &[Self::Contains, Self::StartsWith, Self::Regex];
+}
+
+impl std::fmt::Display for TitleMatchMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Contains => write!(f, "Contains"),
+            Self::StartsWith => write!(f, "Starts with"),
+            Self::Regex => write!(f, "Regex"),
+        }
+    }
+}
+
+/// Notification title (keyword) rule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TitleRule {
+    pub id: String,
+    pub enabled: bool,
+    /// Text or regular expression matched against the title.
+    pub pattern: String,
+    #[serde(default)]
+    pub match_mode: TitleMatchMode,
+    /// Matching ignores case unless this is set.
+    #[serde(default)]
+    pub case_sensitive: bool,
+    /// Priority level (higher = more important).
+    pub priority: i32,
+    pub action: RuleAction,
+    /// Compiled regex, built on first use after the rule is created or loaded,
+    /// so the pattern fields must be settled before the rule is matched.
+    #[serde(skip)]
+    regex: OnceLock<Result<Regex, regex::Error>>,
+}
+
+impl TitleRule {
+    pub fn new(pattern: impl Into<String>, match_mode: TitleMatchMode, priority: i32) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            enabled: true,
+            pattern: pattern.into(),
+            match_mode,
+            case_sensitive: false,
+            priority,
+            action: RuleAction::Show,
+            regex: OnceLock::new(),
+        }
+    }
+
+    fn regex(&self) -> &Result<Regex, regex::Error> {
+        self.regex
+            .get_or_init(|| compile_title_regex(&self.pattern, !self.case_sensitive))
+    }
+
+    /// Check the pattern against a title. An invalid regex never matches.
+    pub fn matches(&self, title: &str) -> bool {
+        match self.match_mode {
+            TitleMatchMode::Regex => self
+                .regex()
+                .as_ref()
+                .is_ok_and(|regex| regex.is_match(title)),
+            TitleMatchMode::Contains if self.case_sensitive => title.contains(&self.pattern),
+            TitleMatchMode::StartsWith if self.case_sensitive => title.starts_with(&self.pattern),
+            TitleMatchMode::Contains => title.to_lowercase().contains(&self.pattern.to_lowercase()),
+            TitleMatchMode::StartsWith => title
+                .to_lowercase()
+                .starts_with(&self.pattern.to_lowercase()),
+        }
+    }
+
+    /// Report why a regex pattern can't be used.
+    pub fn validate(&self) -> Result<(), regex::Error> {
+        match self.match_mode {
+            TitleMatchMode::Regex => self.regex().as_ref().map(|_| ()).map_err(Clone::clone),
+            TitleMatchMode::Contains | TitleMatchMode::StartsWith => Ok(()),
+        }
+    }
+}
+
+/// Check a title pattern before it is saved. Only regex patterns can be invalid.
+pub fn validate_title_pattern(
+    pattern: &str,
+    match_mode: TitleMatchMode,
+) -> Result<(), regex::Error> {
+    match match_mode {
+        TitleMatchMode::Regex => compile_title_regex(pattern, true).map(|_| ()),
+        TitleMatchMode::Contains | TitleMatchMode::StartsWith => Ok(()),
+    }
+}
+
+fn compile_title_regex(pattern: &str, case_insensitive: bool) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(case_insensitive)
+        .build()
+}
+
+/// Case-insensitive title regex used by composite conditions.
+///
+/// Serialized as the bare pattern string; the compiled form is cached on first use.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TitleRegex {
+    pattern: String,
+    #[serde(skip)]
+    regex: OnceLock<Result<Regex, regex::Error>>,
+}
+
+impl TitleRegex {
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    fn regex(&self) -> &Result<Regex, regex::Error> {
+        self.regex
+            .get_or_init(|| compile_title_regex(&self.pattern, true))
+    }
+
+    pub fn is_match(&self, title: &str) -> bool {
+        self.regex()
+            .as_ref()
+            .is_ok_and(|regex| regex.is_match(title))
+    }
+
+    pub fn validate(&self) -> Result<(), regex::Error> {
+        self.regex().as_ref().map(|_| ()).map_err(Clone::clone)
+    }
+}
+
+impl PartialEq for TitleRegex {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+impl Eq for TitleRegex {}
+
+/// A node in a composite rule's condition tree.
+///
+/// Serialized as externally tagged JSON so rules can be written by hand:
+/// `{"all": [{"reason": "review_requested"}, {"org": "acme"}]}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Condition {
+    /// Every child matches (an empty list always matches).
+    All(Vec<Condition>),
+    /// At least one child matches.
+    Any(Vec<Condition>),
+    Not(Box<Condition>),
+    Reason(NotificationReason),
+    SubjectType(SubjectType),
+    /// Repository owner, compared case-insensitively.
+    Org(String),
+    /// Repository full name, may contain `*` and `?` wildcards.
+    Repo(String),
+    Account(String),
+    /// Case-insensitive substring of the title.
+    Title(String),
+    /// Regular expression on the title (case-insensitive).
+    TitleRegex(TitleRegex),
+    Private(bool),
+    /// Local time window; an empty `days` list means every day.
+    TimeWindow {
+        start: NaiveTime,
+        end: NaiveTime,
+        #[serde(default)]
+        days: Vec<Weekday>,
+    },
+}
+
+impl Condition {
+    /// Evaluate against a context. Attributes the context doesn't know never match.
+    pub fn matches(&self, context: &RuleContext<'_>, now: &chrono::DateTime<Local>) -> bool {
+        match self {
+            Self::All(children) => children.iter().all(|c| c.matches(context, now)),
+            Self::Any(children) => children.iter().any(|c| c.matches(context, now)),
+            Self::Not(child) => !child.matches(context, now),
+            Self::Reason(reason) => context.reason == Some(*reason),
+            Self::SubjectType(subject_type) => context.subject_type == Some(*subject_type),
+            Self::Org(org) => context
+                .repo_owner
+                .is_some_and(|owner| owner.eq_ignore_ascii_case(org)),
+            Self::Repo(pattern) => context
+                .repo_full_name
+                .is_some_and(|repo| glob_matches(pattern, repo)),
+            Self::Account(account) => context
+                .account
+                .is_some_and(|acc| acc.eq_ignore_ascii_case(account)),
+            Self::Title(needle) => context
+                .title
+                .is_some_and(|title| title.to_lowercase().contains(&needle.to_lowercase())),
+            Self::TitleRegex(pattern) => context.title.is_some_and(|title| pattern.is_match(title)),
+            Self::Private(private) => context.is_private == Some(*private),
+            Self::TimeWindow { start, end, days } => {
+                (days.is_empty() || days.contains(&now.weekday()))
+                    && time_in_window(now.time(), *start, *end)
+            }
+        }
+    }
+
+    /// Reject trees that parse but can never mean what was intended.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::All(children) | Self::Any(children) => {
+                children.iter().try_for_each(Condition::validate)
+            }
+            Self::Not(child) => child.validate(),
+            Self::Reason(NotificationReason::Unknown) => Err("unknown reason".to_string()),
+            Self::SubjectType(SubjectType::Unknown) => Err("unknown subject type".to_string()),
+            Self::TitleRegex(pattern) => pattern
+                .validate()
+                .map_err(|e| format!("title_regex: {}", e)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl std::fmt::Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn join(
+            f: &mut std::fmt::Formatter<'_>,
+            children: &[Condition],
+            separator: &str,
+        ) -> std::fmt::Result {
+            for (i, child) in children.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " {} ", separator)?;
+                }
+                match child {
+                    Condition::All(c) | Condition::Any(c) if c.len() > 1 => {
+                        write!(f, "({})", child)?
+                    }
+                    _ => write!(f, "{}", child)?,
+                }
+            }
+            Ok(())
+        }
+
+        match self {
+            Self::All(children) if children.is_empty() => write!(f, "always"),
+            Self::Any(children) if children.is_empty() => write!(f, "never"),
+            Self::All(children) => join(f, children, "AND"),
+            Self::Any(children) => join(f, children, "OR"),
+            Self::Not(child) => match child.as_ref() {
+                Self::All(c) | Self::Any(c) if c.len() > 1 => write!(f, "NOT ({})", child),
+                _ => write!(f, "NOT {}", child),
+            },
+            Self::Reason(reason) => write!(f, "reason is {}", reason),
+            Self::SubjectType(subject_type) => write!(f, "type is {}", subject_type),
+            Self::Org(org) => write!(f, "org is {}", org),
+            Self::Repo(repo) => write!(f, "repo is {}", repo),
+            Self::Account(account) => write!(f, "account is {}", account),
+            Self::Title(needle) => write!(f, "title contains \"{}\"", needle),
+            Self::TitleRegex(pattern) => write!(f, "title matches /{}/", pattern.as_str()),
+            Self::Private(true) => write!(f, "repo is private"),
+            Self::Private(false) => write!(f, "repo is public"),
+            Self::TimeWindow { start, end, days } => {
+                write!(
+                    f,
+                    "between {} and {}",
+                    start.format("%H:%M"),
+                    end.format("%H:%M")
+                )?;
+                if !days.is_empty() {
+                    let days: Vec<String> = days.iter().map(|d| d.to_string()).collect();
+                    write!(f, " on {}", days.join(", "))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A rule that matches on a tree of conditions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompositeRule {
+    pub id: String,
+    pub enabled: bool,
+    /// User-facing label, e.g. "Acme PR reviews".
+    pub name: String,
+    pub condition: Condition,
+    /// Priority level (higher = more important).
+    pub priority: i32,
+    pub action: RuleAction,
+}
+
+impl CompositeRule {
+    pub fn new(name: impl Into<String>, condition: Condition, priority: i32) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            enabled: true,
+            name: name.into(),
+            condition,
+            priority,
+            action: RuleAction::Show,
+        }
+    }
+}
+
+/// Notification reason and subject type rule.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TypeRule {
     pub id: String,
     pub enabled: bool,
-    /// Notification reason type (mention, review_requested, etc.).
-    pub notification_type: String,
+    /// Notification reason, stored by its API value (`mention`, `review_requested`).
+    /// `None` matches any reason.
+    #[serde(
+        default,
+        alias = "notification_type",
+        deserialize_with = "deserialize_reason"
+    )]
+    pub reason: Option<NotificationReason>,
+    /// Subject type (`PullRequest`, `CheckSuite`, ...). `None` matches any type.
+    #[serde(default)]
+    pub subject_type: Option<SubjectType>,
     /// Optional GitHub username to scope this rule to.
     #[serde(default)]
     pub account: Option<String>,
@@ -231,25 +694,121 @@ pub struct TypeRule {
 
 impl TypeRule {
     pub fn new(
-        notification_type: impl Into<String>,
+        reason: Option<NotificationReason>,
+        subject_type: Option<SubjectType>,
         account: Option<String>,
         priority: i32,
     ) -> Self {
         Self {
             id: Uuid::new_v4().to_string(),
             enabled: true,
-            notification_type: notification_type.into(),
+            reason,
+            subject_type,
             account,
             priority,
             action: RuleAction::Show,
         }
     }
+
+    /// What the rule matches, e.g. "Mentioned on Pull Request".
+    pub fn target_label(&self) -> String {
+        match (self.reason, self.subject_type) {
+            (Some(reason), Some(subject_type)) => {
+                format!("{} on {}", reason.label(), subject_type.label())
+            }
+            (Some(reason), None) => reason.label().to_string(),
+            (None, Some(subject_type)) => format!("{} notifications", subject_type.label()),
+            (None, None) => "All notifications".to_string(),
+        }
+    }
+
+    /// The target with its account scope, e.g. "Watching (Global)".
+    pub fn scoped_label(&self) -> String {
+        format!(
+            "{} ({})",
+            self.target_label(),
+            self.account.as_deref().unwrap_or("Global")
+        )
+    }
+
+    fn matches(&self, context: &RuleContext<'_>) -> bool {
+        self.reason
+            .is_none_or(|reason| context.reason == Some(reason))
+            && self
+                .subject_type
+                .is_none_or(|subject_type| context.subject_type == Some(subject_type))
+    }
+}
+
+/// Read a reason stored either by API value or, in older rule files, by display label.
+///
+/// Both are compared ignoring case, with exact matches first since the label
+/// "Subscribed" and the API value "subscribed" name different reasons.
+/// Unrecognised values are an error rather than `Unknown`.
+fn deserialize_reason<'de, D>(deserializer: D) -> Result<Option<NotificationReason>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let Some(value) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+
+    let names = |reason: &NotificationReason| {
+        let api_value = serde_json::to_value(reason)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default();
+        [reason.label().to_string(), api_value]
+    };
+    let find = |matches: &dyn Fn(&str) -> bool| {
+        NotificationReason::ALL
+            .iter()
+            .find(|reason| names(reason).iter().any(|name| matches(name)))
+            .copied()
+    };
+
+    find(&|name| name == value)
+        .or_else(|| find(&|name| name.eq_ignore_ascii_case(&value)))
+        .map(Some)
+        .ok_or_else(|| {
+            serde::de::Error::custom(format!("unknown notification reason \"{}\"", value))
+        })
 }
 
 // ============================================================================
 // RULE SET (ROOT CONTAINER)
 // ============================================================================
 
+/// Weekly window during which a rule set activates itself (e.g. an on-call rotation).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleSetSchedule {
+    pub enabled: bool,
+    #[serde(default = "default_active_days")]
+    pub active_days: HashSet<Weekday>,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+}
+
+impl Default for RuleSetSchedule {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            active_days: default_active_days(),
+            start_time: NaiveTime::from_hms_opt(9, 0, 0).unwrap_or_default(),
+            end_time: NaiveTime::from_hms_opt(17, 0, 0).unwrap_or_default(),
+        }
+    }
+}
+
+impl RuleSetSchedule {
+    /// Check if `now` falls inside the schedule.
+    pub fn is_active(&self, now: &chrono::DateTime<Local>) -> bool {
+        self.enabled
+            && self.active_days.contains(&now.weekday())
+            && time_in_window(now.time(), self.start_time, self.end_time)
+    }
+}
+
 /// Complete notification rule configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct NotificationRuleSet {
@@ -262,8 +821,20 @@ pub struct NotificationRuleSet {
     pub account_rules: Vec<AccountRule>,
     /// Organization priority rules.
     pub org_rules: Vec<OrgRule>,
+    /// Repository priority rules.
+    #[serde(default)]
+    pub repo_rules: Vec<RepoRule>,
+    /// Title keyword rules.
+    #[serde(default)]
+    pub title_rules: Vec<TitleRule>,
+    /// Condition-tree rules.
+    #[serde(default)]
+    pub composite_rules: Vec<CompositeRule>,
     /// Notification type filtering.
     pub type_rules: Vec<TypeRule>,
+    /// When set, this rule set takes over automatically inside the window.
+    #[serde(default)]
+    pub schedule: Option<RuleSetSchedule>,
 }
 
 fn default_rule_set_name() -> String {
@@ -271,31 +842,148 @@ fn default_rule_set_name() -> String {
 }
 
 impl NotificationRuleSet {
-    /// Get the rules file path.
-    fn rules_path() -> Option<PathBuf> {
-        dirs::config_dir().map(|p| p.join("GitTop").join("rules.json"))
+    /// Create an empty, enabled rule set.
+    pub fn named(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            enabled: true,
+            ..Default::default()
+        }
     }
 
-    /// Load rules from disk, or return defaults.
+    /// Load the active rule set from disk, or return defaults.
     pub fn load() -> Self {
-        Self::rules_path()
-            .and_then(|path| fs::read_to_string(path).ok())
-            .and_then(|content| serde_json::from_str(&content).ok())
-            .unwrap_or_default()
+        RuleSetStore::load().active_set(&Local::now()).clone()
     }
 
-    /// Save rules to disk.
+    /// Save this rule set into the store, replacing the set with the same name.
     pub fn save(&self) -> Result<(), std::io::Error> {
-        if let Some(path) = Self::rules_path() {
-            if let Some(parent) = path.parent() {
-                fs::create_dir_all(parent)?;
+        let mut store = RuleSetStore::load();
+        store.upsert(self.clone());
+        store.save()
+    }
+
+    /// Write this set to a shareable, versioned file.
+    pub fn export_to(&self, path: &Path) -> Result<(), RuleFileError> {
+        let file = RuleSetFile {
+            version: RULES_FORMAT_VERSION,
+            rule_set: self.clone(),
+        };
+        let content = serde_json::to_string_pretty(&file).map_err(std::io::Error::other);
+        content
+            .and_then(|content| fs::write(path, content))
+            .map_err(|source| RuleFileError::Io {
+                path: path.to_path_buf(),
+                source,
+            })
+    }
+
+    /// Read a set from an exported file, upgrading older formats.
+    ///
+    /// Version 1 files are a bare rule set, as in the legacy `rules.json`.
+    pub fn import_from(path: &Path) -> Result<Self, RuleFileError> {
+        let value = read_versioned(path)?;
+        if value.get("rule_set").is_none() {
+            return serde_json::from_value(value).map_err(|e| parse_error(path, e));
+        }
+        serde_json::from_value::<RuleSetFile>(value)
+            .map(|file| file.rule_set)
+            .map_err(|e| parse_error(path, e))
+    }
+
+    /// Merge another set's rules into this one.
+    /// Rules with a matching ID are replaced and the rest are added;
+    /// account rules match by account, since there is one per account.
+    pub fn merge(&mut self, other: NotificationRuleSet) {
+        merge_rules(&mut self.account_rules, other.account_rules, |a, b| {
+            a.account.eq_ignore_ascii_case(&b.account)
+        });
+        merge_rules(&mut self.org_rules, other.org_rules, |a, b| a.id == b.id);
+        merge_rules(&mut self.repo_rules, other.repo_rules, |a, b| a.id == b.id);
+        merge_rules(&mut self.title_rules, other.title_rules, |a, b| {
+            a.id == b.id
+        });
+        merge_rules(&mut self.composite_rules, other.composite_rules, |a, b| {
+            a.id == b.id
+        });
+        merge_rules(&mut self.type_rules, other.type_rules, |a, b| a.id == b.id);
+    }
+
+    /// Add the rule `quick` describes for `notification`, or re-enable the
+    /// equivalent rule if one exists. A rule for the same target with another
+    /// action is left alone and reported. The set's own switch isn't touched.
+    pub fn add_quick_rule(
+        &mut self,
+        quick: QuickRule,
+        notification: &NotificationView,
+    ) -> Result<(), QuickRuleConflict> {
+        let conflict = |target: &str, existing: RuleAction| QuickRuleConflict {
+            target: target.to_string(),
+            existing,
+        };
+        match quick {
+            QuickRule::HideRepo => {
+                let repo = &notification.repo_full_name;
+                let existing = self.repo_rules.iter_mut().find(|r| {
+                    r.repo.eq_ignore_ascii_case(repo) && r.visibility == RepoVisibility::Any
+                });
+                match existing {
+                    Some(rule) if rule.action != RuleAction::Hide => {
+                        return Err(conflict(repo, rule.action));
+                    }
+                    Some(rule) => rule.enabled = true,
+                    None => {
+                        let mut rule = RepoRule::new(repo.clone(), 0);
+                        rule.action = RuleAction::Hide;
+                        self.repo_rules.push(rule);
+                    }
+                }
+            }
+            QuickRule::SilenceReason => {
+                let reason = Some(notification.reason);
+                let account = Some(notification.account.clone());
+                let existing = self.type_rules.iter_mut().find(|r| {
+                    r.reason == reason && r.subject_type.is_none() && r.account == account
+                });
+                match existing {
+                    Some(rule) if rule.action != RuleAction::Silent => {
+                        return Err(conflict(notification.reason.label(), rule.action));
+                    }
+                    Some(rule) => rule.enabled = true,
+                    None => {
+                        let mut rule = TypeRule::new(reason, None, account, 0);
+                        rule.action = RuleAction::Silent;
+                        self.type_rules.push(rule);
+                    }
+                }
+            }
+            QuickRule::ImportantOrg => {
+                let org = notification.repo_owner();
+                let existing = self
+                    .org_rules
+                    .iter_mut()
+                    .find(|r| r.org.eq_ignore_ascii_case(org));
+                match existing {
+                    Some(rule) if rule.action != RuleAction::Important => {
+                        return Err(conflict(org, rule.action));
+                    }
+                    Some(rule) => rule.enabled = true,
+                    None => {
+                        let mut rule = OrgRule::new(org, 0);
+                        rule.action = RuleAction::Important;
+                        self.org_rules.push(rule);
+                    }
+                }
             }
-            let content = serde_json::to_string_pretty(self)?;
-            fs::write(path, content)?;
         }
         Ok(())
     }
 
+    /// Whether this set's schedule currently claims it as the active set.
+    pub fn is_scheduled_now(&self, now: &chrono::DateTime<Local>) -> bool {
+        self.schedule.as_ref().is_some_and(|s| s.is_active(now))
+    }
+
     /// Count total active rules.
     pub fn active_rule_count(&self) -> usize {
         if !self.enabled {
@@ -303,6 +991,9 @@ impl NotificationRuleSet {
         }
         self.account_rules.iter().filter(|r| r.enabled).count()
             + self.org_rules.iter().filter(|r| r.enabled).count()
+            + self.repo_rules.iter().filter(|r| r.enabled).count()
+            + self.title_rules.iter().filter(|r| r.enabled).count()
+            + self.composite_rules.iter().filter(|r| r.enabled).count()
             + self.type_rules.iter().filter(|r| r.enabled).count()
     }
 
@@ -331,6 +1022,21 @@ impl NotificationRuleSet {
             .iter()
             .filter(|r| r.enabled && r.action == RuleAction::Hide)
             .count();
+        count += self
+            .repo_rules
+            .iter()
+            .filter(|r| r.enabled && r.action == RuleAction::Hide)
+            .count();
+        count += self
+            .title_rules
+            .iter()
+            .filter(|r| r.enabled && r.action == RuleAction::Hide)
+            .count();
+        count += self
+            .composite_rules
+            .iter()
+            .filter(|r| r.enabled && r.action == RuleAction::Hide)
+            .count();
         count += self
             .type_rules
             .iter()
@@ -339,86 +1045,456 @@ impl NotificationRuleSet {
         count
     }
 
-    /// Count rules with Important action or high priority value.
-    pub fn count_high_priority_rules(&self) -> usize {
-        if !self.enabled {
-            return 0;
+    /// Count rules with Important action or high priority value.
+    pub fn count_high_priority_rules(&self) -> usize {
+        if !self.enabled {
+            return 0;
+        }
+        let mut count = 0;
+        count += self
+            .org_rules
+            .iter()
+            .filter(|r| {
+                r.enabled && (r.priority >= PRIORITY_HIGH || r.action == RuleAction::Important)
+            })
+            .count();
+        count += self
+            .repo_rules
+            .iter()
+            .filter(|r| {
+                r.enabled && (r.priority >= PRIORITY_HIGH || r.action == RuleAction::Important)
+            })
+            .count();
+        count += self
+            .title_rules
+            .iter()
+            .filter(|r| {
+                r.enabled && (r.priority >= PRIORITY_HIGH || r.action == RuleAction::Important)
+            })
+            .count();
+        count += self
+            .composite_rules
+            .iter()
+            .filter(|r| {
+                r.enabled && (r.priority >= PRIORITY_HIGH || r.action == RuleAction::Important)
+            })
+            .count();
+        count += self
+            .type_rules
+            .iter()
+            .filter(|r| {
+                r.enabled && (r.priority >= PRIORITY_HIGH || r.action == RuleAction::Important)
+            })
+            .count();
+        count
+    }
+
+    /// Collected high-impact rule info for Overview display.
+    pub fn get_high_impact_rules(&self) -> Vec<HighImpactRule> {
+        if !self.enabled {
+            return Vec::new();
+        }
+
+        let mut rules = Vec::new();
+
+        // Account rules that are currently suppressing (outside schedule)
+        let now = Local::now();
+        for rule in &self.account_rules {
+            if rule.enabled && !rule.is_active(&now) {
+                let action = match rule.outside_behavior {
+                    OutsideScheduleBehavior::Suppress => RuleAction::Hide,
+                    OutsideScheduleBehavior::Defer => RuleAction::Silent,
+                };
+                rules.push(HighImpactRule {
+                    name: rule.account.clone(),
+                    action,
+                });
+            }
+        }
+
+        // Org rules with Hide or Important action
+        for rule in &self.org_rules {
+            if rule.enabled
+                && (rule.action == RuleAction::Hide
+                    || rule.action == RuleAction::Important
+                    || rule.priority >= PRIORITY_HIGH)
+            {
+                rules.push(HighImpactRule {
+                    name: rule.org.clone(),
+                    action: rule.action,
+                });
+            }
+        }
+
+        // Repo rules with Hide or Important action
+        for rule in &self.repo_rules {
+            if rule.enabled
+                && (rule.action == RuleAction::Hide
+                    || rule.action == RuleAction::Important
+                    || rule.priority >= PRIORITY_HIGH)
+            {
+                rules.push(HighImpactRule {
+                    name: rule.label(),
+                    action: rule.action,
+                });
+            }
+        }
+
+        // Title rules with Hide or Important action
+        for rule in &self.title_rules {
+            if rule.enabled
+                && (rule.action == RuleAction::Hide
+                    || rule.action == RuleAction::Important
+                    || rule.priority >= PRIORITY_HIGH)
+            {
+                rules.push(HighImpactRule {
+                    name: format!("Title: {}", rule.pattern),
+                    action: rule.action,
+                });
+            }
+        }
+
+        // Composite rules with Hide or Important action
+        for rule in &self.composite_rules {
+            if rule.enabled
+                && (rule.action == RuleAction::Hide
+                    || rule.action == RuleAction::Important
+                    || rule.priority >= PRIORITY_HIGH)
+            {
+                rules.push(HighImpactRule {
+                    name: rule.name.clone(),
+                    action: rule.action,
+                });
+            }
+        }
+
+        // Type rules with Hide or Important action
+        for rule in &self.type_rules {
+            if rule.enabled
+                && (rule.action == RuleAction::Hide
+                    || rule.action == RuleAction::Important
+                    || rule.priority >= PRIORITY_HIGH)
+            {
+                rules.push(HighImpactRule {
+                    name: rule.scoped_label(),
+                    action: rule.action,
+                });
+            }
+        }
+
+        rules
+    }
+
+    /// Every enabled rule, in evaluation-table order.
+    pub fn enabled_rule_summaries(&self) -> Vec<RuleSummary> {
+        let summary = |id: &str, name: String| RuleSummary {
+            id: id.to_string(),
+            name,
+        };
+
+        let accounts = self.account_rules.iter().filter(|r| r.enabled);
+        let orgs = self.org_rules.iter().filter(|r| r.enabled);
+        let repos = self.repo_rules.iter().filter(|r| r.enabled);
+        let titles = self.title_rules.iter().filter(|r| r.enabled);
+        let composites = self.composite_rules.iter().filter(|r| r.enabled);
+        let types = self.type_rules.iter().filter(|r| r.enabled);
+
+        accounts
+            .map(|r| summary(&r.id, format!("Account: {}", r.account)))
+            .chain(orgs.map(|r| summary(&r.id, format!("Org: {}", r.org))))
+            .chain(repos.map(|r| summary(&r.id, format!("Repo: {}", r.label()))))
+            .chain(titles.map(|r| summary(&r.id, format!("Title: {}", r.pattern))))
+            .chain(composites.map(|r| summary(&r.id, r.name.clone())))
+            .chain(types.map(|r| summary(&r.id, r.scoped_label())))
+            .collect()
+    }
+}
+
+// ============================================================================
+// RULE FILES
+// ============================================================================
+
+/// Rule file format version written by this build.
+///
+/// - 1: a bare rule set (`rules.json`); type rules stored `notification_type` labels.
+/// - 2: versioned files; type rules store `reason` as the API value.
+///
+/// Version 1 type rules still parse through `TypeRule`'s `notification_type` alias.
+pub const RULES_FORMAT_VERSION: u32 = 2;
+
+/// Why a rule file couldn't be read or written.
+#[derive(Debug, Error)]
+pub enum RuleFileError {
+    #[error("could not access {}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("{} is not a valid rule file: {source}", path.display())]
+    Parse {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    #[error(
+        "{} uses rule format version {found}, newer than this build supports ({RULES_FORMAT_VERSION})",
+        path.display()
+    )]
+    UnsupportedVersion { path: PathBuf, found: u32 },
+}
+
+fn parse_error(path: &Path, source: serde_json::Error) -> RuleFileError {
+    RuleFileError::Parse {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+/// An exported rule set.
+#[derive(Debug, Serialize, Deserialize)]
+struct RuleSetFile {
+    version: u32,
+    rule_set: NotificationRuleSet,
+}
+
+/// Read a rule file as JSON, rejecting formats newer than this build.
+fn read_versioned(path: &Path) -> Result<serde_json::Value, RuleFileError> {
+    let content = fs::read_to_string(path).map_err(|source| RuleFileError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let value: serde_json::Value =
+        serde_json::from_str(&content).map_err(|e| parse_error(path, e))?;
+
+    let version = value
+        .get("version")
+        .and_then(serde_json::Value::as_u64)
+        .map_or(1, |v| u32::try_from(v).unwrap_or(u32::MAX));
+    if version > RULES_FORMAT_VERSION {
+        return Err(RuleFileError::UnsupportedVersion {
+            path: path.to_path_buf(),
+            found: version,
+        });
+    }
+    Ok(value)
+}
+
+/// Whether this load failure differs from the last one reported.
+/// A successful load (`None`) resets it, so a later failure is reported again.
+fn first_report(error: Option<&RuleFileError>) -> bool {
+    static LAST_REPORTED: Mutex<Option<String>> = Mutex::new(None);
+
+    let message = error.map(ToString::to_string);
+    let mut last = LAST_REPORTED.lock().unwrap_or_else(|e| e.into_inner());
+    if *last == message {
+        return false;
+    }
+    *last = message;
+    true
+}
+
+fn merge_rules<T>(into: &mut Vec<T>, from: Vec<T>, same: impl Fn(&T, &T) -> bool) {
+    for rule in from {
+        match into.iter_mut().find(|existing| same(existing, &rule)) {
+            Some(existing) => *existing = rule,
+            None => into.push(rule),
+        }
+    }
+}
+
+// ============================================================================
+// RULE SET STORE
+// ============================================================================
+
+/// All named rule sets plus the one chosen by hand.
+///
+/// A set whose schedule is open takes precedence over the manual choice,
+/// so an "On-Call" set can switch itself on for a rotation window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleSetStore {
+    #[serde(default = "legacy_version")]
+    pub version: u32,
+    /// Name of the manually selected set.
+    pub active: String,
+    pub sets: Vec<NotificationRuleSet>,
+}
+
+impl Default for RuleSetStore {
+    fn default() -> Self {
+        Self::from_single(NotificationRuleSet {
+            name: default_rule_set_name(),
+            ..Default::default()
+        })
+    }
+}
+
+fn legacy_version() -> u32 {
+    1
+}
+
+impl RuleSetStore {
+    fn config_dir() -> Option<PathBuf> {
+        dirs::config_dir().map(|p| p.join("GitTop"))
+    }
+
+    fn from_single(set: NotificationRuleSet) -> Self {
+        Self {
+            version: RULES_FORMAT_VERSION,
+            active: set.name.clone(),
+            sets: vec![set],
+        }
+    }
+
+    /// Load the store, falling back to defaults if it can't be read.
+    pub fn load() -> Self {
+        Self::load_checked().0
+    }
+
+    /// Load the store, reporting why it couldn't be read instead of hiding it.
+    ///
+    /// Migrates a legacy single `rules.json` on first run. A store that fails
+    /// to parse is copied to `rule_sets.json.bak` before anything can overwrite it.
+    /// The store is loaded on every tick, so each failure is logged and backed up once.
+    pub fn load_checked() -> (Self, Option<RuleFileError>) {
+        let Some(dir) = Self::config_dir() else {
+            return (Self::default(), None);
+        };
+
+        let path = dir.join("rule_sets.json");
+        if path.exists() {
+            return match Self::read(&path) {
+                Ok(store) => {
+                    first_report(None);
+                    (store, None)
+                }
+                Err(e) => {
+                    if first_report(Some(&e)) {
+                        eprintln!("[WARN] {e}");
+                        let _ = fs::copy(&path, dir.join("rule_sets.json.bak"));
+                    }
+                    (Self::default(), Some(e))
+                }
+            };
+        }
+
+        let legacy = dir.join("rules.json");
+        if legacy.exists() {
+            return match NotificationRuleSet::import_from(&legacy) {
+                Ok(set) => (Self::from_single(set), None),
+                Err(e) => {
+                    if first_report(Some(&e)) {
+                        eprintln!("[WARN] {e}");
+                    }
+                    (Self::default(), Some(e))
+                }
+            };
+        }
+
+        (Self::default(), None)
+    }
+
+    fn backup_path() -> Option<PathBuf> {
+        Self::config_dir().map(|dir| dir.join("rule_sets.json.bak"))
+    }
+
+    /// Whether a copy of a store that failed to load is waiting to be restored.
+    pub fn has_backup() -> bool {
+        Self::backup_path().is_some_and(|path| path.exists())
+    }
+
+    /// Replace the store with `rule_sets.json.bak` once it has been fixed.
+    pub fn restore_backup() -> Result<Self, RuleFileError> {
+        let Some(backup) = Self::backup_path() else {
+            return Ok(Self::default());
+        };
+        let store = Self::read(&backup)?;
+        store.save().map_err(|source| RuleFileError::Io {
+            path: backup.with_file_name("rule_sets.json"),
+            source,
+        })?;
+        let _ = fs::remove_file(&backup);
+        Ok(store)
+    }
+
+    fn read(path: &Path) -> Result<Self, RuleFileError> {
+        let value = read_versioned(path)?;
+        let mut store = serde_json::from_value::<Self>(value).map_err(|e| parse_error(path, e))?;
+        if store.sets.is_empty() {
+            store = Self::default();
         }
-        let mut count = 0;
-        count += self
-            .org_rules
-            .iter()
-            .filter(|r| {
-                r.enabled && (r.priority >= PRIORITY_HIGH || r.action == RuleAction::Important)
-            })
-            .count();
-        count += self
-            .type_rules
-            .iter()
-            .filter(|r| {
-                r.enabled && (r.priority >= PRIORITY_HIGH || r.action == RuleAction::Important)
-            })
-            .count();
-        count
+        store.version = RULES_FORMAT_VERSION;
+        Ok(store)
     }
 
-    /// Collected high-impact rule info for Overview display.
-    pub fn get_high_impact_rules(&self) -> Vec<HighImpactRule> {
-        if !self.enabled {
-            return Vec::new();
+    /// Save the store to disk.
+    pub fn save(&self) -> Result<(), std::io::Error> {
+        if let Some(dir) = Self::config_dir() {
+            fs::create_dir_all(&dir)?;
+            let content = serde_json::to_string_pretty(self)?;
+            fs::write(dir.join("rule_sets.json"), content)?;
         }
+        Ok(())
+    }
 
-        let mut rules = Vec::new();
+    pub fn names(&self) -> Vec<String> {
+        self.sets.iter().map(|s| s.name.clone()).collect()
+    }
 
-        // Account rules that are currently suppressing (outside schedule)
-        let now = Local::now();
-        for rule in &self.account_rules {
-            if rule.enabled && !rule.is_active(&now) {
-                let action = match rule.outside_behavior {
-                    OutsideScheduleBehavior::Suppress => RuleAction::Hide,
-                    OutsideScheduleBehavior::Defer => RuleAction::Silent,
-                };
-                rules.push(HighImpactRule {
-                    name: rule.account.clone(),
-                    action,
-                });
-            }
+    pub fn get(&self, name: &str) -> Option<&NotificationRuleSet> {
+        self.sets.iter().find(|s| s.name == name)
+    }
+
+    /// The set in force at `now`: a scheduled set if one is open, else the manual choice.
+    pub fn active_set(&self, now: &chrono::DateTime<Local>) -> &NotificationRuleSet {
+        self.sets
+            .iter()
+            .find(|s| s.is_scheduled_now(now))
+            .or_else(|| self.get(&self.active))
+            .unwrap_or(&self.sets[0])
+    }
+
+    /// Make `name` the manually selected set. Returns false if it doesn't exist.
+    pub fn select(&mut self, name: &str) -> bool {
+        let exists = self.get(name).is_some();
+        if exists {
+            self.active = name.to_string();
         }
+        exists
+    }
 
-        // Org rules with Hide or Important action
-        for rule in &self.org_rules {
-            if rule.enabled
-                && (rule.action == RuleAction::Hide
-                    || rule.action == RuleAction::Important
-                    || rule.priority >= PRIORITY_HIGH)
-            {
-                rules.push(HighImpactRule {
-                    name: rule.org.clone(),
-                    action: rule.action,
-                });
-            }
+    /// Insert a set, replacing any existing set with the same name.
+    pub fn upsert(&mut self, set: NotificationRuleSet) {
+        match self.sets.iter_mut().find(|s| s.name == set.name) {
+            Some(existing) => *existing = set,
+            None => self.sets.push(set),
         }
+    }
 
-        // Type rules with Hide or Important action
-        for rule in &self.type_rules {
-            if rule.enabled
-                && (rule.action == RuleAction::Hide
-                    || rule.action == RuleAction::Important
-                    || rule.priority >= PRIORITY_HIGH)
-            {
-                let name = if let Some(acc) = &rule.account {
-                    format!("{} ({})", rule.notification_type, acc)
-                } else {
-                    format!("{} (Global)", rule.notification_type)
-                };
-                rules.push(HighImpactRule {
-                    name,
-                    action: rule.action,
-                });
-            }
+    /// Remove a set. The last remaining set cannot be removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        if self.sets.len() <= 1 || self.get(name).is_none() {
+            return false;
+        }
+        self.sets.retain(|s| s.name != name);
+        if self.active == name {
+            self.active = self.sets[0].name.clone();
         }
+        true
+    }
 
-        rules
+    /// Rename a set. Fails if the new name is empty or taken.
+    pub fn rename(&mut self, old: &str, new: &str) -> bool {
+        let new = new.trim();
+        if new.is_empty() || self.get(new).is_some() {
+            return false;
+        }
+        let Some(set) = self.sets.iter_mut().find(|s| s.name == old) else {
+            return false;
+        };
+        set.name = new.to_string();
+        if self.active == old {
+            self.active = new.to_string();
+        }
+        true
     }
 }
 
@@ -426,20 +1502,47 @@ impl NotificationRuleSet {
 // RULE ENGINE (EVALUATION)
 // ============================================================================
 
+/// The notification attributes rules are matched against.
+///
+/// Built from a [`NotificationView`] during evaluation, or assembled by hand
+/// in the Explain Decision panel where only some attributes are known.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RuleContext<'a> {
+    pub repo_owner: Option<&'a str>,
+    pub repo_full_name: Option<&'a str>,
+    pub title: Option<&'a str>,
+    pub account: Option<&'a str>,
+    pub reason: Option<NotificationReason>,
+    pub subject_type: Option<SubjectType>,
+    pub is_private: Option<bool>,
+}
+
+impl<'a> RuleContext<'a> {
+    pub fn from_notification(notification: &'a NotificationView) -> Self {
+        Self {
+            repo_owner: Some(notification.repo_owner()),
+            repo_full_name: Some(&notification.repo_full_name),
+            title: Some(&notification.title),
+            account: Some(&notification.account),
+            reason: Some(notification.reason),
+            subject_type: Some(notification.subject_type),
+            is_private: Some(notification.is_private),
+        }
+    }
+}
+
 impl NotificationRuleSet {
     /// Evaluate with full trace of the decision.
     pub fn evaluate_detailed(
         &self,
-        notification_type: &str,
-        repo_owner: Option<&str>,
-        account: Option<&str>,
+        notification: &NotificationView,
         now: &chrono::DateTime<Local>,
     ) -> (RuleAction, Option<RuleDecision>) {
         if !self.enabled {
             return (RuleAction::Show, None);
         }
 
-        let matches = self.trace(notification_type, repo_owner, account, now, false);
+        let matches = self.trace(&RuleContext::from_notification(notification), now, false);
 
         if matches.is_empty() {
             return (RuleAction::Show, None);
@@ -478,12 +1581,17 @@ impl NotificationRuleSet {
     /// Gather all applicable rules for a given context, sorted by valid priority order.
     pub fn trace(
         &self,
-        notification_type: &str,
-        repo_owner: Option<&str>,
-        account: Option<&str>,
+        context: &RuleContext<'_>,
         now: &chrono::DateTime<Local>,
         allow_loose_account_match: bool,
     ) -> Vec<MatchResult> {
+        let RuleContext {
+            repo_owner,
+            repo_full_name,
+            title,
+            account,
+            ..
+        } = *context;
         let mut matches = Vec::new();
 
         // 1. Account Rules
@@ -507,15 +1615,21 @@ impl NotificationRuleSet {
                             enabled: true,
                         });
                     } else {
-                        let action = match rule.outside_behavior {
-                            OutsideScheduleBehavior::Suppress => RuleAction::Hide,
-                            OutsideScheduleBehavior::Defer => RuleAction::Silent,
+                        let (action, reason) = match rule.outside_behavior {
+                            OutsideScheduleBehavior::Suppress => (
+                                RuleAction::Hide,
+                                RuleDecisionReason::Account(rule.account.clone()),
+                            ),
+                            OutsideScheduleBehavior::Defer => (
+                                RuleAction::Silent,
+                                RuleDecisionReason::Deferred(rule.account.clone()),
+                            ),
                         };
                         matches.push(MatchResult {
                             id: rule.id.clone(),
                             priority: PRIORITY_DEFAULT,
                             action,
-                            reason: RuleDecisionReason::Account(rule.account.clone()),
+                            reason,
                             rule_source: "Account".to_string(),
                             name: rule.account.clone(),
                             enabled: true,
@@ -543,15 +1657,66 @@ impl NotificationRuleSet {
             );
         }
 
-        // 3. Type Rules
+        // 3. Repo Rules
+        if let Some(repo) = repo_full_name {
+            matches.extend(
+                self.repo_rules
+                    .iter()
+                    .filter(|r| {
+                        r.enabled && r.matches(repo) && r.visibility.matches(context.is_private)
+                    })
+                    .map(|r| MatchResult {
+                        id: r.id.clone(),
+                        priority: r.priority,
+                        action: r.action,
+                        reason: RuleDecisionReason::Repo(r.label()),
+                        rule_source: "Repo".to_string(),
+                        name: r.label(),
+                        enabled: true,
+                    }),
+            );
+        }
+
+        // 4. Title Rules
+        if let Some(title) = title {
+            matches.extend(
+                self.title_rules
+                    .iter()
+                    .filter(|r| r.enabled && r.matches(title))
+                    .map(|r| MatchResult {
+                        id: r.id.clone(),
+                        priority: r.priority,
+                        action: r.action,
+                        reason: RuleDecisionReason::Title(r.pattern.clone()),
+                        rule_source: "Title".to_string(),
+                        name: r.pattern.clone(),
+                        enabled: true,
+                    }),
+            );
+        }
+
+        // 5. Composite Rules
+        matches.extend(
+            self.composite_rules
+                .iter()
+                .filter(|r| r.enabled && r.condition.matches(context, now))
+                .map(|r| MatchResult {
+                    id: r.id.clone(),
+                    priority: r.priority,
+                    action: r.action,
+                    reason: RuleDecisionReason::Composite(r.name.clone()),
+                    rule_source: "Composite".to_string(),
+                    name: r.name.clone(),
+                    enabled: true,
+                }),
+        );
+
+        // 6. Type Rules
         matches.extend(
             self.type_rules
                 .iter()
                 .filter(|r| {
-                    if !r.enabled {
-                        return false;
-                    }
-                    if !r.notification_type.eq_ignore_ascii_case(notification_type) {
+                    if !r.enabled || !r.matches(context) {
                         return false;
                     }
 
@@ -567,13 +1732,9 @@ impl NotificationRuleSet {
                     id: r.id.clone(),
                     priority: r.priority,
                     action: r.action,
-                    reason: RuleDecisionReason::Type(r.notification_type.clone()),
+                    reason: RuleDecisionReason::Type(r.scoped_label()),
                     rule_source: "Type".to_string(),
-                    name: format!(
-                        "{} ({})",
-                        r.notification_type,
-                        r.account.as_deref().unwrap_or("Global")
-                    ),
+                    name: r.scoped_label(),
                     enabled: true,
                 }),
         );
@@ -610,6 +1771,19 @@ impl NotificationRuleSet {
     }
 }
 
+impl NotificationRuleSet {
+    /// Whether an account's schedule currently defers its notifications.
+    pub fn is_deferring(&self, account: &str, now: &chrono::DateTime<Local>) -> bool {
+        self.enabled
+            && self.account_rules.iter().any(|r| {
+                r.enabled
+                    && r.account.eq_ignore_ascii_case(account)
+                    && r.outside_behavior == OutsideScheduleBehavior::Defer
+                    && !r.is_active(now)
+            })
+    }
+}
+
 /// Rule engine wrapper (legacy support, or use NotificationRuleSet directly).
 pub struct RuleEngine {
     rules: NotificationRuleSet,
@@ -622,13 +1796,14 @@ impl RuleEngine {
 
     pub fn evaluate_detailed(
         &self,
-        notification_type: &str,
-        repo_owner: Option<&str>,
-        account: Option<&str>,
+        notification: &NotificationView,
         now: &chrono::DateTime<Local>,
     ) -> (RuleAction, Option<RuleDecision>) {
-        self.rules
-            .evaluate_detailed(notification_type, repo_owner, account, now)
+        self.rules.evaluate_detailed(notification, now)
+    }
+
+    pub fn rules(&self) -> &NotificationRuleSet {
+        &self.rules
     }
 }
 
@@ -641,7 +1816,7 @@ pub struct MatchResult {
     pub reason: RuleDecisionReason,
 
     // UI Helpers
-    pub rule_source: String, // "Account", "Org", "Type"
+    pub rule_source: String, // "Account", "Org", "Repo", "Title", "Composite", "Type"
     pub name: String,
     pub enabled: bool,
 }
@@ -655,16 +1830,76 @@ pub struct RuleDecision {
     pub reason: RuleDecisionReason,
 }
 
+impl RuleDecision {
+    /// One-line explanation, e.g. "Silenced by Type rule 'Watching (Global)'".
+    pub fn summary(&self) -> String {
+        let verb = match (&self.reason, self.action) {
+            (RuleDecisionReason::Deferred(_), _) => "Deferred",
+            (_, RuleAction::Show) => "Shown",
+            (_, RuleAction::Silent) => "Silenced",
+            (_, RuleAction::Hide) => "Hidden",
+            (_, RuleAction::Important) => "Marked important",
+        };
+        let (kind, name) = self.reason.kind_and_name();
+        format!("{verb} by {kind} rule '{name}'")
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RuleDecisionReason {
     Account(String),
+    /// Held back by the account's schedule until its next active window.
+    Deferred(String),
     Org(String),
+    Repo(String),
+    /// The title rule pattern that matched.
+    Title(String),
+    /// The name of the composite rule that matched.
+    Composite(String),
+    /// The type rule's target and scope.
     Type(String),
 }
 
+impl RuleDecisionReason {
+    /// The kind of rule that decided, and what it matched on.
+    pub fn kind_and_name(&self) -> (&'static str, &str) {
+        match self {
+            Self::Account(name) | Self::Deferred(name) => ("Account", name),
+            Self::Org(name) => ("Org", name),
+            Self::Repo(name) => ("Repo", name),
+            Self::Title(name) => ("Title", name),
+            Self::Composite(name) => ("Composite", name),
+            Self::Type(name) => ("Type", name),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::github::types::{NotificationReason, SubjectType};
+
+    fn notification(repo_full_name: &str, title: &str, account: &str) -> NotificationView {
+        NotificationView {
+            id: "1".to_string(),
+            title: title.to_string(),
+            repo_name: repo_full_name
+                .rsplit('/')
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            repo_full_name: repo_full_name.to_string(),
+            subject_type: SubjectType::PullRequest,
+            reason: NotificationReason::Mention,
+            unread: true,
+            updated_at: chrono::Utc::now(),
+            url: None,
+            latest_comment_url: None,
+            avatar_url: String::new(),
+            is_private: false,
+            account: account.to_string(),
+        }
+    }
 
     #[test]
     fn test_rule_set_default() {
@@ -744,7 +1979,343 @@ mod tests {
         // Account rule says Hide. Org rule says Important.
         // Important should win.
         let (action, _) =
-            engine.evaluate_detailed("mention", Some("WorkOrg"), Some("WorkAcc"), &now);
+            engine.evaluate_detailed(&notification("WorkOrg/app", "Fix build", "WorkAcc"), &now);
         assert_eq!(action, RuleAction::Important);
     }
+
+    #[test]
+    fn test_repo_rule_glob() {
+        let mut rules = NotificationRuleSet {
+            enabled: true,
+            ..Default::default()
+        };
+        let mut repo_rule = RepoRule::new("my-org/infra-*", 10);
+        repo_rule.action = RuleAction::Silent;
+        rules.repo_rules.push(repo_rule);
+
+        let now = chrono::Local::now();
+        let eval = |repo: &str| {
+            rules
+                .evaluate_detailed(&notification(repo, "Fix build", "someone"), &now)
+                .0
+        };
+        assert_eq!(eval("my-org/infra-terraform"), RuleAction::Silent);
+        assert_eq!(eval("My-Org/Infra-"), RuleAction::Silent);
+        assert_eq!(eval("my-org/app"), RuleAction::Show);
+        assert_eq!(eval("other/my-org/infra-x"), RuleAction::Show);
+
+        assert!(glob_matches("*/docs", "rust-lang/docs"));
+        assert!(glob_matches("owner/r?po", "owner/repo"));
+        assert!(!glob_matches("owner/r?po", "owner/rpo"));
+    }
+
+    #[test]
+    fn test_repo_rule_visibility() {
+        let mut rules = NotificationRuleSet {
+            enabled: true,
+            ..Default::default()
+        };
+        let mut private_rule = RepoRule::new("*", 10);
+        private_rule.visibility = RepoVisibility::Private;
+        private_rule.action = RuleAction::Important;
+        let mut public_rule = RepoRule::new("*", 0);
+        public_rule.visibility = RepoVisibility::Public;
+        public_rule.action = RuleAction::Silent;
+        rules.repo_rules.extend([private_rule, public_rule]);
+
+        let now = chrono::Local::now();
+        let mut n = notification("work/api", "Deploy", "someone");
+        n.is_private = true;
+        assert_eq!(rules.evaluate_detailed(&n, &now).0, RuleAction::Important);
+        n.is_private = false;
+        assert_eq!(rules.evaluate_detailed(&n, &now).0, RuleAction::Silent);
+
+        // The Explain panel doesn't know the visibility, so restricted rules stay out.
+        let context = RuleContext {
+            repo_full_name: Some("work/api"),
+            ..Default::default()
+        };
+        assert!(rules.trace(&context, &now, true).is_empty());
+    }
+
+    #[test]
+    fn test_decision_summary() {
+        let mut rules = NotificationRuleSet {
+            enabled: true,
+            ..Default::default()
+        };
+        let mut watching = TypeRule::new(Some(NotificationReason::Subscribed), None, None, 0);
+        watching.action = RuleAction::Silent;
+        rules.type_rules.push(watching);
+
+        let mut n = notification("org/app", "Bump", "someone");
+        n.reason = NotificationReason::Subscribed;
+        let (_, decision) = rules.evaluate_detailed(&n, &chrono::Local::now());
+        assert_eq!(
+            decision.unwrap().summary(),
+            "Silenced by Type rule 'Watching (Global)'"
+        );
+    }
+
+    #[test]
+    fn test_quick_rules() {
+        let mut rules = NotificationRuleSet::default();
+        let mut n = notification("Acme/api", "Deploy", "someone");
+        n.reason = NotificationReason::Subscribed;
+        let now = chrono::Local::now();
+
+        // Adding a rule doesn't switch a disabled set on.
+        rules.add_quick_rule(QuickRule::SilenceReason, &n).unwrap();
+        assert!(!rules.enabled);
+        assert_eq!(rules.evaluate_detailed(&n, &now).0, RuleAction::Show);
+
+        rules.enabled = true;
+        assert_eq!(rules.evaluate_detailed(&n, &now).0, RuleAction::Silent);
+
+        rules.add_quick_rule(QuickRule::HideRepo, &n).unwrap();
+        rules.add_quick_rule(QuickRule::HideRepo, &n).unwrap();
+        assert_eq!(rules.repo_rules.len(), 1);
+        assert_eq!(rules.evaluate_detailed(&n, &now).0, RuleAction::Hide);
+
+        rules.add_quick_rule(QuickRule::ImportantOrg, &n).unwrap();
+        assert_eq!(rules.org_rules[0].org, "Acme");
+        assert_eq!(rules.evaluate_detailed(&n, &now).0, RuleAction::Important);
+
+        // An existing rule with another action is reported, not overwritten.
+        rules.org_rules[0].action = RuleAction::Silent;
+        assert_eq!(
+            rules.add_quick_rule(QuickRule::ImportantOrg, &n),
+            Err(QuickRuleConflict {
+                target: "Acme".to_string(),
+                existing: RuleAction::Silent,
+            })
+        );
+        assert_eq!(rules.org_rules.len(), 1);
+        assert_eq!(rules.org_rules[0].action, RuleAction::Silent);
+    }
+
+    #[test]
+    fn test_title_rules() {
+        let mut rules = NotificationRuleSet {
+            enabled: true,
+            ..Default::default()
+        };
+        let mut deps = TitleRule::new("chore(deps)", TitleMatchMode::StartsWith, 0);
+        deps.action = RuleAction::Hide;
+        rules.title_rules.push(deps);
+        let mut sev = TitleRule::new(r"\[SEV[12]\]", TitleMatchMode::Regex, 0);
+        sev.action = RuleAction::Important;
+        rules.title_rules.push(sev);
+
+        let now = chrono::Local::now();
+        let eval =
+            |title: &str| rules.evaluate_detailed(&notification("org/app", title, "someone"), &now);
+
+        let (action, decision) = eval("Chore(deps): bump serde to 1.0.200");
+        assert_eq!(action, RuleAction::Hide);
+        assert_eq!(
+            decision.map(|d| d.reason),
+            Some(RuleDecisionReason::Title("chore(deps)".to_string()))
+        );
+        assert_eq!(eval("[SEV1] API outage").0, RuleAction::Important);
+        assert_eq!(eval("Revert chore(deps) bump").0, RuleAction::Show);
+
+        let invalid = TitleRule::new("(unclosed", TitleMatchMode::Regex, 0);
+        assert!(invalid.validate().is_err());
+        assert!(!invalid.matches("(unclosed"));
+    }
+
+    #[test]
+    fn test_title_regex_matching() {
+        let regex = |pattern: &str| TitleRule::new(pattern, TitleMatchMode::Regex, 0);
+        assert!(regex(r"^chore\(deps\)").matches("chore(deps): bump serde"));
+        assert!(!regex(r"^chore\(deps\)").matches("fix: chore(deps) fallout"));
+        assert!(regex("^(feat|fix)(\\(.+\\))?: ").matches("fix(ui): button"));
+        assert!(regex(r"v\d+\.\d+").matches("Release v1.24"));
+        // Backtracking matchers take exponential time on this; the regex crate doesn't.
+        assert!(!regex("^(a|a)*b$").matches(&"a".repeat(64)));
+
+        assert!(regex("^Bump").matches("bump x"));
+        let mut bump = regex("^Bump");
+        bump.case_sensitive = true;
+        assert!(!bump.matches("bump x"));
+
+        for invalid in ["(abc", "abc)", "[abc", "*a"] {
+            assert!(validate_title_pattern(invalid, TitleMatchMode::Regex).is_err());
+        }
+        assert!(validate_title_pattern("(abc", TitleMatchMode::Contains).is_ok());
+    }
+
+    #[test]
+    fn test_composite_rules() {
+        let condition: Condition = serde_json::from_str(
+            r#"{"all": [
+                {"reason": "review_requested"},
+                {"org": "acme"},
+                {"subject_type": "PullRequest"},
+                {"not": {"title": "wip"}}
+            ]}"#,
+        )
+        .unwrap();
+        assert!(condition.validate().is_ok());
+
+        let mut rules = NotificationRuleSet {
+            enabled: true,
+            ..Default::default()
+        };
+        let mut rule = CompositeRule::new("Acme reviews", condition, 0);
+        rule.action = RuleAction::Important;
+        rules.composite_rules.push(rule);
+
+        let now = chrono::Local::now();
+        let mut review = notification("Acme/api", "Add caching", "someone");
+        review.reason = NotificationReason::ReviewRequested;
+        assert_eq!(
+            rules.evaluate_detailed(&review, &now).1.map(|d| d.reason),
+            Some(RuleDecisionReason::Composite("Acme reviews".to_string()))
+        );
+
+        review.title = "WIP: add caching".to_string();
+        assert_eq!(rules.evaluate_detailed(&review, &now).0, RuleAction::Show);
+
+        let typo: Condition = serde_json::from_str(r#"{"reason": "review_requestd"}"#).unwrap();
+        assert!(typo.validate().is_err());
+
+        let window: Condition =
+            serde_json::from_str(r#"{"time_window": {"start": "22:00:00", "end": "07:00:00"}}"#)
+                .unwrap();
+        assert_eq!(window.to_string(), "between 22:00 and 07:00");
+        assert!(time_in_window(
+            NaiveTime::from_hms_opt(23, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(7, 0, 0).unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_type_rule_subject_and_migration() {
+        // Older rule files stored the display label under `notification_type`.
+        let legacy: TypeRule = serde_json::from_str(
+            r#"{"id": "t1", "enabled": true, "notification_type": "Subscribed",
+                "account": null, "priority": 0, "action": "Hide"}"#,
+        )
+        .unwrap();
+        assert_eq!(legacy.reason, Some(NotificationReason::Manual));
+        let saved = serde_json::to_value(&legacy).unwrap();
+        assert_eq!(saved["reason"], "manual");
+        let reloaded: TypeRule = serde_json::from_value(saved).unwrap();
+        assert_eq!(reloaded.reason, Some(NotificationReason::Manual));
+
+        let reason = |value: &str| {
+            serde_json::from_value::<TypeRule>(serde_json::json!({
+                "id": "t2", "enabled": true, "reason": value, "priority": 0, "action": "Hide"
+            }))
+            .map(|rule| rule.reason)
+        };
+        assert_eq!(
+            reason("subscribed").unwrap(),
+            Some(NotificationReason::Subscribed)
+        );
+        assert_eq!(
+            reason("review requested").unwrap(),
+            Some(NotificationReason::ReviewRequested)
+        );
+        assert_eq!(
+            reason("Review_Requested").unwrap(),
+            Some(NotificationReason::ReviewRequested)
+        );
+        assert!(reason("reviewer_requested").is_err());
+        assert!(reason("unknown").is_err());
+
+        let mut rules = NotificationRuleSet {
+            enabled: true,
+            ..Default::default()
+        };
+        let mut ci = TypeRule::new(None, Some(SubjectType::CheckSuite), None, 0);
+        ci.action = RuleAction::Silent;
+        rules.type_rules.push(ci);
+
+        let now = chrono::Local::now();
+        let mut check = notification("org/app", "CI failed", "someone");
+        check.subject_type = SubjectType::CheckSuite;
+        assert_eq!(rules.evaluate_detailed(&check, &now).0, RuleAction::Silent);
+        check.subject_type = SubjectType::Issue;
+        assert_eq!(rules.evaluate_detailed(&check, &now).0, RuleAction::Show);
+    }
+
+    #[test]
+    fn test_rule_set_store_scheduling() {
+        use chrono::TimeZone;
+
+        let mut store = RuleSetStore::from_single(NotificationRuleSet::named("Work"));
+        let mut on_call = NotificationRuleSet::named("On-Call");
+        on_call.schedule = Some(RuleSetSchedule {
+            start_time: NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+            end_time: NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+            ..Default::default()
+        });
+        store.upsert(on_call);
+
+        let day = Local.with_ymd_and_hms(2025, 1, 6, 12, 0, 0).unwrap();
+        let night = Local.with_ymd_and_hms(2025, 1, 6, 23, 0, 0).unwrap();
+        assert_eq!(store.active_set(&day).name, "Work");
+        assert_eq!(store.active_set(&night).name, "On-Call");
+
+        assert!(store.rename("Work", "Office"));
+        assert!(!store.rename("Office", "On-Call"));
+        assert_eq!(store.active, "Office");
+
+        assert!(store.remove("Office"));
+        assert!(!store.remove("On-Call"));
+        assert_eq!(store.active, "On-Call");
+    }
+
+    #[test]
+    fn test_rule_file_import_export() {
+        let dir = std::env::temp_dir().join(format!("gittop-rules-{}", Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+
+        // Version 1: a bare set with label-based type rules.
+        let legacy = dir.join("legacy.json");
+        fs::write(
+            &legacy,
+            r#"{"name": "Team", "enabled": true, "account_rules": [], "org_rules": [],
+                "type_rules": [{"id": "t1", "enabled": true, "notification_type": "Mentioned",
+                                "account": null, "priority": 0, "action": "Important"}]}"#,
+        )
+        .unwrap();
+        let imported = NotificationRuleSet::import_from(&legacy).unwrap();
+        assert_eq!(
+            imported.type_rules[0].reason,
+            Some(NotificationReason::Mention)
+        );
+
+        let exported = dir.join("team.json");
+        imported.export_to(&exported).unwrap();
+        let round_trip = NotificationRuleSet::import_from(&exported).unwrap();
+        assert_eq!(round_trip.name, "Team");
+
+        let mut mine = NotificationRuleSet::named("Mine");
+        mine.type_rules.push(TypeRule::new(None, None, None, 5));
+        let mut changed = round_trip.clone();
+        changed.type_rules[0].priority = 50;
+        mine.merge(round_trip);
+        mine.merge(changed);
+        assert_eq!(mine.type_rules.len(), 2);
+        assert_eq!(mine.type_rules[1].priority, 50);
+
+        let future = dir.join("future.json");
+        fs::write(&future, r#"{"version": 99, "rule_set": {}}"#).unwrap();
+        assert!(matches!(
+            NotificationRuleSet::import_from(&future),
+            Err(RuleFileError::UnsupportedVersion { found: 99, .. })
+        ));
+        fs::write(&future, "{ not json").unwrap();
+        assert!(matches!(
+            NotificationRuleSet::import_from(&future),
+            Err(RuleFileError::Parse { .. })
+        ));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
 }
@@ -1,16 +1,23 @@
 //! Rule Engine screen - main state and layout.
 
-use iced::widget::{Space, button, column, container, row, scrollable, text, toggler};
+use iced::widget::{Space, button, column, container, pick_list, row, scrollable, text, toggler};
 use iced::{Alignment, Element, Fill, Length, Task};
 
+use crate::cache::{DiskCache, RuleHitStats};
+use crate::github::NotificationView;
 use crate::settings::{AppSettings, IconTheme};
-use crate::ui::screens::settings::rule_engine::rules::{NotificationRuleSet, RuleAction, TypeRule};
+use crate::tray::TrayManager;
+use crate::ui::screens::notifications::{DryRunReport, NotificationEngine};
+use crate::ui::screens::settings::rule_engine::rules::{
+    AccountRule, CompositeRule, NotificationRuleSet, RepoRule, RuleAction, RuleSetStore, TitleRule,
+    TypeRule,
+};
 use crate::ui::{icons, theme};
 use chrono::NaiveTime;
 
 use super::messages::{
-    AccountMessage, ExplainMessage, InspectorMessage, OrgMessage, RuleEngineMessage, RuleTab,
-    TypeMessage,
+    AccountMessage, CompositeMessage, DryRunMessage, ExplainMessage, InspectorMessage, OrgMessage,
+    RepoMessage, RuleEngineMessage, RuleSetMessage, RuleTab, TitleMessage, TypeMessage,
 };
 use super::tabs;
 
@@ -18,6 +25,13 @@ use super::tabs;
 #[derive(Debug, Clone)]
 pub struct RuleEngineScreen {
     pub rules: NotificationRuleSet,
+    /// Snapshot of every stored rule set, for switching and the Rule Sets tab.
+    pub rule_sets: RuleSetStore,
+    pub rule_set_form: tabs::RuleSetFormState,
+    /// Why the stored rules couldn't be read, until dismissed.
+    pub load_error: Option<String>,
+    /// A copy of the unreadable store is waiting to be fixed and restored.
+    pub backup_available: bool,
     pub selected_tab: RuleTab,
     pub icon_theme: IconTheme,
     pub sidebar_width: f32,
@@ -28,11 +42,21 @@ pub struct RuleEngineScreen {
     pub expanded_account_time_windows: std::collections::HashSet<String>,
 
     // Type Rule Creation State
-    pub new_type_rule_type: crate::github::types::NotificationReason,
+    pub new_type_rule_type: Option<crate::github::types::NotificationReason>,
+    pub new_type_rule_subject: Option<crate::github::types::SubjectType>,
     pub new_type_rule_account: Option<String>,
     pub new_type_rule_priority: i32,
     pub new_type_rule_action: RuleAction,
 
+    // Repo Rule Creation State
+    pub new_repo_rule: tabs::RepoRuleFormState,
+
+    // Title Rule Creation State
+    pub new_title_rule: tabs::TitleRuleFormState,
+
+    // Composite Rule Editor State
+    pub composite_form: tabs::CompositeRuleFormState,
+
     // Type Rules Grouping State
     pub expanded_type_groups: std::collections::HashSet<String>,
 
@@ -41,32 +65,39 @@ pub struct RuleEngineScreen {
 
     // Explain Decision State
     pub explain_test_type: String,
+    pub explain_test_repo: String,
+    pub explain_test_title: String,
 
     // Handbook/Help State
     pub show_handbook: bool,
+
+    /// Notifications held back by Defer schedules, shown in the overview.
+    pub deferred_queue: Vec<NotificationView>,
+    /// Per-rule hit statistics, keyed by rule id.
+    pub rule_hits: std::collections::HashMap<String, RuleHitStats>,
+
+    // Dry Run State
+    /// Loaded notifications that edits are previewed against.
+    pub inbox: Vec<NotificationView>,
+    /// The saved rules while a dry run holds edits back.
+    pub dry_run_saved: Option<NotificationRuleSet>,
+    pub dry_run_report: DryRunReport,
+    /// Back was pressed during a dry run; pressing it again discards the draft.
+    pub leave_requested: bool,
 }
 
 impl RuleEngineScreen {
-    pub fn new(mut rules: NotificationRuleSet, settings: AppSettings) -> Self {
-        let accounts: Vec<String> = settings
-            .accounts
-            .iter()
-            .map(|a| a.username.clone())
-            .collect();
-
-        // Ensure every signed-in account has a rule entry
-        for account in &accounts {
-            if !rules
-                .account_rules
-                .iter()
-                .any(|r| r.account.eq_ignore_ascii_case(account))
-            {
-                use crate::ui::screens::settings::rule_engine::rules::AccountRule;
-                rules.account_rules.push(AccountRule::new(account));
-            }
-        }
+    pub fn new(rules: NotificationRuleSet, settings: AppSettings) -> Self {
+        let (rule_sets, load_error) = RuleSetStore::load_checked();
+        let accounts: Vec<String> = settings.accounts.iter().map(|a| a.key()).collect();
+        let rules = with_account_rules(rules, &accounts);
+        let inbox = Self::load_cached_inbox(&accounts);
 
         Self {
+            rule_sets,
+            load_error: load_error.map(|e| e.to_string()),
+            backup_available: RuleSetStore::has_backup(),
+            rule_set_form: tabs::RuleSetFormState::for_set(&rules),
             rules,
             selected_tab: RuleTab::default(),
             icon_theme: settings.icon_theme,
@@ -77,19 +108,84 @@ impl RuleEngineScreen {
             selected_account_id: None,
             expanded_account_time_windows: std::collections::HashSet::new(),
 
-            new_type_rule_type: crate::github::types::NotificationReason::Mention,
+            new_type_rule_type: Some(crate::github::types::NotificationReason::Mention),
+            new_type_rule_subject: None,
             new_type_rule_account: None,
             new_type_rule_priority: 0,
             new_type_rule_action: RuleAction::Show,
+            new_repo_rule: tabs::RepoRuleFormState::default(),
+            new_title_rule: tabs::TitleRuleFormState::default(),
+            composite_form: tabs::CompositeRuleFormState::default(),
             expanded_type_groups: std::collections::HashSet::new(),
             selected_rule_id: None,
 
             explain_test_type: "Mentioned".to_string(),
+            explain_test_repo: String::new(),
+            explain_test_title: String::new(),
             show_handbook: false,
+            deferred_queue: Self::load_deferred_queue(),
+            rule_hits: Self::load_rule_hits(),
+            inbox,
+            dry_run_saved: None,
+            leave_requested: false,
+            dry_run_report: DryRunReport::default(),
+        }
+    }
+
+    fn load_deferred_queue() -> Vec<NotificationView> {
+        let mut queue = DiskCache::shared()
+            .and_then(|cache| cache.load_deferred().ok())
+            .unwrap_or_default();
+        queue.sort_by_key(|n| std::cmp::Reverse(n.updated_at));
+        queue
+    }
+
+    fn load_rule_hits() -> std::collections::HashMap<String, RuleHitStats> {
+        DiskCache::shared()
+            .and_then(|cache| cache.load_rule_hits().ok())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|stats| (stats.rule_id.clone(), stats))
+            .collect()
+    }
+
+    /// The last notifications fetched for each account.
+    fn load_cached_inbox(accounts: &[String]) -> Vec<NotificationView> {
+        let Some(cache) = DiskCache::shared() else {
+            return Vec::new();
+        };
+        accounts
+            .iter()
+            .filter_map(|account| cache.load_notifications(account).ok().flatten())
+            .flatten()
+            .collect()
+    }
+
+    /// Whether Back may leave the screen. With a dry run open the first press
+    /// only warns, since leaving throws the held-back edits away.
+    pub fn confirm_leave(&mut self) -> bool {
+        if self.dry_run_saved.is_none() || self.leave_requested {
+            return true;
+        }
+        self.leave_requested = true;
+        false
+    }
+
+    /// Save the edited rules, or while a dry run holds them back, preview them.
+    fn save_rules(&mut self) {
+        match &self.dry_run_saved {
+            Some(saved) => {
+                self.dry_run_report = NotificationEngine::dry_run(saved, &self.rules, &self.inbox);
+            }
+            None => {
+                let _ = self.rules.save();
+            }
         }
     }
 
     pub fn update(&mut self, message: RuleEngineMessage) -> Task<RuleEngineMessage> {
+        // Anything but a second Back cancels a pending leave.
+        self.leave_requested = false;
         match message {
             RuleEngineMessage::Back => Task::none(),
             RuleEngineMessage::NoOp => Task::none(),
@@ -99,7 +195,38 @@ impl RuleEngineScreen {
             }
             RuleEngineMessage::ToggleEnabled(enabled) => {
                 self.rules.enabled = enabled;
-                let _ = self.rules.save();
+                self.save_rules();
+                Task::none()
+            }
+            RuleEngineMessage::DismissLoadError => {
+                self.load_error = None;
+                Task::none()
+            }
+            // Restoring switches sets, so it waits out a dry run like the rest.
+            RuleEngineMessage::RestoreBackup if self.dry_run_saved.is_some() => Task::none(),
+            RuleEngineMessage::RestoreBackup => {
+                match RuleSetStore::restore_backup() {
+                    Ok(store) => {
+                        self.switch_rule_set(store);
+                        self.load_error = None;
+                        self.backup_available = false;
+                    }
+                    Err(e) => self.load_error = Some(e.to_string()),
+                }
+                Task::none()
+            }
+            RuleEngineMessage::ClearDeferred => {
+                if let Some(cache) = DiskCache::shared() {
+                    let _ = cache.clear_deferred();
+                }
+                self.deferred_queue.clear();
+                Task::none()
+            }
+            RuleEngineMessage::ClearRuleHits => {
+                if let Some(cache) = DiskCache::shared() {
+                    let _ = cache.clear_rule_hits();
+                }
+                self.rule_hits.clear();
                 Task::none()
             }
             RuleEngineMessage::ToggleHandbook => {
@@ -108,12 +235,38 @@ impl RuleEngineScreen {
             }
             RuleEngineMessage::Account(msg) => self.update_account(msg),
             RuleEngineMessage::Org(msg) => self.update_org(msg),
+            RuleEngineMessage::Repo(msg) => self.update_repo(msg),
+            RuleEngineMessage::Title(msg) => self.update_title(msg),
+            RuleEngineMessage::Composite(msg) => self.update_composite(msg),
             RuleEngineMessage::Type(msg) => self.update_type(msg),
             RuleEngineMessage::Inspector(msg) => self.update_inspector(msg),
             RuleEngineMessage::Explain(msg) => self.update_explain(msg),
+            RuleEngineMessage::RuleSet(msg) => self.update_rule_set(msg),
+            RuleEngineMessage::DryRun(msg) => self.update_dry_run(msg),
         }
     }
 
+    fn update_dry_run(&mut self, message: DryRunMessage) -> Task<RuleEngineMessage> {
+        match message {
+            DryRunMessage::Start => {
+                self.dry_run_saved = Some(self.rules.clone());
+                self.save_rules();
+            }
+            DryRunMessage::Save => {
+                self.dry_run_saved = None;
+                self.save_rule_set_schedule();
+            }
+            DryRunMessage::Discard => {
+                if let Some(saved) = self.dry_run_saved.take() {
+                    self.rules = saved;
+                    self.rule_set_form.reload(&self.rules);
+                    self.composite_form = tabs::CompositeRuleFormState::default();
+                }
+            }
+        }
+        Task::none()
+    }
+
     fn update_account(&mut self, message: AccountMessage) -> Task<RuleEngineMessage> {
         match message {
             AccountMessage::Select(id) => {
@@ -122,7 +275,7 @@ impl RuleEngineScreen {
             AccountMessage::ToggleEnabled(id, enabled) => {
                 if let Some(rule) = self.rules.account_rules.iter_mut().find(|r| r.id == id) {
                     rule.enabled = enabled;
-                    let _ = self.rules.save();
+                    self.save_rules();
                 }
             }
             AccountMessage::ToggleDay(id, day) => {
@@ -132,7 +285,7 @@ impl RuleEngineScreen {
                     } else {
                         rule.active_days.insert(day);
                     }
-                    let _ = self.rules.save();
+                    self.save_rules();
                 }
             }
             AccountMessage::SetTimeWindow(id, start_str, end_str) => {
@@ -141,7 +294,7 @@ impl RuleEngineScreen {
                     let end = end_str.and_then(|s| NaiveTime::parse_from_str(&s, "%H:%M").ok());
                     rule.start_time = start;
                     rule.end_time = end;
-                    let _ = self.rules.save();
+                    self.save_rules();
                 }
             }
             AccountMessage::SetTimeWindowExpanded(id, expanded) => {
@@ -154,7 +307,7 @@ impl RuleEngineScreen {
             AccountMessage::SetOutsideBehavior(id, behavior) => {
                 if let Some(rule) = self.rules.account_rules.iter_mut().find(|r| r.id == id) {
                     rule.outside_behavior = behavior;
-                    let _ = self.rules.save();
+                    self.save_rules();
                 }
             }
         }
@@ -167,19 +320,418 @@ impl RuleEngineScreen {
                 if let Some(rule) = self.rules.org_rules.iter_mut().find(|r| r.id == id) {
                     rule.enabled = enabled;
                 }
-                let _ = self.rules.save();
+                self.save_rules();
             }
             OrgMessage::Delete(id) => {
                 self.rules.org_rules.retain(|r| r.id != id);
-                let _ = self.rules.save();
+                self.save_rules();
             }
             OrgMessage::Duplicate(id) => {
                 if let Some(rule) = self.rules.org_rules.iter().find(|r| r.id == id).cloned() {
                     let mut new_rule = rule;
                     new_rule.id = uuid::Uuid::new_v4().to_string();
                     self.rules.org_rules.push(new_rule);
-                    let _ = self.rules.save();
+                    self.save_rules();
+                }
+            }
+        }
+        Task::none()
+    }
+
+    fn update_rule_set(&mut self, message: RuleSetMessage) -> Task<RuleEngineMessage> {
+        // Switching sets would drop a dry run's held-back edits, so those wait it
+        // out. The view disables them too; this also covers submitting the name.
+        let switches_set = match &message {
+            RuleSetMessage::Select(_) | RuleSetMessage::Create | RuleSetMessage::Duplicate => true,
+            RuleSetMessage::Delete(name) => *name == self.rules.name,
+            RuleSetMessage::Import => !self.rule_set_form.merge_import,
+            _ => false,
+        };
+        if switches_set && self.dry_run_saved.is_some() {
+            return Task::none();
+        }
+
+        match message {
+            RuleSetMessage::Select(name) => {
+                let mut store = RuleSetStore::load();
+                if store.select(&name) {
+                    let _ = store.save();
+                    self.switch_rule_set(store);
+                }
+            }
+            RuleSetMessage::Delete(name) => {
+                let mut store = RuleSetStore::load();
+                if store.remove(&name) {
+                    let _ = store.save();
+                    if name == self.rules.name {
+                        self.switch_rule_set(store);
+                    } else {
+                        self.rule_sets = store;
+                        TrayManager::refresh_rule_sets();
+                    }
+                }
+            }
+            RuleSetMessage::NewNameChanged(name) => {
+                self.rule_set_form.new_name = name;
+            }
+            RuleSetMessage::Create | RuleSetMessage::Duplicate => {
+                let name = self.rule_set_form.new_name.trim().to_string();
+                let mut store = RuleSetStore::load();
+                if name.is_empty() || store.get(&name).is_some() {
+                    return Task::none();
+                }
+
+                let set = if matches!(message, RuleSetMessage::Duplicate) {
+                    NotificationRuleSet {
+                        name: name.clone(),
+                        schedule: None,
+                        ..self.rules.clone()
+                    }
+                } else {
+                    NotificationRuleSet::named(name.clone())
+                };
+                store.upsert(set);
+                store.select(&name);
+                let _ = store.save();
+                self.switch_rule_set(store);
+            }
+            RuleSetMessage::RenameChanged(name) => {
+                self.rule_set_form.rename = name;
+            }
+            RuleSetMessage::Rename => {
+                let old = self.rules.name.clone();
+                let new = self.rule_set_form.rename.trim().to_string();
+                let mut store = RuleSetStore::load();
+                if store.rename(&old, &new) {
+                    let _ = store.save();
+                    // A dry run keeps going under the new name.
+                    if let Some(saved) = &mut self.dry_run_saved {
+                        saved.name = new.clone();
+                    }
+                    self.rules.name = new;
+                    self.rule_set_form.error = None;
+                    self.rule_sets = store;
+                    TrayManager::refresh_rule_sets();
+                } else if new != old {
+                    self.rule_set_form.error = Some(format!("\"{new}\" is empty or already taken"));
+                }
+            }
+            RuleSetMessage::ToggleSchedule(enabled) => {
+                self.rules.schedule.get_or_insert_default().enabled = enabled;
+                self.save_rule_set_schedule();
+            }
+            RuleSetMessage::ToggleDay(day) => {
+                let days = &mut self.rules.schedule.get_or_insert_default().active_days;
+                if !days.remove(&day) {
+                    days.insert(day);
+                }
+                self.save_rule_set_schedule();
+            }
+            RuleSetMessage::FilePathChanged(path) => {
+                self.rule_set_form.file_path = path;
+            }
+            RuleSetMessage::ToggleMerge(merge) => {
+                self.rule_set_form.merge_import = merge;
+            }
+            RuleSetMessage::Export => {
+                let path = expand_home(&self.rule_set_form.file_path);
+                self.rule_set_form.file_status = Some(
+                    self.rules
+                        .export_to(&path)
+                        .map(|()| format!("Exported \"{}\" to {}", self.rules.name, path.display()))
+                        .map_err(|e| e.to_string()),
+                );
+            }
+            RuleSetMessage::Import => {
+                let path = expand_home(&self.rule_set_form.file_path);
+                match NotificationRuleSet::import_from(&path) {
+                    Ok(imported) => self.import_rule_set(imported),
+                    Err(e) => self.rule_set_form.file_status = Some(Err(e.to_string())),
+                }
+            }
+            RuleSetMessage::StartChanged(value) => self.set_schedule_time(value, true),
+            RuleSetMessage::EndChanged(value) => self.set_schedule_time(value, false),
+        }
+        Task::none()
+    }
+
+    /// Merge an imported set into the edited one, or add it as a new set.
+    fn import_rule_set(&mut self, imported: NotificationRuleSet) {
+        if self.rule_set_form.merge_import {
+            let count = imported.active_rule_count();
+            self.rules.merge(imported);
+            self.save_rules();
+            self.rule_sets = RuleSetStore::load();
+            self.rule_set_form.file_status = Some(Ok(format!(
+                "Merged {count} rules into \"{}\"",
+                self.rules.name
+            )));
+            return;
+        }
+
+        let mut store = RuleSetStore::load();
+        let base = match imported.name.trim() {
+            "" => "Imported".to_string(),
+            name => name.to_string(),
+        };
+        let name = std::iter::once(base.clone())
+            .chain((2..).map(|n| format!("{base} ({n})")))
+            .find(|candidate| store.get(candidate).is_none())
+            .unwrap_or(base);
+
+        store.upsert(NotificationRuleSet {
+            name: name.clone(),
+            ..imported
+        });
+        store.select(&name);
+        let _ = store.save();
+        self.switch_rule_set(store);
+        self.rule_set_form.file_status = Some(Ok(format!("Imported as \"{name}\"")));
+    }
+
+    /// Record a typed schedule time, applying it once it parses.
+    fn set_schedule_time(&mut self, value: String, is_start: bool) {
+        let parsed = NaiveTime::parse_from_str(&value, "%H:%M");
+        if is_start {
+            self.rule_set_form.schedule_start = value;
+        } else {
+            self.rule_set_form.schedule_end = value;
+        }
+
+        match parsed {
+            Ok(time) => {
+                let schedule = self.rules.schedule.get_or_insert_default();
+                if is_start {
+                    schedule.start_time = time;
+                } else {
+                    schedule.end_time = time;
+                }
+                self.rule_set_form.error = None;
+                self.save_rule_set_schedule();
+            }
+            Err(_) => {
+                self.rule_set_form.error = Some("Times use HH:MM, e.g. 22:00".to_string());
+            }
+        }
+    }
+
+    /// Load the manually selected set of `store` into the editor. Callers wait out
+    /// a dry run first, so no held-back edits are lost.
+    fn switch_rule_set(&mut self, store: RuleSetStore) {
+        let rules = store
+            .get(&store.active)
+            .cloned()
+            .unwrap_or_else(|| store.sets[0].clone());
+        self.rules = with_account_rules(rules, &self.accounts);
+        self.rule_set_form.reload(&self.rules);
+        self.rule_sets = store;
+        self.selected_rule_id = None;
+        self.composite_form = tabs::CompositeRuleFormState::default();
+        TrayManager::refresh_rule_sets();
+    }
+
+    /// Schedules change which set is in force, so the tray needs to follow.
+    fn save_rule_set_schedule(&mut self) {
+        self.save_rules();
+        self.rule_sets = RuleSetStore::load();
+        TrayManager::refresh_rule_sets();
+    }
+
+    fn update_repo(&mut self, message: RepoMessage) -> Task<RuleEngineMessage> {
+        match message {
+            RepoMessage::Toggle(id, enabled) => {
+                if let Some(rule) = self.rules.repo_rules.iter_mut().find(|r| r.id == id) {
+                    rule.enabled = enabled;
+                }
+                self.save_rules();
+            }
+            RepoMessage::Delete(id) => {
+                self.rules.repo_rules.retain(|r| r.id != id);
+                self.save_rules();
+            }
+            RepoMessage::Duplicate(id) => {
+                if let Some(rule) = self.rules.repo_rules.iter().find(|r| r.id == id).cloned() {
+                    let mut new_rule = rule;
+                    new_rule.id = uuid::Uuid::new_v4().to_string();
+                    self.rules.repo_rules.push(new_rule);
+                    self.save_rules();
+                }
+            }
+            RepoMessage::FormRepoChanged(s) => {
+                self.new_repo_rule.repo = s;
+            }
+            RepoMessage::FormPriorityChanged(p) => {
+                self.new_repo_rule.priority = p;
+            }
+            RepoMessage::FormActionChanged(a) => {
+                self.new_repo_rule.action = a;
+            }
+            RepoMessage::FormVisibilityChanged(v) => {
+                self.new_repo_rule.visibility = v;
+            }
+            RepoMessage::Add => {
+                let repo = self.new_repo_rule.repo.trim();
+                if repo.is_empty() {
+                    return Task::none();
+                }
+
+                let mut rule = RepoRule::new(repo, self.new_repo_rule.priority);
+                rule.action = self.new_repo_rule.action;
+                rule.visibility = self.new_repo_rule.visibility;
+
+                self.rules.repo_rules.push(rule);
+                self.save_rules();
+
+                // Reset form
+                self.new_repo_rule = tabs::RepoRuleFormState::default();
+            }
+        }
+        Task::none()
+    }
+
+    fn update_title(&mut self, message: TitleMessage) -> Task<RuleEngineMessage> {
+        match message {
+            TitleMessage::Toggle(id, enabled) => {
+                if let Some(rule) = self.rules.title_rules.iter_mut().find(|r| r.id == id) {
+                    rule.enabled = enabled;
+                }
+                self.save_rules();
+            }
+            TitleMessage::Delete(id) => {
+                self.rules.title_rules.retain(|r| r.id != id);
+                self.save_rules();
+            }
+            TitleMessage::Duplicate(id) => {
+                if let Some(rule) = self.rules.title_rules.iter().find(|r| r.id == id).cloned() {
+                    let mut new_rule = rule;
+                    new_rule.id = uuid::Uuid::new_v4().to_string();
+                    self.rules.title_rules.push(new_rule);
+                    self.save_rules();
+                }
+            }
+            TitleMessage::FormPatternChanged(s) => {
+                self.new_title_rule.pattern = s;
+            }
+            TitleMessage::FormModeChanged(m) => {
+                self.new_title_rule.match_mode = m;
+            }
+            TitleMessage::FormCaseSensitiveChanged(c) => {
+                self.new_title_rule.case_sensitive = c;
+            }
+            TitleMessage::FormPriorityChanged(p) => {
+                self.new_title_rule.priority = p;
+            }
+            TitleMessage::FormActionChanged(a) => {
+                self.new_title_rule.action = a;
+            }
+            TitleMessage::Add => {
+                let form = &self.new_title_rule;
+                let pattern = form.pattern.trim();
+                if pattern.is_empty() {
+                    return Task::none();
+                }
+
+                let mut rule = TitleRule::new(pattern, form.match_mode, form.priority);
+                rule.case_sensitive = form.case_sensitive;
+                rule.action = form.action;
+                // Submitting with Enter bypasses the disabled button.
+                if rule.validate().is_err() {
+                    return Task::none();
+                }
+
+                self.rules.title_rules.push(rule);
+                self.save_rules();
+
+                // Reset form
+                self.new_title_rule = tabs::TitleRuleFormState::default();
+            }
+        }
+        Task::none()
+    }
+
+    fn update_composite(&mut self, message: CompositeMessage) -> Task<RuleEngineMessage> {
+        match message {
+            CompositeMessage::Toggle(id, enabled) => {
+                if let Some(rule) = self.rules.composite_rules.iter_mut().find(|r| r.id == id) {
+                    rule.enabled = enabled;
+                }
+                self.save_rules();
+            }
+            CompositeMessage::Delete(id) => {
+                self.rules.composite_rules.retain(|r| r.id != id);
+                if self.composite_form.editing_id.as_deref() == Some(id.as_str()) {
+                    self.composite_form = tabs::CompositeRuleFormState::default();
+                }
+                self.save_rules();
+            }
+            CompositeMessage::Duplicate(id) => {
+                if let Some(rule) = self
+                    .rules
+                    .composite_rules
+                    .iter()
+                    .find(|r| r.id == id)
+                    .cloned()
+                {
+                    let mut new_rule = rule;
+                    new_rule.id = uuid::Uuid::new_v4().to_string();
+                    self.rules.composite_rules.push(new_rule);
+                    self.save_rules();
+                }
+            }
+            CompositeMessage::Edit(id) => {
+                if let Some(rule) = self.rules.composite_rules.iter().find(|r| r.id == id) {
+                    self.composite_form = tabs::CompositeRuleFormState::from_rule(rule);
+                    self.selected_tab = RuleTab::CompositeRules;
+                }
+            }
+            CompositeMessage::CancelEdit => {
+                self.composite_form = tabs::CompositeRuleFormState::default();
+            }
+            CompositeMessage::FormNameChanged(s) => {
+                self.composite_form.name = s;
+            }
+            CompositeMessage::FormConditionEdited(action) => {
+                self.composite_form.condition.perform(action);
+                self.composite_form.error = None;
+            }
+            CompositeMessage::FormPriorityChanged(p) => {
+                self.composite_form.priority = p;
+            }
+            CompositeMessage::FormActionChanged(a) => {
+                self.composite_form.action = a;
+            }
+            CompositeMessage::Save => {
+                let form = &self.composite_form;
+                let name = form.name.trim();
+                if name.is_empty() {
+                    return Task::none();
+                }
+                let condition = match form.parse_condition() {
+                    Ok(condition) => condition,
+                    Err(e) => {
+                        self.composite_form.error = Some(e);
+                        return Task::none();
+                    }
+                };
+
+                let existing = form
+                    .editing_id
+                    .as_ref()
+                    .and_then(|id| self.rules.composite_rules.iter_mut().find(|r| &r.id == id));
+                if let Some(rule) = existing {
+                    rule.name = name.to_string();
+                    rule.condition = condition;
+                    rule.priority = form.priority;
+                    rule.action = form.action;
+                } else {
+                    let mut rule = CompositeRule::new(name, condition, form.priority);
+                    rule.action = form.action;
+                    self.rules.composite_rules.push(rule);
                 }
+                self.save_rules();
+
+                // Reset form
+                self.composite_form = tabs::CompositeRuleFormState::default();
             }
         }
         Task::none()
@@ -191,18 +743,18 @@ impl RuleEngineScreen {
                 if let Some(rule) = self.rules.type_rules.iter_mut().find(|r| r.id == id) {
                     rule.enabled = enabled;
                 }
-                let _ = self.rules.save();
+                self.save_rules();
             }
             TypeMessage::Delete(id) => {
                 self.rules.type_rules.retain(|r| r.id != id);
-                let _ = self.rules.save();
+                self.save_rules();
             }
             TypeMessage::Duplicate(id) => {
                 if let Some(rule) = self.rules.type_rules.iter().find(|r| r.id == id).cloned() {
                     let mut new_rule = rule;
                     new_rule.id = uuid::Uuid::new_v4().to_string();
                     self.rules.type_rules.push(new_rule);
-                    let _ = self.rules.save();
+                    self.save_rules();
                 }
             }
             TypeMessage::ToggleGroup(group_name) => {
@@ -215,6 +767,9 @@ impl RuleEngineScreen {
             TypeMessage::FormTypeChanged(s) => {
                 self.new_type_rule_type = s;
             }
+            TypeMessage::FormSubjectTypeChanged(s) => {
+                self.new_type_rule_subject = s;
+            }
             TypeMessage::FormAccountChanged(s) => {
                 self.new_type_rule_account = if s == "Global" || s.trim().is_empty() {
                     None
@@ -232,11 +787,17 @@ impl RuleEngineScreen {
                 let priority = self.new_type_rule_priority;
                 let account = self.new_type_rule_account.clone();
 
-                let mut rule = TypeRule::new(self.new_type_rule_type.label(), account, priority);
+                let (reason, subject_type) = (self.new_type_rule_type, self.new_type_rule_subject);
+                // A rule without either would match every notification.
+                if reason.is_none() && subject_type.is_none() {
+                    return Task::none();
+                }
+
+                let mut rule = TypeRule::new(reason, subject_type, account, priority);
                 rule.action = self.new_type_rule_action;
 
                 self.rules.type_rules.push(rule);
-                let _ = self.rules.save();
+                self.save_rules();
 
                 // Reset form
                 self.new_type_rule_account = None;
@@ -247,6 +808,25 @@ impl RuleEngineScreen {
         Task::none()
     }
 
+    /// Show a rule in the inspector, on the tab that lists it.
+    pub fn inspect_rule(&mut self, rule_id: String) {
+        use super::inspector::{FoundRule, find_rule_by_id};
+
+        self.selected_tab = match find_rule_by_id(&self.rules, &rule_id) {
+            Some(FoundRule::Account(_)) => {
+                self.selected_account_id = Some(rule_id.clone());
+                RuleTab::AccountRules
+            }
+            Some(FoundRule::Org(_)) => RuleTab::OrgRules,
+            Some(FoundRule::Repo(_)) => RuleTab::RepoRules,
+            Some(FoundRule::Title(_)) => RuleTab::TitleRules,
+            Some(FoundRule::Composite(_)) => RuleTab::CompositeRules,
+            Some(FoundRule::Type(_)) => RuleTab::TypeRules,
+            None => RuleTab::Overview,
+        };
+        self.selected_rule_id = Some(rule_id);
+    }
+
     fn update_inspector(&mut self, message: InspectorMessage) -> Task<RuleEngineMessage> {
         match message {
             InspectorMessage::Select(rule_id) => {
@@ -264,6 +844,12 @@ impl RuleEngineScreen {
             ExplainMessage::SetTestType(test_type) => {
                 self.explain_test_type = test_type;
             }
+            ExplainMessage::SetTestRepo(test_repo) => {
+                self.explain_test_repo = test_repo;
+            }
+            ExplainMessage::SetTestTitle(test_title) => {
+                self.explain_test_title = test_title;
+            }
         }
         Task::none()
     }
@@ -278,18 +864,29 @@ impl RuleEngineScreen {
         let content = self.view_tab_content();
 
         // Build main area with optional inspector
-        let main_area = if let Some(ref rule_id) = self.selected_rule_id {
-            let inspector = super::inspector::view_inspector(&self.rules, rule_id, self.icon_theme);
-            row![sidebar, content, inspector].height(Fill)
-        } else {
-            row![sidebar, content].height(Fill)
-        };
+        let mut main_area = row![sidebar, content].height(Fill);
+        if let Some(ref rule_id) = self.selected_rule_id {
+            main_area = main_area.push(super::inspector::view_inspector(
+                &self.rules,
+                rule_id,
+                self.rule_hits.get(rule_id),
+                self.icon_theme,
+            ));
+        }
+        if self.dry_run_saved.is_some() {
+            main_area = main_area.push(super::dry_run::view_dry_run(
+                &self.dry_run_report,
+                self.leave_requested,
+                self.icon_theme,
+            ));
+        }
 
-        let base_layout: Element<'_, RuleEngineMessage> = column![header, main_area]
-            .spacing(0)
-            .width(Fill)
-            .height(Fill)
-            .into();
+        let base_layout: Element<'_, RuleEngineMessage> =
+            column![header, self.view_load_error(), main_area]
+                .spacing(0)
+                .width(Fill)
+                .height(Fill)
+                .into();
 
         // Overlay handbook modal if visible
         if self.show_handbook {
@@ -303,6 +900,64 @@ impl RuleEngineScreen {
         }
     }
 
+    /// Banner explaining why the stored rules were replaced by defaults.
+    fn view_load_error(&self) -> Element<'_, RuleEngineMessage> {
+        let Some(error) = &self.load_error else {
+            return Space::new().height(0).into();
+        };
+        let p = theme::palette();
+
+        let (hint, restore): (String, Element<'_, RuleEngineMessage>) = if self.backup_available {
+            (
+                format!(
+                    "{error}. A copy was kept as rule_sets.json.bak; fix it there, then restore it."
+                ),
+                button(text("Restore").size(12))
+                    .style(theme::primary_button)
+                    .padding([6, 14])
+                    .on_press_maybe(
+                        self.dry_run_saved
+                            .is_none()
+                            .then_some(RuleEngineMessage::RestoreBackup),
+                    )
+                    .into(),
+            )
+        } else {
+            (
+                format!("{error}. The file is left untouched, so it can be fixed in place."),
+                Space::new().width(0).into(),
+            )
+        };
+
+        container(
+            row![
+                icons::icon_alert(14.0, p.accent_warning, self.icon_theme),
+                Space::new().width(8),
+                column![
+                    text("Your saved rules couldn't be loaded, so defaults are shown.")
+                        .size(13)
+                        .color(p.text_primary),
+                    text(hint).size(12).color(p.text_secondary),
+                ]
+                .spacing(2),
+                Space::new().width(Fill),
+                restore,
+                button(icons::icon_x(14.0, p.text_secondary, self.icon_theme))
+                    .style(theme::ghost_button)
+                    .padding(4)
+                    .on_press(RuleEngineMessage::DismissLoadError),
+            ]
+            .align_y(Alignment::Center),
+        )
+        .padding([10, 16])
+        .width(Fill)
+        .style(move |_| container::Style {
+            background: Some(iced::Background::Color(p.accent_warning.scale_alpha(0.12))),
+            ..Default::default()
+        })
+        .into()
+    }
+
     fn view_handbook_modal(&self) -> Element<'_, RuleEngineMessage> {
         let p = theme::palette();
 
@@ -499,6 +1154,41 @@ it is treated as Important regardless of other rules."
         .padding([6, 10])
         .on_press(RuleEngineMessage::ToggleHandbook);
 
+        // Switching sets would drop held-back edits, so the picker waits out a dry run.
+        let rule_set_picker: Element<'_, RuleEngineMessage> = if self.dry_run_saved.is_some() {
+            text(format!("{} (dry run)", self.rules.name))
+                .size(12)
+                .color(p.text_secondary)
+                .into()
+        } else {
+            pick_list(
+                self.rule_sets.names(),
+                Some(self.rules.name.clone()),
+                |name| RuleEngineMessage::RuleSet(RuleSetMessage::Select(name)),
+            )
+            .text_size(12)
+            .padding([4, 8])
+            .style(theme::pick_list_style)
+            .menu_style(theme::menu_style)
+            .into()
+        };
+
+        let dry_run_btn = button(
+            row![
+                icons::icon_zap(16.0, p.text_secondary, self.icon_theme),
+                Space::new().width(4),
+                text("Dry run").size(12).color(p.text_secondary),
+            ]
+            .align_y(Alignment::Center),
+        )
+        .style(theme::ghost_button)
+        .padding([6, 10])
+        .on_press_maybe(
+            self.dry_run_saved
+                .is_none()
+                .then_some(RuleEngineMessage::DryRun(DryRunMessage::Start)),
+        );
+
         let enabled_toggle = row![
             text("Enabled").size(12).color(p.text_secondary),
             Space::new().width(8),
@@ -512,7 +1202,11 @@ it is treated as Important regardless of other rules."
             back_btn,
             Space::new().width(16),
             title,
+            Space::new().width(16),
+            rule_set_picker,
             Space::new().width(Fill),
+            dry_run_btn,
+            Space::new().width(8),
             help_btn,
             Space::new().width(16),
             enabled_toggle,
@@ -555,11 +1249,31 @@ it is treated as Important regardless of other rules."
                 RuleTab::OrgRules,
                 icons::icon_building(icon_size, self.nav_icon_color(RuleTab::OrgRules), t)
             ),
+            self.view_nav_item(
+                "Repositories",
+                RuleTab::RepoRules,
+                icons::icon_repo(icon_size, self.nav_icon_color(RuleTab::RepoRules), t)
+            ),
+            self.view_nav_item(
+                "Titles",
+                RuleTab::TitleRules,
+                icons::icon_title(icon_size, self.nav_icon_color(RuleTab::TitleRules), t)
+            ),
+            self.view_nav_item(
+                "Composite",
+                RuleTab::CompositeRules,
+                icons::icon_composite(icon_size, self.nav_icon_color(RuleTab::CompositeRules), t)
+            ),
             self.view_nav_item(
                 "Types",
                 RuleTab::TypeRules,
                 icons::icon_tag(icon_size, self.nav_icon_color(RuleTab::TypeRules), t)
             ),
+            self.view_nav_item(
+                "Rule Sets",
+                RuleTab::RuleSets,
+                icons::icon_clock(icon_size, self.nav_icon_color(RuleTab::RuleSets), t)
+            ),
         ]
         .spacing(4)
         .padding([16, 8]);
@@ -630,7 +1344,15 @@ it is treated as Important regardless of other rules."
 
         match self.selected_tab {
             RuleTab::Overview => {
-                let content = tabs::view_overview_tab(&self.rules, t, &self.explain_test_type);
+                let content = tabs::view_overview_tab(
+                    &self.rules,
+                    t,
+                    &self.explain_test_type,
+                    &self.explain_test_repo,
+                    &self.explain_test_title,
+                    &self.deferred_queue,
+                    &self.rule_hits,
+                );
                 container(
                     scrollable(content)
                         .width(Fill)
@@ -679,12 +1401,61 @@ it is treated as Important regardless of other rules."
                 })
                 .into()
             }
+            RuleTab::RepoRules => {
+                let content = tabs::view_repo_rules_tab(&self.rules, t, &self.new_repo_rule);
+                container(
+                    scrollable(content)
+                        .width(Fill)
+                        .height(Fill)
+                        .style(theme::scrollbar),
+                )
+                .width(Fill)
+                .height(Fill)
+                .style(move |_| container::Style {
+                    background: Some(iced::Background::Color(p.bg_base)),
+                    ..Default::default()
+                })
+                .into()
+            }
+            RuleTab::TitleRules => {
+                let content = tabs::view_title_rules_tab(&self.rules, t, &self.new_title_rule);
+                container(
+                    scrollable(content)
+                        .width(Fill)
+                        .height(Fill)
+                        .style(theme::scrollbar),
+                )
+                .width(Fill)
+                .height(Fill)
+                .style(move |_| container::Style {
+                    background: Some(iced::Background::Color(p.bg_base)),
+                    ..Default::default()
+                })
+                .into()
+            }
+            RuleTab::CompositeRules => {
+                let content = tabs::view_composite_rules_tab(&self.rules, t, &self.composite_form);
+                container(
+                    scrollable(content)
+                        .width(Fill)
+                        .height(Fill)
+                        .style(theme::scrollbar),
+                )
+                .width(Fill)
+                .height(Fill)
+                .style(move |_| container::Style {
+                    background: Some(iced::Background::Color(p.bg_base)),
+                    ..Default::default()
+                })
+                .into()
+            }
             RuleTab::TypeRules => {
                 let content = tabs::view_type_rules_tab(
                     &self.rules,
                     t,
                     &tabs::TypeRuleFormState {
-                        notification_type: self.new_type_rule_type,
+                        reason: self.new_type_rule_type,
+                        subject_type: self.new_type_rule_subject,
                         account: self.new_type_rule_account.clone(),
                         priority: self.new_type_rule_priority,
                         action: self.new_type_rule_action,
@@ -706,6 +1477,51 @@ it is treated as Important regardless of other rules."
                 })
                 .into()
             }
+            RuleTab::RuleSets => {
+                let content = tabs::view_rule_sets_tab(
+                    &self.rules,
+                    &self.rule_sets,
+                    t,
+                    &self.rule_set_form,
+                    self.dry_run_saved.is_some(),
+                );
+                container(
+                    scrollable(content)
+                        .width(Fill)
+                        .height(Fill)
+                        .style(theme::scrollbar),
+                )
+                .width(Fill)
+                .height(Fill)
+                .style(move |_| container::Style {
+                    background: Some(iced::Background::Color(p.bg_base)),
+                    ..Default::default()
+                })
+                .into()
+            }
         }
     }
 }
+
+/// Ensure every signed-in account has a rule entry.
+fn with_account_rules(mut rules: NotificationRuleSet, accounts: &[String]) -> NotificationRuleSet {
+    for account in accounts {
+        if !rules
+            .account_rules
+            .iter()
+            .any(|r| r.account.eq_ignore_ascii_case(account))
+        {
+            rules.account_rules.push(AccountRule::new(account));
+        }
+    }
+    rules
+}
+
+/// Expand a leading `~` to the home directory.
+fn expand_home(path: &str) -> std::path::PathBuf {
+    let path = path.trim();
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => std::path::PathBuf::from(path),
+    }
+}
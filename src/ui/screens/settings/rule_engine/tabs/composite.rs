@@ -0,0 +1,239 @@
+//! Composite Rules tab for Rule Engine.
+
+use iced::widget::{
+    Space, button, column, container, pick_list, row, slider, text, text_editor, text_input,
+};
+use iced::{Alignment, Element, Fill, Length};
+
+use crate::settings::IconTheme;
+use crate::ui::icons;
+use crate::ui::screens::settings::rule_engine::rules::{
+    CompositeRule, Condition, NotificationRuleSet, RuleAction,
+};
+use crate::ui::theme;
+
+use super::super::components::{view_composite_rule_card, view_empty_state};
+use super::super::messages::{CompositeMessage, RuleEngineMessage};
+
+/// Condition shown in the editor for a new rule.
+const CONDITION_TEMPLATE: &str = r#"{
+  "all": [
+    { "reason": "review_requested" },
+    { "org": "acme" },
+    { "subject_type": "PullRequest" }
+  ]
+}"#;
+
+/// State for the composite rule editor.
+#[derive(Debug, Clone)]
+pub struct CompositeRuleFormState {
+    /// Rule being edited, or `None` when creating a new one.
+    pub editing_id: Option<String>,
+    pub name: String,
+    /// Condition tree as JSON.
+    pub condition: text_editor::Content,
+    pub priority: i32,
+    pub action: RuleAction,
+    /// Why the last save was rejected.
+    pub error: Option<String>,
+}
+
+impl Default for CompositeRuleFormState {
+    fn default() -> Self {
+        Self {
+            editing_id: None,
+            name: String::new(),
+            condition: text_editor::Content::with_text(CONDITION_TEMPLATE),
+            priority: 0,
+            action: RuleAction::Show,
+            error: None,
+        }
+    }
+}
+
+impl CompositeRuleFormState {
+    /// Load an existing rule into the editor.
+    pub fn from_rule(rule: &CompositeRule) -> Self {
+        let json = serde_json::to_string_pretty(&rule.condition).unwrap_or_default();
+        Self {
+            editing_id: Some(rule.id.clone()),
+            name: rule.name.clone(),
+            condition: text_editor::Content::with_text(&json),
+            priority: rule.priority,
+            action: rule.action,
+            error: None,
+        }
+    }
+
+    /// Parse and validate the condition JSON.
+    pub fn parse_condition(&self) -> Result<Condition, String> {
+        let condition: Condition =
+            serde_json::from_str(&self.condition.text()).map_err(|e| e.to_string())?;
+        condition.validate()?;
+        Ok(condition)
+    }
+}
+
+pub fn view_composite_rules_tab<'a>(
+    rules: &'a NotificationRuleSet,
+    icon_theme: IconTheme,
+    form_state: &'a CompositeRuleFormState,
+) -> Element<'a, RuleEngineMessage> {
+    let p = theme::palette();
+
+    // ========================================================================
+    // Form Section
+    // ========================================================================
+    let name_input = container(
+        column![
+            text("Name").size(12).color(p.text_secondary),
+            text_input("e.g. Acme PR reviews", &form_state.name)
+                .on_input(|s| RuleEngineMessage::Composite(CompositeMessage::FormNameChanged(s)))
+                .padding(6)
+                .size(13)
+                .style(theme::text_input_style)
+                .width(Length::Fixed(220.0)),
+        ]
+        .spacing(4),
+    );
+
+    let priority_input = container(
+        column![
+            row![
+                text("Priority").size(12).color(p.text_secondary),
+                Space::new().width(8),
+                text(format!("{}", form_state.priority))
+                    .size(12)
+                    .color(p.text_primary),
+            ]
+            .align_y(Alignment::Center),
+            slider(-100..=100, form_state.priority, |p| {
+                RuleEngineMessage::Composite(CompositeMessage::FormPriorityChanged(p))
+            })
+            .width(Length::Fixed(150.0)),
+        ]
+        .spacing(4),
+    );
+
+    // Action Input with Warning
+    let action_label_row = if form_state.action == RuleAction::Hide {
+        row![
+            text("Action").size(12).color(p.text_secondary),
+            Space::new().width(4),
+            icons::icon_alert(12.0, p.accent_warning, icon_theme),
+        ]
+        .align_y(Alignment::Center)
+    } else {
+        row![text("Action").size(12).color(p.text_secondary)]
+    };
+
+    let action_input = container(
+        column![
+            action_label_row,
+            pick_list(RuleAction::ALL, Some(form_state.action), |a| {
+                RuleEngineMessage::Composite(CompositeMessage::FormActionChanged(a))
+            })
+            .width(Length::Fixed(100.0))
+            .style(theme::pick_list_style)
+            .menu_style(theme::menu_style),
+        ]
+        .spacing(4),
+    );
+
+    let editing = form_state.editing_id.is_some();
+    let can_save = !form_state.name.trim().is_empty();
+    let save_btn = button(text(if editing { "Save Rule" } else { "Add Rule" }).size(13))
+        .style(theme::primary_button)
+        .on_press_maybe(can_save.then_some(RuleEngineMessage::Composite(CompositeMessage::Save)))
+        .padding([8, 16]);
+
+    let mut buttons = row![].spacing(8);
+    if editing {
+        buttons = buttons.push(
+            button(text("Cancel").size(13))
+                .style(theme::ghost_button)
+                .on_press(RuleEngineMessage::Composite(CompositeMessage::CancelEdit))
+                .padding([8, 16]),
+        );
+    }
+    buttons = buttons.push(save_btn);
+
+    let form_row = row![
+        name_input,
+        priority_input,
+        action_input,
+        Space::new().width(Fill),
+        column![Space::new().height(19), buttons].spacing(0),
+    ]
+    .spacing(12)
+    .align_y(Alignment::End);
+
+    let condition_editor = column![
+        text("Condition (JSON)").size(12).color(p.text_secondary),
+        text_editor(&form_state.condition)
+            .on_action(|a| RuleEngineMessage::Composite(CompositeMessage::FormConditionEdited(a)))
+            .font(iced::Font::MONOSPACE)
+            .size(12)
+            .padding(8)
+            .height(Length::Fixed(180.0))
+            .style(theme::text_editor_style),
+        text(
+            "Combine all / any / not over reason, subject_type, org, repo, account, title, \
+             title_regex, private and time_window."
+        )
+        .size(11)
+        .color(p.text_muted),
+    ]
+    .spacing(4);
+
+    let mut form_column = column![form_row, Space::new().height(12), condition_editor];
+    if let Some(error) = &form_state.error {
+        form_column = form_column.push(Space::new().height(8));
+        form_column = form_column.push(
+            row![
+                icons::icon_alert(12.0, p.accent_warning, icon_theme),
+                Space::new().width(4),
+                text(error.clone()).size(11).color(p.accent_warning),
+            ]
+            .align_y(Alignment::Center),
+        );
+    }
+
+    let form_section = container(form_column)
+        .padding(16)
+        .style(move |_| container::Style {
+            background: Some(iced::Background::Color(p.bg_control)),
+            border: iced::Border {
+                radius: 8.0.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+    let rules_list: Element<_> = if rules.composite_rules.is_empty() {
+        view_empty_state("No composite rules configured.", icon_theme)
+    } else {
+        column(rules.composite_rules.iter().flat_map(|rule| {
+            [
+                view_composite_rule_card(rule, icon_theme),
+                Space::new().height(8).into(),
+            ]
+        }))
+        .into()
+    };
+
+    column![
+        text("Composite Rules").size(20).color(p.text_primary),
+        text("Match on several attributes at once, e.g. review requests on Acme pull requests.")
+            .size(12)
+            .color(p.text_secondary),
+        Space::new().height(16),
+        form_section,
+        Space::new().height(24),
+        rules_list,
+    ]
+    .spacing(4)
+    .padding(24)
+    .width(Fill)
+    .into()
+}
@@ -1,9 +1,17 @@
 mod account_rules;
+mod composite;
 mod org;
 mod overview;
+mod repo;
+mod rule_sets;
+mod title;
 mod type_rules;
 
 pub use account_rules::view_account_rules_tab;
+pub use composite::{CompositeRuleFormState, view_composite_rules_tab};
 pub use org::view_org_rules_tab;
 pub use overview::view_overview_tab;
+pub use repo::{RepoRuleFormState, view_repo_rules_tab};
+pub use rule_sets::{RuleSetFormState, view_rule_sets_tab};
+pub use title::{TitleRuleFormState, view_title_rules_tab};
 pub use type_rules::{TypeRuleFormState, view_type_rules_tab};
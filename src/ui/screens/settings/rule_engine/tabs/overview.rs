@@ -1,19 +1,33 @@
 //! Overview tab for Rule Engine - System health and high-impact rules.
 
-use iced::widget::{Space, button, column, container, row, text};
+use iced::widget::{Space, button, column, container, row, text, text_input};
 use iced::{Element, Fill, Length};
 
+use std::collections::HashMap;
+
+use chrono::{Duration, Utc};
+
+use crate::cache::RuleHitStats;
+use crate::github::NotificationView;
+use crate::github::types::format_time_ago;
 use crate::settings::IconTheme;
 use crate::ui::icons;
-use crate::ui::screens::settings::rule_engine::rules::NotificationRuleSet;
+use crate::ui::screens::settings::rule_engine::rules::{NotificationRuleSet, RuleContext};
 use crate::ui::theme;
 
-use super::super::messages::{ExplainMessage, RuleEngineMessage};
+use super::super::messages::{ExplainMessage, InspectorMessage, RuleEngineMessage};
+
+/// Rules without a hit for this long are listed as stale.
+const STALE_AFTER_DAYS: i64 = 30;
 
 pub fn view_overview_tab(
     rules: &NotificationRuleSet,
     icon_theme: IconTheme,
     explain_test_type: &str,
+    explain_test_repo: &str,
+    explain_test_title: &str,
+    deferred_queue: &[NotificationView],
+    rule_hits: &HashMap<String, RuleHitStats>,
 ) -> Element<'static, RuleEngineMessage> {
     let p = theme::palette();
 
@@ -144,6 +158,18 @@ pub fn view_overview_tab(
         Space::new().width(12),
         dist_divider(),
         Space::new().width(12),
+        dist_item("Repo", rules.repo_rules.len()),
+        Space::new().width(12),
+        dist_divider(),
+        Space::new().width(12),
+        dist_item("Title", rules.title_rules.len()),
+        Space::new().width(12),
+        dist_divider(),
+        Space::new().width(12),
+        dist_item("Composite", rules.composite_rules.len()),
+        Space::new().width(12),
+        dist_divider(),
+        Space::new().width(12),
         dist_item("Type", rules.type_rules.len()),
     ]
     .align_y(iced::Alignment::Center);
@@ -190,8 +216,37 @@ pub fn view_overview_tab(
         type_buttons = type_buttons.push(btn);
     }
 
+    let repo_input = text_input("Repository (optional), e.g. owner/repo", explain_test_repo)
+        .on_input(|s| RuleEngineMessage::Explain(ExplainMessage::SetTestRepo(s)))
+        .padding(6)
+        .size(12)
+        .style(theme::text_input_style)
+        .width(Length::Fixed(280.0));
+
+    let title_input = text_input(
+        "Title (optional), e.g. chore(deps): bump",
+        explain_test_title,
+    )
+    .on_input(|s| RuleEngineMessage::Explain(ExplainMessage::SetTestTitle(s)))
+    .padding(6)
+    .size(12)
+    .style(theme::text_input_style)
+    .width(Length::Fixed(280.0));
+
+    let test_repo = Some(explain_test_repo.trim()).filter(|r| !r.is_empty());
+    let context = RuleContext {
+        repo_owner: test_repo.and_then(|repo| repo.split('/').next()),
+        repo_full_name: test_repo,
+        title: Some(explain_test_title).filter(|t| !t.trim().is_empty()),
+        account: None,
+        reason: NotificationReason::ALL
+            .iter()
+            .copied()
+            .find(|r| r.label() == type_owned),
+        ..Default::default()
+    };
     let explain_panel =
-        super::super::explain_decision::view_explain_panel(rules, &type_owned, None, icon_theme);
+        super::super::explain_decision::view_explain_panel(rules, &context, icon_theme);
 
     let test_lab = container(
         column![
@@ -213,6 +268,8 @@ pub fn view_overview_tab(
                 .color(p.text_secondary),
             Space::new().height(20),
             type_buttons,
+            Space::new().height(12),
+            row![repo_input, title_input].spacing(12),
             Space::new().height(24),
             explain_panel,
         ]
@@ -285,6 +342,161 @@ pub fn view_overview_tab(
     ]
     .width(Length::Fixed(240.0));
 
+    // ========================================================================
+    // 5. Deferred Queue
+    // ========================================================================
+
+    let queue_content: Element<'static, RuleEngineMessage> = if deferred_queue.is_empty() {
+        text("Nothing waiting for a schedule to open.")
+            .size(12)
+            .color(p.text_muted)
+            .into()
+    } else {
+        let mut items = column(deferred_queue.iter().take(6).map(|n| {
+            row![
+                icons::icon_clock(12.0, p.text_secondary, icon_theme),
+                Space::new().width(8),
+                column![
+                    text(n.title.clone()).size(13).color(p.text_primary),
+                    text(format!("{} · {}", n.account, n.repo_full_name))
+                        .size(11)
+                        .color(p.text_muted)
+                ]
+            ]
+            .align_y(iced::Alignment::Start)
+            .padding(4)
+            .into()
+        }))
+        .spacing(8);
+
+        if deferred_queue.len() > 6 {
+            items = items.push(
+                text(format!("...and {} more", deferred_queue.len() - 6))
+                    .size(11)
+                    .color(p.text_muted),
+            );
+        }
+
+        column![
+            items,
+            Space::new().height(8),
+            button(text("Clear queue").size(12))
+                .style(theme::ghost_button)
+                .padding([4, 8])
+                .on_press(RuleEngineMessage::ClearDeferred),
+        ]
+        .into()
+    };
+
+    let deferred_section = column![
+        text(format!("DEFERRED ({})", deferred_queue.len()))
+            .size(11)
+            .color(p.text_muted)
+            .font(iced::Font {
+                weight: iced::font::Weight::Bold,
+                ..Default::default()
+            }),
+        Space::new().height(4),
+        text("Delivered as one digest when the account's window opens.")
+            .size(11)
+            .color(p.text_secondary),
+        Space::new().height(12),
+        queue_content
+    ]
+    .width(Length::Fixed(240.0));
+
+    // ========================================================================
+    // 6. Rule Activity
+    // ========================================================================
+
+    let stale_before = Utc::now() - Duration::days(STALE_AFTER_DAYS);
+    let (never_matched, stale): (Vec<_>, Vec<_>) = rules
+        .enabled_rule_summaries()
+        .into_iter()
+        .filter_map(|r| match rule_hits.get(&r.id) {
+            None => Some((r, None)),
+            Some(stats) if stats.last_hit < stale_before => Some((r, Some(stats.last_hit))),
+            Some(_) => None,
+        })
+        .partition(|(_, last_hit)| last_hit.is_none());
+
+    let activity_list = |title: String, entries: Vec<(String, String, Option<String>)>| {
+        let mut list = column![text(title).size(12).color(p.text_secondary)].spacing(4);
+        let total = entries.len();
+        for (id, name, detail) in entries.into_iter().take(6) {
+            let mut label = column![text(name).size(13).color(p.text_primary)];
+            if let Some(detail) = detail {
+                label = label.push(text(detail).size(11).color(p.text_muted));
+            }
+            list = list.push(
+                button(label)
+                    .padding(4)
+                    .style(theme::ghost_button)
+                    .on_press(RuleEngineMessage::Inspector(InspectorMessage::Select(id))),
+            );
+        }
+        if total > 6 {
+            list = list.push(
+                text(format!("...and {} more", total - 6))
+                    .size(11)
+                    .color(p.text_muted),
+            );
+        }
+        list
+    };
+
+    let mut activity_content = column![].spacing(12);
+    if never_matched.is_empty() && stale.is_empty() {
+        activity_content = activity_content.push(
+            text("Every enabled rule has matched recently.")
+                .size(12)
+                .color(p.text_muted),
+        );
+    }
+    if !never_matched.is_empty() {
+        activity_content = activity_content.push(activity_list(
+            format!("Never matched ({})", never_matched.len()),
+            never_matched
+                .into_iter()
+                .map(|(r, _)| (r.id, r.name, None))
+                .collect(),
+        ));
+    }
+    if !stale.is_empty() {
+        activity_content = activity_content.push(activity_list(
+            format!("No hits in {STALE_AFTER_DAYS} days ({})", stale.len()),
+            stale
+                .into_iter()
+                .map(|(r, last_hit)| {
+                    let detail = last_hit.map(|t| format!("Last hit {}", format_time_ago(t)));
+                    (r.id, r.name, detail)
+                })
+                .collect(),
+        ));
+    }
+
+    let activity_section = column![
+        text("RULE ACTIVITY")
+            .size(11)
+            .color(p.text_muted)
+            .font(iced::Font {
+                weight: iced::font::Weight::Bold,
+                ..Default::default()
+            }),
+        Space::new().height(4),
+        text("Hits are counted once per thread update.")
+            .size(11)
+            .color(p.text_secondary),
+        Space::new().height(12),
+        activity_content,
+        Space::new().height(8),
+        button(text("Reset statistics").size(12))
+            .style(theme::ghost_button)
+            .padding([4, 8])
+            .on_press(RuleEngineMessage::ClearRuleHits),
+    ]
+    .width(Length::Fixed(240.0));
+
     // ========================================================================
     // Final Layout Assembly
     // ========================================================================
@@ -301,7 +513,17 @@ pub fn view_overview_tab(
     column![
         header,
         Space::new().height(32),
-        row![left_column, Space::new().width(48), high_impact_section]
+        row![
+            left_column,
+            Space::new().width(48),
+            column![
+                high_impact_section,
+                Space::new().height(32),
+                deferred_section,
+                Space::new().height(32),
+                activity_section
+            ]
+        ]
     ]
     .padding(40) // More outer padding
     .width(Fill)
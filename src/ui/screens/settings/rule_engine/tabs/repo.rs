@@ -0,0 +1,163 @@
+//! Repository Rules tab for Rule Engine.
+
+use iced::widget::{Space, button, column, container, pick_list, row, slider, text, text_input};
+use iced::{Alignment, Element, Fill, Length};
+
+use crate::settings::IconTheme;
+use crate::ui::icons;
+use crate::ui::screens::settings::rule_engine::rules::{
+    NotificationRuleSet, RepoVisibility, RuleAction,
+};
+use crate::ui::theme;
+
+use super::super::components::{view_empty_state, view_repo_rule_card};
+use super::super::messages::{RepoMessage, RuleEngineMessage};
+
+/// State for the "New Rule" form.
+#[derive(Debug, Clone, Default)]
+pub struct RepoRuleFormState {
+    /// Repository pattern, e.g. `my-org/infra-*`.
+    pub repo: String,
+    pub visibility: RepoVisibility,
+    pub priority: i32,
+    pub action: RuleAction,
+}
+
+pub fn view_repo_rules_tab<'a>(
+    rules: &'a NotificationRuleSet,
+    icon_theme: IconTheme,
+    form_state: &RepoRuleFormState,
+) -> Element<'a, RuleEngineMessage> {
+    let p = theme::palette();
+
+    // ========================================================================
+    // Form Section
+    // ========================================================================
+    let repo_input = container(
+        column![
+            text("Repository").size(12).color(p.text_secondary),
+            text_input("owner/repo or owner/prefix-*", &form_state.repo)
+                .on_input(|s| RuleEngineMessage::Repo(RepoMessage::FormRepoChanged(s)))
+                .on_submit(RuleEngineMessage::Repo(RepoMessage::Add))
+                .padding(6)
+                .size(13)
+                .style(theme::text_input_style)
+                .width(Length::Fixed(220.0)),
+        ]
+        .spacing(4),
+    );
+
+    let visibility_input = container(
+        column![
+            text("Visibility").size(12).color(p.text_secondary),
+            pick_list(RepoVisibility::ALL, Some(form_state.visibility), |v| {
+                RuleEngineMessage::Repo(RepoMessage::FormVisibilityChanged(v))
+            })
+            .width(Length::Fixed(130.0))
+            .style(theme::pick_list_style)
+            .menu_style(theme::menu_style),
+        ]
+        .spacing(4),
+    );
+
+    let priority_input = container(
+        column![
+            row![
+                text("Priority").size(12).color(p.text_secondary),
+                Space::new().width(8),
+                text(format!("{}", form_state.priority))
+                    .size(12)
+                    .color(p.text_primary),
+            ]
+            .align_y(Alignment::Center),
+            slider(-100..=100, form_state.priority, |p| {
+                RuleEngineMessage::Repo(RepoMessage::FormPriorityChanged(p))
+            })
+            .width(Length::Fixed(150.0)),
+        ]
+        .spacing(4),
+    );
+
+    // Action Input with Warning
+    let action_label_row = if form_state.action == RuleAction::Hide {
+        row![
+            text("Action").size(12).color(p.text_secondary),
+            Space::new().width(4),
+            icons::icon_alert(12.0, p.accent_warning, icon_theme),
+        ]
+        .align_y(Alignment::Center)
+    } else {
+        row![text("Action").size(12).color(p.text_secondary)]
+    };
+
+    let action_input = container(
+        column![
+            action_label_row,
+            pick_list(RuleAction::ALL, Some(form_state.action), |a| {
+                RuleEngineMessage::Repo(RepoMessage::FormActionChanged(a))
+            })
+            .width(Length::Fixed(100.0))
+            .style(theme::pick_list_style)
+            .menu_style(theme::menu_style),
+        ]
+        .spacing(4),
+    );
+
+    let can_add = !form_state.repo.trim().is_empty();
+    let add_btn = button(text("Add Rule").size(13))
+        .style(theme::primary_button)
+        .on_press_maybe(can_add.then_some(RuleEngineMessage::Repo(RepoMessage::Add)))
+        .padding([8, 16]);
+
+    let form_row = row![
+        repo_input,
+        visibility_input,
+        priority_input,
+        action_input,
+        Space::new().width(Fill),
+        column![Space::new().height(19), add_btn].spacing(0),
+    ]
+    .spacing(12)
+    .align_y(Alignment::End);
+
+    let form_section = container(form_row)
+        .padding(16)
+        .style(move |_| container::Style {
+            background: Some(iced::Background::Color(p.bg_control)),
+            border: iced::Border {
+                radius: 8.0.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+    let rules_list: Element<_> = if rules.repo_rules.is_empty() {
+        view_empty_state("No repository rules configured.", icon_theme)
+    } else {
+        column(rules.repo_rules.iter().flat_map(|rule| {
+            [
+                view_repo_rule_card(rule, icon_theme),
+                Space::new().height(8).into(),
+            ]
+        }))
+        .into()
+    };
+
+    column![
+        text("Repository Rules").size(20).color(p.text_primary),
+        text(
+            "Set priority and actions for repositories. Use * to match several, \
+             and Visibility to target only private or public ones."
+        )
+        .size(12)
+        .color(p.text_secondary),
+        Space::new().height(16),
+        form_section,
+        Space::new().height(24),
+        rules_list,
+    ]
+    .spacing(4)
+    .padding(24)
+    .width(Fill)
+    .into()
+}
@@ -0,0 +1,395 @@
+//! Rule Sets tab for Rule Engine.
+
+use chrono::{Local, Weekday};
+use iced::widget::{Space, button, column, container, row, text, text_input, toggler};
+use iced::{Alignment, Element, Fill, Length};
+
+use crate::settings::IconTheme;
+use crate::ui::icons;
+use crate::ui::screens::settings::rule_engine::rules::{NotificationRuleSet, RuleSetStore};
+use crate::ui::theme;
+
+use super::super::messages::{RuleEngineMessage, RuleSetMessage};
+
+/// State for the rule set forms.
+#[derive(Debug, Clone, Default)]
+pub struct RuleSetFormState {
+    /// Name for a new set.
+    pub new_name: String,
+    /// Pending rename of the set being edited.
+    pub rename: String,
+    /// Schedule times as typed, so half-written values survive.
+    pub schedule_start: String,
+    pub schedule_end: String,
+    pub error: Option<String>,
+    /// Path for import/export.
+    pub file_path: String,
+    /// Merge imports into the edited set by rule ID instead of adding a new set.
+    pub merge_import: bool,
+    /// Outcome of the last import/export: a confirmation or an error.
+    pub file_status: Option<Result<String, String>>,
+}
+
+impl RuleSetFormState {
+    /// Form state for editing `rules`.
+    pub fn for_set(rules: &NotificationRuleSet) -> Self {
+        let schedule = rules.schedule.clone().unwrap_or_default();
+        Self {
+            rename: rules.name.clone(),
+            schedule_start: schedule.start_time.format("%H:%M").to_string(),
+            schedule_end: schedule.end_time.format("%H:%M").to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Reset for a newly loaded set, keeping the import/export fields.
+    pub fn reload(&mut self, rules: &NotificationRuleSet) {
+        *self = Self {
+            file_path: std::mem::take(&mut self.file_path),
+            merge_import: self.merge_import,
+            file_status: self.file_status.take(),
+            ..Self::for_set(rules)
+        };
+    }
+}
+
+/// `dry_run` locks everything that would switch away from the edited set,
+/// since that drops the dry run's held-back edits.
+pub fn view_rule_sets_tab<'a>(
+    rules: &'a NotificationRuleSet,
+    store: &'a RuleSetStore,
+    icon_theme: IconTheme,
+    form_state: &'a RuleSetFormState,
+    dry_run: bool,
+) -> Element<'a, RuleEngineMessage> {
+    let p = theme::palette();
+    let in_force = store.active_set(&Local::now()).name.as_str();
+
+    // ========================================================================
+    // Set List
+    // ========================================================================
+    let can_delete = store.sets.len() > 1;
+    let set_list = column(store.sets.iter().map(|set| {
+        // The stored copy of the set being edited may lag behind unsaved state.
+        let set = if set.name == rules.name { rules } else { set };
+        view_rule_set_card(
+            set,
+            set.name == rules.name,
+            set.name == in_force,
+            can_delete,
+            dry_run,
+            icon_theme,
+        )
+    }))
+    .spacing(8);
+
+    // ========================================================================
+    // Create Form
+    // ========================================================================
+    let can_create = {
+        let name = form_state.new_name.trim();
+        !dry_run && !name.is_empty() && store.get(name).is_none()
+    };
+    let create_row = row![
+        column![
+            text("New rule set").size(12).color(p.text_secondary),
+            text_input("e.g. On-Call", &form_state.new_name)
+                .on_input(|s| RuleEngineMessage::RuleSet(RuleSetMessage::NewNameChanged(s)))
+                .on_submit(RuleEngineMessage::RuleSet(RuleSetMessage::Create))
+                .padding(6)
+                .size(13)
+                .style(theme::text_input_style)
+                .width(Length::Fixed(220.0)),
+        ]
+        .spacing(4),
+        Space::new().width(Fill),
+        button(text("Copy Current").size(13))
+            .style(theme::ghost_button)
+            .on_press_maybe(
+                can_create.then_some(RuleEngineMessage::RuleSet(RuleSetMessage::Duplicate)),
+            )
+            .padding([8, 16]),
+        button(text("Create").size(13))
+            .style(theme::primary_button)
+            .on_press_maybe(
+                can_create.then_some(RuleEngineMessage::RuleSet(RuleSetMessage::Create))
+            )
+            .padding([8, 16]),
+    ]
+    .spacing(12)
+    .align_y(Alignment::End);
+
+    // ========================================================================
+    // Current Set Settings
+    // ========================================================================
+    let rename_row = row![
+        text_input("Name", &form_state.rename)
+            .on_input(|s| RuleEngineMessage::RuleSet(RuleSetMessage::RenameChanged(s)))
+            .on_submit(RuleEngineMessage::RuleSet(RuleSetMessage::Rename))
+            .padding(6)
+            .size(13)
+            .style(theme::text_input_style)
+            .width(Length::Fixed(220.0)),
+        button(text("Rename").size(13))
+            .style(theme::ghost_button)
+            .on_press_maybe(
+                (form_state.rename.trim() != rules.name)
+                    .then_some(RuleEngineMessage::RuleSet(RuleSetMessage::Rename)),
+            )
+            .padding([6, 12]),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    let schedule_enabled = rules.schedule.as_ref().is_some_and(|s| s.enabled);
+    let mut schedule_section = column![
+        row![
+            toggler(schedule_enabled)
+                .on_toggle(|v| RuleEngineMessage::RuleSet(RuleSetMessage::ToggleSchedule(v)))
+                .size(18),
+            Space::new().width(8),
+            text("Activate automatically on a schedule")
+                .size(13)
+                .color(p.text_primary),
+        ]
+        .align_y(Alignment::Center),
+        text("While the window is open this set overrides the one picked by hand.")
+            .size(12)
+            .color(p.text_muted),
+    ]
+    .spacing(6);
+
+    if let Some(schedule) = rules.schedule.as_ref().filter(|s| s.enabled) {
+        let days = [
+            ("Sun", Weekday::Sun),
+            ("Mon", Weekday::Mon),
+            ("Tue", Weekday::Tue),
+            ("Wed", Weekday::Wed),
+            ("Thu", Weekday::Thu),
+            ("Fri", Weekday::Fri),
+            ("Sat", Weekday::Sat),
+        ];
+        let day_buttons = row(days.iter().map(|(label, day)| {
+            button(text(*label).size(12))
+                .style(theme::segment_button(schedule.active_days.contains(day)))
+                .padding([6, 10])
+                .on_press(RuleEngineMessage::RuleSet(RuleSetMessage::ToggleDay(*day)))
+                .into()
+        }))
+        .spacing(4);
+
+        schedule_section = schedule_section
+            .push(Space::new().height(4))
+            .push(day_buttons)
+            .push(
+                row![
+                    text("From:").size(13).color(p.text_muted),
+                    text_input("09:00", &form_state.schedule_start)
+                        .on_input(|s| RuleEngineMessage::RuleSet(RuleSetMessage::StartChanged(s)))
+                        .width(Length::Fixed(80.0))
+                        .padding(6)
+                        .style(theme::text_input_style),
+                    Space::new().width(16),
+                    text("To:").size(13).color(p.text_muted),
+                    text_input("17:00", &form_state.schedule_end)
+                        .on_input(|s| RuleEngineMessage::RuleSet(RuleSetMessage::EndChanged(s)))
+                        .width(Length::Fixed(80.0))
+                        .padding(6)
+                        .style(theme::text_input_style),
+                ]
+                .spacing(8)
+                .align_y(Alignment::Center),
+            );
+    }
+
+    if let Some(error) = &form_state.error {
+        schedule_section = schedule_section.push(
+            row![
+                icons::icon_alert(12.0, p.accent_warning, icon_theme),
+                Space::new().width(4),
+                text(error.as_str()).size(12).color(p.accent_warning),
+            ]
+            .align_y(Alignment::Center),
+        );
+    }
+
+    // ========================================================================
+    // Import / Export
+    // ========================================================================
+    let has_path = !form_state.file_path.trim().is_empty();
+    // A merge stays in the edited set; adding a new set switches to it.
+    let can_import = has_path && (form_state.merge_import || !dry_run);
+    let file_row = row![
+        text_input("/path/to/rules.json", &form_state.file_path)
+            .on_input(|s| RuleEngineMessage::RuleSet(RuleSetMessage::FilePathChanged(s)))
+            .padding(6)
+            .size(13)
+            .style(theme::text_input_style)
+            .width(Fill),
+        button(text("Export").size(13))
+            .style(theme::ghost_button)
+            .on_press_maybe(has_path.then_some(RuleEngineMessage::RuleSet(RuleSetMessage::Export)))
+            .padding([6, 12]),
+        button(text("Import").size(13))
+            .style(theme::primary_button)
+            .on_press_maybe(
+                can_import.then_some(RuleEngineMessage::RuleSet(RuleSetMessage::Import))
+            )
+            .padding([6, 12]),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    let mut file_section = column![
+        file_row,
+        row![
+            toggler(form_state.merge_import)
+                .on_toggle(|v| RuleEngineMessage::RuleSet(RuleSetMessage::ToggleMerge(v)))
+                .size(16),
+            Space::new().width(8),
+            text(format!(
+                "Merge imports into \"{}\" by rule ID instead of adding a new set",
+                rules.name
+            ))
+            .size(12)
+            .color(p.text_secondary),
+        ]
+        .align_y(Alignment::Center),
+    ]
+    .spacing(10);
+
+    match &form_state.file_status {
+        Some(Ok(message)) => {
+            file_section = file_section.push(
+                row![
+                    icons::icon_check(12.0, p.accent_success, icon_theme),
+                    Space::new().width(4),
+                    text(message.as_str()).size(12).color(p.accent_success),
+                ]
+                .align_y(Alignment::Center),
+            );
+        }
+        Some(Err(error)) => {
+            file_section = file_section.push(
+                row![
+                    icons::icon_alert(12.0, p.accent_warning, icon_theme),
+                    Space::new().width(4),
+                    text(error.as_str()).size(12).color(p.accent_warning),
+                ]
+                .align_y(Alignment::Center),
+            );
+        }
+        None => {}
+    }
+
+    let section_style = move |_: &iced::Theme| container::Style {
+        background: Some(iced::Background::Color(p.bg_control)),
+        border: iced::Border {
+            radius: 8.0.into(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let subtitle = if dry_run {
+        "A dry run is open. Save or discard it before switching sets."
+    } else {
+        "Keep separate rules for different situations. Switch here, from the tray menu or the notifications header."
+    };
+
+    column![
+        text("Rule Sets").size(20).color(p.text_primary),
+        text(subtitle).size(12).color(p.text_secondary),
+        Space::new().height(16),
+        container(create_row).padding(16).style(section_style),
+        Space::new().height(16),
+        set_list,
+        Space::new().height(24),
+        text(format!("Editing \"{}\"", rules.name))
+            .size(14)
+            .color(p.text_primary),
+        Space::new().height(8),
+        container(column![
+            rename_row,
+            Space::new().height(16),
+            schedule_section
+        ])
+        .padding(16)
+        .width(Fill)
+        .style(section_style),
+        Space::new().height(24),
+        text("Import / Export").size(14).color(p.text_primary),
+        text("Share a rule set as a file. Older rule files are upgraded on import.")
+            .size(12)
+            .color(p.text_secondary),
+        Space::new().height(8),
+        container(file_section)
+            .padding(16)
+            .width(Fill)
+            .style(section_style),
+    ]
+    .spacing(4)
+    .padding(24)
+    .width(Fill)
+    .into()
+}
+
+fn view_rule_set_card<'a>(
+    set: &'a NotificationRuleSet,
+    is_editing: bool,
+    is_in_force: bool,
+    can_delete: bool,
+    dry_run: bool,
+    icon_theme: IconTheme,
+) -> Element<'a, RuleEngineMessage> {
+    let p = theme::palette();
+
+    let mut status = format!("{} active rules", set.active_rule_count());
+    if let Some(schedule) = set.schedule.as_ref().filter(|s| s.enabled) {
+        status.push_str(&format!(
+            " · scheduled {}–{}",
+            schedule.start_time.format("%H:%M"),
+            schedule.end_time.format("%H:%M")
+        ));
+    }
+
+    let mut name_row = row![text(set.name.as_str()).size(14).color(p.text_primary)]
+        .spacing(8)
+        .align_y(Alignment::Center);
+    if is_in_force {
+        name_row = name_row.push(text("In force").size(11).color(p.accent_success));
+    }
+    if is_editing {
+        name_row = name_row.push(text("Editing").size(11).color(p.accent));
+    }
+
+    let switch_btn = button(text("Edit").size(12))
+        .style(theme::ghost_button)
+        .padding([4, 10])
+        .on_press_maybe(
+            (!is_editing && !dry_run)
+                .then(|| RuleEngineMessage::RuleSet(RuleSetMessage::Select(set.name.clone()))),
+        );
+
+    let delete_btn = button(icons::icon_trash(14.0, p.text_muted, icon_theme))
+        .style(theme::ghost_button)
+        .padding(6)
+        .on_press_maybe(
+            (can_delete && !(is_editing && dry_run))
+                .then(|| RuleEngineMessage::RuleSet(RuleSetMessage::Delete(set.name.clone()))),
+        );
+
+    container(
+        row![
+            column![name_row, text(status).size(12).color(p.text_secondary)].spacing(4),
+            Space::new().width(Fill),
+            switch_btn,
+            delete_btn,
+        ]
+        .spacing(4)
+        .align_y(Alignment::Center)
+        .padding(14),
+    )
+    .style(|_| theme::rule_card_container())
+    .into()
+}
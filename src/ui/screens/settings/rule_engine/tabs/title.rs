@@ -0,0 +1,181 @@
+//! Title Rules tab for Rule Engine.
+
+use iced::widget::{
+    Space, button, column, container, pick_list, row, slider, text, text_input, toggler,
+};
+use iced::{Alignment, Element, Fill, Length};
+
+use crate::settings::IconTheme;
+use crate::ui::icons;
+use crate::ui::screens::settings::rule_engine::rules::{
+    NotificationRuleSet, RuleAction, TitleMatchMode, validate_title_pattern,
+};
+use crate::ui::theme;
+
+use super::super::components::{view_empty_state, view_title_rule_card};
+use super::super::messages::{RuleEngineMessage, TitleMessage};
+
+/// State for the "New Rule" form.
+#[derive(Debug, Clone, Default)]
+pub struct TitleRuleFormState {
+    /// Keyword or regex, e.g. `chore(deps)`.
+    pub pattern: String,
+    pub match_mode: TitleMatchMode,
+    pub case_sensitive: bool,
+    pub priority: i32,
+    pub action: RuleAction,
+}
+
+pub fn view_title_rules_tab<'a>(
+    rules: &'a NotificationRuleSet,
+    icon_theme: IconTheme,
+    form_state: &TitleRuleFormState,
+) -> Element<'a, RuleEngineMessage> {
+    let p = theme::palette();
+
+    // ========================================================================
+    // Form Section
+    // ========================================================================
+    let pattern_error = validate_title_pattern(&form_state.pattern, form_state.match_mode).err();
+
+    let mut pattern_column = column![
+        text("Title").size(12).color(p.text_secondary),
+        text_input("chore(deps) or ^\\[SEV[12]\\]", &form_state.pattern)
+            .on_input(|s| RuleEngineMessage::Title(TitleMessage::FormPatternChanged(s)))
+            .on_submit(RuleEngineMessage::Title(TitleMessage::Add))
+            .padding(6)
+            .size(13)
+            .style(theme::text_input_style)
+            .width(Length::Fixed(220.0)),
+    ]
+    .spacing(4);
+    if let Some(error) = &pattern_error {
+        pattern_column = pattern_column.push(
+            text(format!("Invalid regex: {}", error))
+                .size(11)
+                .color(p.accent_warning),
+        );
+    }
+    let pattern_input = container(pattern_column);
+
+    let mode_input = container(
+        column![
+            text("Match").size(12).color(p.text_secondary),
+            pick_list(TitleMatchMode::ALL, Some(form_state.match_mode), |m| {
+                RuleEngineMessage::Title(TitleMessage::FormModeChanged(m))
+            })
+            .width(Length::Fixed(120.0))
+            .style(theme::pick_list_style)
+            .menu_style(theme::menu_style),
+            row![
+                toggler(form_state.case_sensitive)
+                    .on_toggle(|c| {
+                        RuleEngineMessage::Title(TitleMessage::FormCaseSensitiveChanged(c))
+                    })
+                    .size(16),
+                Space::new().width(6),
+                text("Case-sensitive").size(12).color(p.text_secondary),
+            ]
+            .align_y(Alignment::Center),
+        ]
+        .spacing(4),
+    );
+
+    let priority_input = container(
+        column![
+            row![
+                text("Priority").size(12).color(p.text_secondary),
+                Space::new().width(8),
+                text(format!("{}", form_state.priority))
+                    .size(12)
+                    .color(p.text_primary),
+            ]
+            .align_y(Alignment::Center),
+            slider(-100..=100, form_state.priority, |p| {
+                RuleEngineMessage::Title(TitleMessage::FormPriorityChanged(p))
+            })
+            .width(Length::Fixed(150.0)),
+        ]
+        .spacing(4),
+    );
+
+    // Action Input with Warning
+    let action_label_row = if form_state.action == RuleAction::Hide {
+        row![
+            text("Action").size(12).color(p.text_secondary),
+            Space::new().width(4),
+            icons::icon_alert(12.0, p.accent_warning, icon_theme),
+        ]
+        .align_y(Alignment::Center)
+    } else {
+        row![text("Action").size(12).color(p.text_secondary)]
+    };
+
+    let action_input = container(
+        column![
+            action_label_row,
+            pick_list(RuleAction::ALL, Some(form_state.action), |a| {
+                RuleEngineMessage::Title(TitleMessage::FormActionChanged(a))
+            })
+            .width(Length::Fixed(100.0))
+            .style(theme::pick_list_style)
+            .menu_style(theme::menu_style),
+        ]
+        .spacing(4),
+    );
+
+    let can_add = !form_state.pattern.trim().is_empty() && pattern_error.is_none();
+    let add_btn = button(text("Add Rule").size(13))
+        .style(theme::primary_button)
+        .on_press_maybe(can_add.then_some(RuleEngineMessage::Title(TitleMessage::Add)))
+        .padding([8, 16]);
+
+    let form_row = row![
+        pattern_input,
+        mode_input,
+        priority_input,
+        action_input,
+        Space::new().width(Fill),
+        column![Space::new().height(19), add_btn].spacing(0),
+    ]
+    .spacing(12)
+    .align_y(Alignment::End);
+
+    let form_section = container(form_row)
+        .padding(16)
+        .style(move |_| container::Style {
+            background: Some(iced::Background::Color(p.bg_control)),
+            border: iced::Border {
+                radius: 8.0.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+    let rules_list: Element<_> = if rules.title_rules.is_empty() {
+        view_empty_state("No title rules configured.", icon_theme)
+    } else {
+        column(rules.title_rules.iter().flat_map(|rule| {
+            [
+                view_title_rule_card(rule, icon_theme),
+                Space::new().height(8).into(),
+            ]
+        }))
+        .into()
+    };
+
+    column![
+        text("Title Rules").size(20).color(p.text_primary),
+        text("Match keywords in notification titles, e.g. hide dependency bumps.")
+            .size(12)
+            .color(p.text_secondary),
+        Space::new().height(16),
+        form_section,
+        Space::new().height(24),
+        rules_list,
+    ]
+    .spacing(4)
+    .padding(24)
+    .width(Fill)
+    .into()
+}
@@ -5,6 +5,7 @@ use std::collections::HashSet;
 use iced::widget::{Space, button, column, container, pick_list, row, slider, text};
 use iced::{Alignment, Element, Fill, Length};
 
+use crate::github::types::{NotificationReason, SubjectType};
 use crate::settings::IconTheme;
 use crate::ui::icons;
 use crate::ui::screens::settings::rule_engine::rules::{NotificationRuleSet, RuleAction, TypeRule};
@@ -13,10 +14,46 @@ use crate::ui::theme;
 use super::super::components::{view_empty_state, view_type_rule_card};
 use super::super::messages::{RuleEngineMessage, TypeMessage};
 
+/// Pick-list entry that can also stand for "any value".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AnyOr<T> {
+    Any,
+    Only(T),
+}
+
+impl<T: Copy> AnyOr<T> {
+    fn options(values: &[T]) -> Vec<Self> {
+        std::iter::once(Self::Any)
+            .chain(values.iter().copied().map(Self::Only))
+            .collect()
+    }
+
+    fn from_option(value: Option<T>) -> Self {
+        value.map_or(Self::Any, Self::Only)
+    }
+
+    fn into_option(self) -> Option<T> {
+        match self {
+            Self::Any => None,
+            Self::Only(value) => Some(value),
+        }
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for AnyOr<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Any => write!(f, "Any"),
+            Self::Only(value) => value.fmt(f),
+        }
+    }
+}
+
 /// State for the "New Rule" form.
 #[derive(Debug, Clone)]
 pub struct TypeRuleFormState {
-    pub notification_type: crate::github::types::NotificationReason,
+    pub reason: Option<NotificationReason>,
+    pub subject_type: Option<SubjectType>,
     pub account: Option<String>,
     pub priority: i32,
     pub action: RuleAction,
@@ -25,7 +62,8 @@ pub struct TypeRuleFormState {
 impl Default for TypeRuleFormState {
     fn default() -> Self {
         Self {
-            notification_type: crate::github::types::NotificationReason::Mention,
+            reason: Some(NotificationReason::Mention),
+            subject_type: None,
             account: None,
             priority: 0,
             action: RuleAction::Show,
@@ -33,7 +71,7 @@ impl Default for TypeRuleFormState {
     }
 }
 
-/// Groups type rules by notification_type using BTreeMap to avoid allocations and sorting.
+/// Groups type rules by what they match using BTreeMap to avoid allocations and sorting.
 fn view_grouped_rules<'a>(
     rules: &'a [TypeRule],
     expanded_groups: &HashSet<String>,
@@ -46,14 +84,11 @@ fn view_grouped_rules<'a>(
         return view_empty_state("No type rules configured.", icon_theme);
     }
 
-    // Group by (NotificationReason, String) -> Vec<&TypeRule>
+    // Group by target label -> Vec<&TypeRule>
     // We use BTreeMap to automatically sort by keys
     let mut groups: BTreeMap<String, Vec<&TypeRule>> = BTreeMap::new();
     for rule in rules {
-        groups
-            .entry(rule.notification_type.clone())
-            .or_default()
-            .push(rule);
+        groups.entry(rule.target_label()).or_default().push(rule);
     }
 
     column(groups.into_iter().flat_map(|(group_name, group_rules)| {
@@ -126,11 +161,11 @@ pub fn view_type_rules_tab<'a>(
     // ========================================================================
     let type_input = container(
         column![
-            text("Type").size(12).color(p.text_secondary),
+            text("Reason").size(12).color(p.text_secondary),
             pick_list(
-                crate::github::types::NotificationReason::ALL,
-                Some(form_state.notification_type),
-                |r| RuleEngineMessage::Type(TypeMessage::FormTypeChanged(r))
+                AnyOr::options(NotificationReason::ALL),
+                Some(AnyOr::from_option(form_state.reason)),
+                |r| RuleEngineMessage::Type(TypeMessage::FormTypeChanged(r.into_option()))
             )
             .width(Length::Fixed(180.0))
             .style(theme::pick_list_style)
@@ -139,6 +174,21 @@ pub fn view_type_rules_tab<'a>(
         .spacing(4),
     );
 
+    let subject_input = container(
+        column![
+            text("Subject").size(12).color(p.text_secondary),
+            pick_list(
+                AnyOr::options(SubjectType::ALL),
+                Some(AnyOr::from_option(form_state.subject_type)),
+                |s| RuleEngineMessage::Type(TypeMessage::FormSubjectTypeChanged(s.into_option()))
+            )
+            .width(Length::Fixed(140.0))
+            .style(theme::pick_list_style)
+            .menu_style(theme::menu_style),
+        ]
+        .spacing(4),
+    );
+
     let selected_account = form_state
         .account
         .clone()
@@ -208,13 +258,15 @@ pub fn view_type_rules_tab<'a>(
         .spacing(4),
     );
 
+    let can_add = form_state.reason.is_some() || form_state.subject_type.is_some();
     let add_btn = button(text("Add Rule").size(13))
         .style(theme::primary_button)
-        .on_press(RuleEngineMessage::Type(TypeMessage::Add))
+        .on_press_maybe(can_add.then_some(RuleEngineMessage::Type(TypeMessage::Add)))
         .padding([8, 16]);
 
     let form_row = row![
         type_input,
+        subject_input,
         account_input,
         priority_input,
         action_input,
@@ -237,7 +289,7 @@ pub fn view_type_rules_tab<'a>(
 
     let header = column![
         text("Type Rules").size(20).color(p.text_primary),
-        text("Filter notifications by type, account, and priority.")
+        text("Filter notifications by reason, subject type, account, and priority.")
             .size(12)
             .color(p.text_secondary),
     ]
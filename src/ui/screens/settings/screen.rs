@@ -3,6 +3,7 @@
 use iced::widget::{Space, button, column, container, row, scrollable, text};
 use iced::{Alignment, Element, Fill, Length, Task};
 
+use crate::github::client::enterprise_api_base_input;
 use crate::github::{GitHubClient, keyring};
 use crate::settings::{AppSettings, IconTheme};
 use crate::ui::{icons, theme};
@@ -55,10 +56,12 @@ impl SettingsScreen {
                 let _ = self.settings.save();
                 Task::none()
             }
-            SettingsMessage::RemoveAccount(username) => {
-                self.settings.remove_account(&username);
+            SettingsMessage::RemoveAccount(key) => {
+                if let Some(account) = self.settings.account(&key) {
+                    let _ = keyring::delete_token(&account.username, account.api_base.as_deref());
+                }
+                self.settings.remove_account(&key);
                 let _ = self.settings.save();
-                let _ = keyring::delete_token(&username);
                 Task::none()
             }
             SettingsMessage::SetNotificationFontScale(scale) => {
@@ -92,8 +95,14 @@ impl SettingsScreen {
                 self.accounts_state.status = accounts::SubmissionStatus::Idle;
                 Task::none()
             }
+            SettingsMessage::ApiBaseInputChanged(api_base) => {
+                self.accounts_state.api_base_input = api_base;
+                self.accounts_state.status = accounts::SubmissionStatus::Idle;
+                Task::none()
+            }
             SettingsMessage::SubmitToken => {
                 let token = self.accounts_state.token_input.clone();
+                let api_base = enterprise_api_base_input(&self.accounts_state.api_base_input);
                 if let Err(e) = crate::github::auth::validate_token_format(&token) {
                     self.accounts_state.status = accounts::SubmissionStatus::Error(e.to_string());
                     return Task::none();
@@ -103,7 +112,7 @@ impl SettingsScreen {
 
                 Task::perform(
                     async move {
-                        let client = GitHubClient::new(&token)
+                        let client = GitHubClient::with_api_base(&token, api_base.as_deref())
                             .map_err(|e| format!("Invalid token: {}", e))?;
 
                         let user = client
@@ -111,20 +120,22 @@ impl SettingsScreen {
                             .await
                             .map_err(|e| format!("Validation failed: {}", e))?;
 
-                        keyring::save_token(&user.login, &token)
+                        keyring::save_token(&user.login, api_base.as_deref(), &token)
                             .map_err(|e| format!("Failed to save token: {}", e))?;
 
-                        Ok(user.login)
+                        Ok((user.login, api_base))
                     },
                     SettingsMessage::TokenValidated,
                 )
             }
             SettingsMessage::TokenValidated(result) => {
                 match result {
-                    Ok(username) => {
-                        self.settings.set_active_account(&username);
+                    Ok((username, api_base)) => {
+                        self.settings
+                            .set_active_account(&username, api_base.as_deref());
                         let _ = self.settings.save();
                         self.accounts_state.token_input.clear();
+                        self.accounts_state.api_base_input.clear();
                         self.accounts_state.status = accounts::SubmissionStatus::Success(format!(
                             "Account '{}' added successfully!",
                             username
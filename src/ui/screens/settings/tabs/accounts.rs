@@ -3,6 +3,7 @@
 use iced::widget::{Space, button, column, container, row, text, text_input};
 use iced::{Alignment, Element, Fill};
 
+use crate::github::client::api_host;
 use crate::settings::{AppSettings, StoredAccount};
 use crate::ui::{icons, theme};
 
@@ -23,6 +24,8 @@ pub enum SubmissionStatus {
 #[derive(Debug, Clone, Default)]
 pub struct AccountsTabState {
     pub token_input: String,
+    /// Optional GitHub Enterprise Server address; empty means github.com.
+    pub api_base_input: String,
     pub status: SubmissionStatus,
 }
 
@@ -92,6 +95,16 @@ fn view_add_account_section<'a>(
             }),
         ]
         .align_y(Alignment::Center),
+        Space::new().height(4),
+        text_input(
+            "GitHub Enterprise Server URL (optional, e.g. ghe.example.com)",
+            &state.api_base_input
+        )
+        .on_input(SettingsMessage::ApiBaseInputChanged)
+        .padding([8, 12])
+        .size(12)
+        .width(Fill)
+        .style(theme::text_input_style),
     ]
     .spacing(4);
 
@@ -143,19 +156,61 @@ fn view_account_item(
 
     // We need owned strings for both output elements because we are returning Element<'static>
     let username_display = account.username.clone();
-    let username_msg = account.username.clone();
+    let key_msg = account.key();
+    let host_display = account
+        .api_base
+        .as_deref()
+        .map_or("github.com", api_host)
+        .to_string();
+
+    let token = &account.token;
+    let scopes_display = match &token.scopes {
+        Some(scopes) if scopes.is_empty() => "Scopes: none".to_string(),
+        Some(scopes) => format!("Scopes: {}", scopes.join(", ")),
+        None => "Fine-grained token".to_string(),
+    };
+    let expiry_display = match token.expires_at {
+        Some(expires) => format!(
+            "Expires {}",
+            expires.with_timezone(&chrono::Local).format("%b %d, %Y")
+        ),
+        None => "No expiry".to_string(),
+    };
+
+    let mut details = column![
+        row![
+            text(username_display).size(13).color(p.text_primary),
+            Space::new().width(8),
+            text(host_display).size(11).color(p.text_muted),
+        ]
+        .align_y(Alignment::Center),
+        text(format!("{} · {}", scopes_display, expiry_display))
+            .size(11)
+            .color(p.text_secondary),
+    ]
+    .spacing(2);
+
+    if let Some(warning) = token.warning() {
+        details = details.push(
+            row![
+                icons::icon_alert(11.0, p.accent_warning, icon_theme),
+                Space::new().width(4),
+                text(warning).size(11).color(p.accent_warning),
+            ]
+            .align_y(Alignment::Center),
+        );
+    }
 
     container(
         row![
             icons::icon_user(14.0, p.text_secondary, icon_theme),
             Space::new().width(8),
-            text(username_display).size(13).color(p.text_primary),
-            Space::new().width(8),
+            details,
             Space::new().width(Fill),
             button(icons::icon_trash(14.0, p.text_muted, icon_theme))
                 .style(theme::ghost_button)
                 .padding(6)
-                .on_press(SettingsMessage::RemoveAccount(username_msg)),
+                .on_press(SettingsMessage::RemoveAccount(key_msg)),
         ]
         .align_y(Alignment::Center)
         .padding(14),
@@ -5,7 +5,7 @@
 //! - Platform-aware defaults
 //! - Clean, professional aesthetic with subtle depth
 
-use iced::widget::{button, container, pick_list, scrollable, text, text_input};
+use iced::widget::{button, container, pick_list, scrollable, text, text_editor, text_input};
 use iced::{Background, Border, Color, Theme};
 use std::sync::atomic::{AtomicU8, AtomicU32, Ordering};
 
@@ -579,6 +579,27 @@ pub fn text_input_style(_: &Theme, status: text_input::Status) -> text_input::St
     })
 }
 
+pub fn text_editor_style(_: &Theme, status: text_editor::Status) -> text_editor::Style {
+    with_palette(|p| {
+        let (bg, border_color, border_width) = match status {
+            text_editor::Status::Focused { .. } => (p.bg_base, p.accent, 2.0),
+            text_editor::Status::Hovered => (p.bg_hover, p.border, 1.0),
+            _ => (p.bg_control, p.border, 1.0),
+        };
+        text_editor::Style {
+            background: Background::Color(bg),
+            border: Border {
+                color: border_color,
+                width: border_width,
+                radius: 6.0.into(),
+            },
+            placeholder: p.text_muted,
+            value: p.text_primary,
+            selection: p.accent,
+        }
+    })
+}
+
 // ============================================================================
 // SCROLLBAR STYLE
 // ============================================================================
@@ -7,17 +7,22 @@
 //!   - State indicators (priority, silent)
 //! - Widget builders: `account_badge()`, `priority_indicator()`, `silent_indicator()`
 //! - `notification_item()`: Coordinates layout using the visual state
+//! - The deciding rule is explained in a hover tooltip and the context menu
 
-use iced::widget::{Space, button, column, container, row, text};
+use std::time::Duration;
+
+use iced::widget::{Space, button, column, container, row, text, tooltip};
 use iced::{Alignment, Color, Element, Fill};
+use iced_aw::ContextMenu;
 
 use crate::github::types::{self, SubjectType};
 use crate::settings::IconTheme;
 use crate::ui::screens::notifications::helper::ProcessedNotification;
 use crate::ui::screens::notifications::messages::{
-    NotificationMessage, ThreadMessage, ViewMessage,
+    NavigationMessage, NotificationMessage, SnoozeUntil, ThreadMessage, ViewMessage,
 };
 use crate::ui::screens::settings::rule_engine::RuleAction;
+use crate::ui::screens::settings::rule_engine::rules::{QuickRule, RuleDecision};
 use crate::ui::{icons, theme};
 
 // ============================================================================
@@ -207,6 +212,102 @@ fn account_badge(account: &str, size: f32) -> Element<'_, NotificationMessage> {
         .into()
 }
 
+fn context_menu_item(
+    label: &'static str,
+    message: NotificationMessage,
+) -> Element<'static, NotificationMessage> {
+    let p = theme::palette();
+
+    button(text(label).size(12).color(p.text_primary))
+        .style(move |_theme, status| {
+            let bg = match status {
+                button::Status::Hovered => p.bg_hover,
+                button::Status::Pressed => p.bg_active,
+                _ => p.bg_control,
+            };
+            button::Style {
+                background: Some(iced::Background::Color(bg)),
+                ..Default::default()
+            }
+        })
+        .padding([6, 12])
+        .width(Fill)
+        .on_press(message)
+        .into()
+}
+
+/// Right-click menu for a notification: the deciding rule, read state, done,
+/// thread subscription, and one-click rules.
+fn thread_context_menu(
+    id: &str,
+    unread: bool,
+    decision: Option<&RuleDecision>,
+) -> Element<'static, NotificationMessage> {
+    let p = theme::palette();
+    let thread =
+        |message: fn(String) -> ThreadMessage| NotificationMessage::Thread(message(id.to_string()));
+    let read_toggle = if unread {
+        context_menu_item("Mark as read", thread(ThreadMessage::MarkAsRead))
+    } else {
+        context_menu_item("Mark as unread", thread(ThreadMessage::MarkAsUnread))
+    };
+    let snooze = |until| NotificationMessage::Thread(ThreadMessage::Snooze(id.to_string(), until));
+
+    let rule_items = decision.map(|decision| {
+        column![
+            container(text(decision.summary()).size(11).color(p.text_muted)).padding([4, 12]),
+            context_menu_item(
+                "Show rule",
+                NotificationMessage::Navigation(NavigationMessage::InspectRule(
+                    decision.applied_rule_id.clone(),
+                )),
+            ),
+        ]
+        .spacing(2)
+    });
+
+    container(
+        column![
+            rule_items,
+            read_toggle,
+            context_menu_item("Mark as done", thread(ThreadMessage::MarkAsDone)),
+            context_menu_item(SnoozeUntil::OneHour.label(), snooze(SnoozeUntil::OneHour)),
+            context_menu_item(
+                SnoozeUntil::TomorrowMorning.label(),
+                snooze(SnoozeUntil::TomorrowMorning)
+            ),
+            context_menu_item(SnoozeUntil::NextWeek.label(), snooze(SnoozeUntil::NextWeek)),
+            context_menu_item("Snooze until...", thread(ThreadMessage::ChooseSnoozeTime)),
+            context_menu_item("Unsubscribe", thread(ThreadMessage::Unsubscribe)),
+            context_menu_item("Mute thread", thread(ThreadMessage::Mute)),
+            context_menu_item("Re-subscribe", thread(ThreadMessage::Resubscribe)),
+        ]
+        .spacing(2)
+        .extend(QuickRule::ALL.iter().map(|&quick| {
+            context_menu_item(
+                quick.label(),
+                NotificationMessage::Thread(ThreadMessage::CreateRule(id.to_string(), quick)),
+            )
+        })),
+    )
+    .style(|_| theme::context_menu_container())
+    .padding(4)
+    .width(220)
+    .into()
+}
+
+/// The deciding rule's numeric priority, shown when it isn't the default.
+fn priority_label(
+    priority: i32,
+    size: f32,
+    p: &theme::ThemePalette,
+) -> Element<'static, NotificationMessage> {
+    text(format!("Priority {priority:+}"))
+        .size(size)
+        .color(p.text_muted)
+        .into()
+}
+
 fn priority_indicator(size: f32) -> Element<'static, NotificationMessage> {
     container(text("⚡").size(size)).padding([0, 4]).into()
 }
@@ -215,6 +316,17 @@ fn silent_indicator(size: f32) -> Element<'static, NotificationMessage> {
     container(text("🔕").size(size)).padding([2, 4]).into()
 }
 
+/// Lock shown next to the repository name of private repositories.
+fn private_indicator(
+    size: f32,
+    color: Color,
+    icon_theme: IconTheme,
+) -> Element<'static, NotificationMessage> {
+    container(icons::icon_lock(size, color, icon_theme))
+        .padding([0, 4])
+        .into()
+}
+
 // ============================================================================
 // Main Widget
 // ============================================================================
@@ -227,6 +339,7 @@ pub fn notification_item(
     dense: bool,
     is_priority_group: bool,
     interactive: bool,
+    show_account: bool,
 ) -> Element<'_, NotificationMessage> {
     let notif = &processed.notification;
     let p = theme::palette();
@@ -254,11 +367,27 @@ pub fn notification_item(
         row_spacing: 8.0,
     };
 
+    let tags = MetaTags {
+        private_icon: notif
+            .is_private
+            .then(|| private_indicator(metrics.reason_size, p.text_muted, icon_theme)),
+        priority_tag: (processed.priority != 0)
+            .then(|| priority_label(processed.priority, metrics.reason_size, &p)),
+    };
+
     // --- BUILD CONTENT ---
     let content = if dense {
-        build_dense_layout(notif, icon_theme, &visual, &metrics, &p)
+        build_dense_layout(notif, icon_theme, tags, &visual, &metrics, &p, show_account)
     } else {
-        build_standard_layout(notif, subject_icon, &visual, &metrics, &p)
+        build_standard_layout(
+            notif,
+            subject_icon,
+            tags,
+            &visual,
+            &metrics,
+            &p,
+            show_account,
+        )
     };
 
     let content_element: Element<'_, NotificationMessage> = if interactive {
@@ -283,7 +412,31 @@ pub fn notification_item(
         content.into()
     };
 
-    build_card(content_element, &visual, dense)
+    let card = build_card(content_element, &visual, dense);
+    if !interactive {
+        return card;
+    }
+
+    let card: Element<'_, NotificationMessage> = match &processed.decision {
+        Some(decision) => tooltip(
+            card,
+            container(text(decision.summary()).size(12).color(p.text_primary))
+                .padding([6, 10])
+                .style(|_| theme::context_menu_container()),
+            tooltip::Position::FollowCursor,
+        )
+        .delay(Duration::from_millis(600))
+        .into(),
+        None => card,
+    };
+
+    let id = notif.id.clone();
+    let unread = notif.unread;
+    let decision = processed.decision.clone();
+    ContextMenu::new(card, move || {
+        thread_context_menu(&id, unread, decision.as_ref())
+    })
+    .into()
 }
 
 // ============================================================================
@@ -301,12 +454,20 @@ struct LayoutMetrics {
     row_spacing: f32,
 }
 
+/// Optional markers shown in a row's meta line.
+struct MetaTags {
+    private_icon: Option<Element<'static, NotificationMessage>>,
+    priority_tag: Option<Element<'static, NotificationMessage>>,
+}
+
 fn build_standard_layout<'a>(
     notif: &'a crate::github::types::NotificationView,
     subject_icon: Element<'static, NotificationMessage>,
+    tags: MetaTags,
     visual: &NotificationVisualState,
     metrics: &LayoutMetrics,
     p: &theme::ThemePalette,
+    show_account: bool,
 ) -> iced::widget::Row<'a, NotificationMessage> {
     let title_color = if visual.is_unread {
         p.text_primary
@@ -323,6 +484,7 @@ fn build_standard_layout<'a>(
         text(&notif.repo_full_name)
             .size(metrics.meta_size)
             .color(p.text_secondary),
+        tags.private_icon,
         Space::new().width(8),
         text(notif.reason.label())
             .size(metrics.reason_size)
@@ -330,8 +492,9 @@ fn build_standard_layout<'a>(
     ]
     .align_y(Alignment::Center);
 
-    // Add account badge only for priority notifications (they can come from any account)
-    if visual.is_priority && !notif.account.is_empty() {
+    // Add account badge for priority notifications (they can come from any account)
+    // and for every row of the unified inbox
+    if (visual.is_priority || show_account) && !notif.account.is_empty() {
         meta_row = meta_row.push(Space::new().width(8));
         meta_row = meta_row.push(account_badge(&notif.account, metrics.account_size));
     }
@@ -341,6 +504,11 @@ fn build_standard_layout<'a>(
         meta_row = meta_row.push(silent_indicator(metrics.account_size));
     }
 
+    if let Some(tag) = tags.priority_tag {
+        meta_row = meta_row.push(Space::new().width(8));
+        meta_row = meta_row.push(tag);
+    }
+
     let time_ago = types::format_time_ago(notif.updated_at);
     let time_row = build_time_row(visual, time_ago, metrics.meta_size, p);
 
@@ -359,9 +527,11 @@ fn build_standard_layout<'a>(
 fn build_dense_layout<'a>(
     notif: &'a crate::github::types::NotificationView,
     icon_theme: IconTheme,
+    tags: MetaTags,
     visual: &NotificationVisualState,
     metrics: &LayoutMetrics,
     p: &theme::ThemePalette,
+    show_account: bool,
 ) -> iced::widget::Row<'a, NotificationMessage> {
     // Use visual state's pre-computed subject_color for the icon
     let subject_icon = visual.icon_for_subject_type_with_color(notif.subject_type, icon_theme);
@@ -381,32 +551,36 @@ fn build_dense_layout<'a>(
     ]
     .align_y(Alignment::Center);
 
-    // Add account badge only for priority notifications (they can come from any account)
-    if visual.is_priority && !notif.account.is_empty() {
+    // Add account badge for priority notifications (they can come from any account)
+    // and for every row of the unified inbox
+    if (visual.is_priority || show_account) && !notif.account.is_empty() {
         title_row = title_row.push(Space::new().width(8));
         title_row = title_row.push(account_badge(&notif.account, metrics.account_size));
     }
 
+    let mut meta_row = row![
+        text(&notif.repo_full_name)
+            .size(metrics.meta_size)
+            .color(p.text_secondary),
+        tags.private_icon,
+        Space::new().width(8),
+        text(notif.reason.label())
+            .size(metrics.reason_size)
+            .color(p.text_muted),
+    ]
+    .align_y(Alignment::Center)
+    .padding([0, 20]); // Indent meta slightly
+
+    if let Some(tag) = tags.priority_tag {
+        meta_row = meta_row.push(Space::new().width(8));
+        meta_row = meta_row.push(tag);
+    }
+
     let time_ago = types::format_time_ago(notif.updated_at);
     let time_row = build_time_row(visual, time_ago, metrics.meta_size, p);
 
     row![
-        column![
-            title_row,
-            row![
-                text(&notif.repo_full_name)
-                    .size(metrics.meta_size)
-                    .color(p.text_secondary),
-                Space::new().width(8),
-                text(notif.reason.label())
-                    .size(metrics.reason_size)
-                    .color(p.text_muted),
-            ]
-            .align_y(Alignment::Center)
-            .padding([0, 20]) // Indent meta slightly
-        ]
-        .spacing(2)
-        .width(Fill),
+        column![title_row, meta_row].spacing(2).width(Fill),
         container(time_row).padding([0, 8]),
     ]
     .align_y(Alignment::Center)
@@ -6,34 +6,60 @@ use iced::{Alignment, Element, Fill};
 use crate::github::UserInfo;
 use crate::settings::IconTheme;
 use crate::ui::screens::notifications::messages::{
-    BulkMessage, FilterMessage, NavigationMessage, NotificationMessage, ThreadMessage,
+    ALL_ACCOUNTS, BulkMessage, FilterMessage, NavigationMessage, NotificationMessage, ThreadMessage,
 };
 use crate::ui::{icons, theme};
 
 /// Account info for the account switcher.
 #[derive(Debug, Clone)]
 pub struct AccountInfo {
-    pub username: String,
+    /// Account key (username plus GHES host).
+    pub key: String,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn view_top_bar<'a>(
     user: &'a UserInfo,
+    account: &str,
     accounts: Vec<AccountInfo>,
+    unified: bool,
     is_loading: bool,
     unread_count: usize,
     show_all_filters: bool,
     bulk_mode: bool,
+    rule_sets: &[String],
+    active_rule_set: &str,
     icon_theme: IconTheme,
 ) -> Element<'a, NotificationMessage> {
     let p = theme::palette();
 
+    // Rule set switcher (hidden with a single set)
+    let rule_set_switch: Element<'_, NotificationMessage> = if rule_sets.len() > 1 {
+        iced::widget::pick_list(rule_sets.to_vec(), Some(active_rule_set.to_string()), |s| {
+            NotificationMessage::Filter(FilterMessage::SelectRuleSet(s))
+        })
+        .text_size(13)
+        .padding([4, 8])
+        .style(theme::pick_list_style)
+        .menu_style(theme::menu_style)
+        .into()
+    } else {
+        Space::new().width(0).into()
+    };
+
     // Context Switcher (Account / Workspace selector)
     let context_switch: Element<'_, NotificationMessage> = if accounts.len() > 1 {
         // Dropdown for switching
-        let account_names: Vec<String> = accounts.iter().map(|a| a.username.clone()).collect();
+        let mut account_names: Vec<String> = accounts.iter().map(|a| a.key.clone()).collect();
+        account_names.push(ALL_ACCOUNTS.to_string());
+        let selected = if unified {
+            ALL_ACCOUNTS.to_string()
+        } else {
+            account.to_string()
+        };
 
-        iced::widget::pick_list(account_names, Some(user.login.clone()), |s| {
-            NotificationMessage::Navigation(NavigationMessage::SwitchAccount(s))
+        iced::widget::pick_list(account_names, Some(selected), |s| {
+            NotificationMessage::Navigation(NavigationMessage::from_account_choice(s))
         })
         .text_size(13)
         .padding([4, 8])
@@ -210,9 +236,14 @@ pub fn view_top_bar<'a>(
             middle_controls,
             Space::new().width(Fill),
             // Right: Controls
-            row![context_switch, settings_btn, profile_section,]
-                .spacing(12)
-                .align_y(Alignment::Center),
+            row![
+                rule_set_switch,
+                context_switch,
+                settings_btn,
+                profile_section,
+            ]
+            .spacing(12)
+            .align_y(Alignment::Center),
         ]
         .align_y(Alignment::Center)
         .padding([8, 16])